@@ -15,8 +15,13 @@
 use crate::app::build::is_up_to_date;
 use crate::app::build::task_result_marker::{AddMetadataMarkerHash, TaskResultMarker};
 use crate::app::context::ApplicationContext;
+use crate::diagnose::{detect_build_tools, DetectedBuildTools};
 use crate::log::{log_action, log_skipping_up_to_date, LogColorize, LogIndent};
 use crate::wasm_rpc_stubgen::commands::metadata::add_metadata;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::debug;
 
 pub async fn add_metadata_to_selected_components(
     ctx: &mut ApplicationContext,
@@ -24,6 +29,11 @@ pub async fn add_metadata_to_selected_components(
     log_action("Adding", "metadata to components");
     let _indent = LogIndent::new();
 
+    // NOTE: components usually share repositories and languages, so these are only detected once
+    //       per component source dir, and only for components which are not up-to-date
+    let mut revisions = HashMap::<PathBuf, Option<String>>::new();
+    let mut build_tools = HashMap::<PathBuf, DetectedBuildTools>::new();
+
     for component_name in ctx.selected_component_names() {
         let linked_wasm = ctx.application.component_temp_linked_wasm(component_name);
         let final_linked_wasm = ctx
@@ -31,12 +41,16 @@ pub async fn add_metadata_to_selected_components(
             .component_linked_wasm(component_name, ctx.build_profile());
 
         let root_package_name = ctx.wit.root_package_name(component_name)?;
+        let metadata = ctx
+            .application
+            .component_metadata(component_name, ctx.build_profile());
 
         let task_result_marker = TaskResultMarker::new(
             &ctx.application.task_result_marker_dir(),
             AddMetadataMarkerHash {
                 component_name,
                 root_package_name: root_package_name.clone(),
+                metadata: &metadata,
             },
         )?;
 
@@ -52,6 +66,19 @@ pub async fn add_metadata_to_selected_components(
             continue;
         }
 
+        let source_dir = ctx
+            .application
+            .component_source_dir(component_name)
+            .to_path_buf();
+        let revision = revisions
+            .entry(source_dir.clone())
+            .or_insert_with(|| git_revision(&source_dir))
+            .clone();
+        let build_tools = build_tools
+            .entry(source_dir.clone())
+            .or_insert_with(|| detect_build_tools(&source_dir))
+            .clone();
+
         task_result_marker.result(
             async {
                 log_action(
@@ -61,7 +88,14 @@ pub async fn add_metadata_to_selected_components(
                         component_name.as_str().log_color_highlight()
                     ),
                 );
-                add_metadata(&linked_wasm, root_package_name, &final_linked_wasm)
+                add_metadata(
+                    &linked_wasm,
+                    root_package_name,
+                    &metadata,
+                    revision.as_deref(),
+                    &build_tools,
+                    &final_linked_wasm,
+                )
             }
             .await,
         )?;
@@ -69,3 +103,35 @@ pub async fn add_metadata_to_selected_components(
 
     Ok(())
 }
+
+/// Returns the current git commit of the repository containing the given dir, with a "-dirty"
+/// suffix if there are uncommitted changes, or None if the dir is not in a git repository
+fn git_revision(dir: &Path) -> Option<String> {
+    let git = |args: &[&str]| -> Option<String> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .inspect_err(|err| debug!(dir = %dir.display(), "Failed to run git: {err}"))
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let revision = git(&["rev-parse", "HEAD"])?;
+    if revision.is_empty() {
+        return None;
+    }
+
+    let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+        .map(|status| !status.is_empty())
+        .unwrap_or(false);
+
+    Some(if dirty {
+        format!("{revision}-dirty")
+    } else {
+        revision
+    })
+}
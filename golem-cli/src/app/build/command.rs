@@ -13,25 +13,26 @@
 // limitations under the License.
 
 use crate::app::build::task_result_marker::{
-    GenerateQuickJSCrateCommandMarkerHash, GenerateQuickJSDTSCommandMarkerHash,
-    ResolvedExternalCommandMarkerHash, TaskResultMarker,
+    ComposeWacCommandMarkerHash, GenerateQuickJSCrateCommandMarkerHash,
+    GenerateQuickJSDTSCommandMarkerHash, ResolvedExternalCommandMarkerHash, TaskResultMarker,
 };
 use crate::app::build::{delete_path_logged, is_up_to_date, valid_env_vars};
 use crate::app::context::ApplicationContext;
 use crate::app::error::CustomCommandError;
+use crate::fs;
 use crate::fs::compile_and_collect_globs;
 use crate::log::{log_action, log_skipping_up_to_date, LogColorize, LogIndent};
 use crate::model::app::AppComponentName;
 use crate::model::app_raw;
 use crate::model::app_raw::{
-    ComposeAgentWrapper, GenerateAgentWrapper, GenerateQuickJSCrate, GenerateQuickJSDTS,
-    InjectToPrebuiltQuickJs,
+    ComposeAgentWrapper, ComposeWac, GenerateAgentWrapper, GenerateQuickJSCrate,
+    GenerateQuickJSDTS, InjectToPrebuiltQuickJs,
 };
 use crate::wasm_rpc_stubgen::commands;
-use anyhow::{anyhow, Context};
+use anyhow::{anyhow, bail, Context};
 use camino::Utf8Path;
-use std::collections::HashMap;
-use std::path::Path;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tracing::debug;
 use wasm_rquickjs::{EmbeddingMode, JsModuleSpec};
@@ -65,6 +66,9 @@ pub async fn execute_build_command(
         app_raw::BuildCommand::InjectToPrebuiltQuickJs(command) => {
             execute_inject_to_prebuilt_quick_js(&base_build_dir, command).await
         }
+        app_raw::BuildCommand::ComposeWac(command) => {
+            execute_compose_wac(ctx, &base_build_dir, command)
+        }
     }
 }
 
@@ -174,6 +178,137 @@ async fn execute_inject_to_prebuilt_quick_js(
     Ok(())
 }
 
+/// Returns the name, the path (for file based compositions) and the source of the WAC composition
+fn compose_wac_source(
+    base_build_dir: &Path,
+    command: &ComposeWac,
+) -> anyhow::Result<(String, Option<PathBuf>, String)> {
+    let target = base_build_dir.join(&command.to);
+
+    // NOTE: exclusivity of the fields is checked by the manifest validation
+    match (&command.compose_wac, &command.compose_wac_inline) {
+        (Some(path), _) => {
+            let path = base_build_dir.join(path);
+            let source = fs::read_to_string(&path)?;
+            Ok((path.display().to_string(), Some(path), source))
+        }
+        (None, Some(source)) => Ok((
+            format!("inline WAC for {}", target.display()),
+            None,
+            source.clone(),
+        )),
+        (None, None) => bail!("Missing WAC composition for {}", target.display()),
+    }
+}
+
+/// Returns the application components referenced as packages by the WAC compositions of the
+/// component, these have to be built before the component
+pub fn compose_wac_component_dependencies(
+    ctx: &ApplicationContext,
+    component_name: &AppComponentName,
+) -> BTreeSet<AppComponentName> {
+    let base_build_dir = ctx.application.component_source_dir(component_name);
+
+    ctx.application
+        .component_properties(component_name, ctx.build_profile())
+        .build
+        .iter()
+        .filter_map(|command| match command {
+            app_raw::BuildCommand::ComposeWac(command) => Some(command),
+            _ => None,
+        })
+        // NOTE: invalid compositions are ignored here, they are reported when composing
+        .filter_map(|command| {
+            let (source_name, _, source) = compose_wac_source(base_build_dir, command).ok()?;
+            commands::composition::wac_package_names(&source_name, &source).ok()
+        })
+        .flatten()
+        .filter_map(|package_name| {
+            ctx.application
+                .component_names()
+                .find(|component_name| component_name.as_str() == package_name)
+                .cloned()
+        })
+        .filter(|dependency| dependency != component_name)
+        .collect()
+}
+
+fn execute_compose_wac(
+    ctx: &ApplicationContext,
+    base_build_dir: &Path,
+    command: &ComposeWac,
+) -> anyhow::Result<()> {
+    let target = base_build_dir.join(&command.to);
+    let (source_name, source_path, source) = compose_wac_source(base_build_dir, command)?;
+
+    // Component outputs are only added when referenced, so components which are not built yet
+    // do not fail unrelated compositions
+    let mut packages = BTreeMap::<String, PathBuf>::new();
+    for package_name in commands::composition::wac_package_names(&source_name, &source)? {
+        if let Some(component_name) = ctx
+            .application
+            .component_names()
+            .find(|component_name| component_name.as_str() == package_name)
+        {
+            packages.insert(
+                package_name,
+                ctx.application
+                    .component_wasm(component_name, ctx.build_profile()),
+            );
+        }
+    }
+    for (name, path) in &command.packages {
+        packages.insert(name.clone(), base_build_dir.join(path));
+    }
+
+    let task_result_marker = TaskResultMarker::new(
+        &ctx.application.task_result_marker_dir(),
+        ComposeWacCommandMarkerHash {
+            build_dir: base_build_dir,
+            command,
+            packages: &packages,
+        },
+    )?;
+
+    let skip_up_to_date_checks =
+        ctx.config.skip_up_to_date_checks || !task_result_marker.is_up_to_date();
+
+    if is_up_to_date(
+        skip_up_to_date_checks,
+        || {
+            source_path
+                .iter()
+                .cloned()
+                .chain(packages.values().cloned())
+                .collect::<Vec<_>>()
+        },
+        || vec![target.clone()],
+    ) {
+        log_skipping_up_to_date(format!(
+            "composing WAC composition {}",
+            source_name.log_color_highlight()
+        ));
+        return Ok(());
+    }
+
+    log_action(
+        "Composing",
+        format!(
+            "WAC composition {} into {}",
+            source_name.log_color_highlight(),
+            target.log_color_highlight()
+        ),
+    );
+    let _indent = LogIndent::new();
+
+    task_result_marker.result(commands::composition::compose_wac(
+        &source_name,
+        &source,
+        &packages,
+        &target,
+    ))
+}
+
 pub fn execute_custom_command(
     ctx: &ApplicationContext,
     command_name: &str,
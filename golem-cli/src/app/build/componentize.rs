@@ -12,11 +12,11 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::app::build::command::execute_build_command;
+use crate::app::build::command::{compose_wac_component_dependencies, execute_build_command};
 use crate::app::context::ApplicationContext;
 use crate::log::{log_action, log_warn_action, LogColorize, LogIndent};
 use crate::model::app::{AppComponentName, DependencyType};
-use std::collections::{BTreeSet, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 pub async fn componentize(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
     log_action("Building", "components");
@@ -54,23 +54,91 @@ pub async fn componentize(ctx: &mut ApplicationContext) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn components_to_build(ctx: &ApplicationContext) -> BTreeSet<AppComponentName> {
+/// Returns the selected components and their wasm and WAC composition dependencies, ordered so
+/// that components used by WAC compositions are built before the composing components
+fn components_to_build(ctx: &ApplicationContext) -> Vec<AppComponentName> {
     let mut components_to_build = BTreeSet::new();
+    let mut compose_wac_dependencies = BTreeMap::new();
     let mut remaining: Vec<_> = ctx.selected_component_names().iter().cloned().collect();
 
     while let Some(component_name) = remaining.pop() {
         components_to_build.insert(component_name.clone());
 
-        for dep in ctx.application.component_dependencies(&component_name) {
-            if dep.dep_type == DependencyType::Wasm {
-                if let Some(dep) = dep.as_dependent_app_component() {
-                    if !components_to_build.contains(&dep.name) {
-                        components_to_build.insert(dep.name.clone());
-                        remaining.push(dep.name.clone());
-                    }
-                }
+        let wasm_dependencies = ctx
+            .application
+            .component_dependencies(&component_name)
+            .iter()
+            .filter(|dep| dep.dep_type == DependencyType::Wasm)
+            .filter_map(|dep| dep.as_dependent_app_component())
+            .map(|dep| dep.name)
+            .collect::<Vec<_>>();
+        let wac_dependencies = compose_wac_component_dependencies(ctx, &component_name);
+
+        for dep in wasm_dependencies.iter().chain(wac_dependencies.iter()) {
+            if !components_to_build.contains(dep) {
+                components_to_build.insert(dep.clone());
+                remaining.push(dep.clone());
             }
         }
+
+        compose_wac_dependencies.insert(component_name, wac_dependencies);
+    }
+
+    build_order(&components_to_build, &compose_wac_dependencies)
+}
+
+/// Orders the components so dependencies come first, cycles are broken arbitrarily, and are
+/// reported by the failing composition
+fn build_order(
+    components: &BTreeSet<AppComponentName>,
+    dependencies: &BTreeMap<AppComponentName, BTreeSet<AppComponentName>>,
+) -> Vec<AppComponentName> {
+    fn visit(
+        component_name: &AppComponentName,
+        dependencies: &BTreeMap<AppComponentName, BTreeSet<AppComponentName>>,
+        visited: &mut BTreeSet<AppComponentName>,
+        order: &mut Vec<AppComponentName>,
+    ) {
+        if !visited.insert(component_name.clone()) {
+            return;
+        }
+        for dependency in dependencies.get(component_name).into_iter().flatten() {
+            visit(dependency, dependencies, visited, order);
+        }
+        order.push(component_name.clone());
+    }
+
+    let mut visited = BTreeSet::new();
+    let mut order = Vec::with_capacity(components.len());
+    for component_name in components {
+        visit(component_name, dependencies, &mut visited, &mut order);
+    }
+    order
+}
+
+#[cfg(test)]
+mod test {
+    use crate::app::build::componentize::build_order;
+    use crate::model::app::AppComponentName;
+    use assert2::check;
+    use std::collections::{BTreeMap, BTreeSet};
+    use test_r::test;
+
+    #[test]
+    fn build_order_puts_dependencies_first() {
+        let name = |name: &str| AppComponentName::from(name);
+
+        let components =
+            BTreeSet::from([name("app:a"), name("app:b"), name("app:c"), name("app:d")]);
+        let dependencies = BTreeMap::from([
+            (name("app:a"), BTreeSet::from([name("app:c")])),
+            (name("app:c"), BTreeSet::from([name("app:d")])),
+            (name("app:d"), BTreeSet::from([name("app:c")])),
+        ]);
+
+        check!(
+            build_order(&components, &dependencies)
+                == vec![name("app:d"), name("app:c"), name("app:a"), name("app:b")]
+        );
     }
-    components_to_build
 }
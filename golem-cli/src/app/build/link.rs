@@ -46,14 +46,19 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
             .filter(|dep| dep.dep_type == DependencyType::DynamicWasmRpc)
             .collect::<BTreeSet<_>>();
 
+        let linked_dependencies = static_dependencies
+            .union(&library_dependencies)
+            .cloned()
+            .collect::<BTreeSet<_>>();
+
         let mut wasms_to_compose_with = Vec::new();
         for static_dep in &static_dependencies {
             let path = ctx.resolve_binary_component_source(static_dep).await?;
-            wasms_to_compose_with.push(path);
+            wasms_to_compose_with.push((path, static_dep.plug_selection.clone()));
         }
         for library_dep in &library_dependencies {
             let path = ctx.resolve_binary_component_source(library_dep).await?;
-            wasms_to_compose_with.push(path);
+            wasms_to_compose_with.push((path, library_dep.plug_selection.clone()));
         }
 
         let component_wasm = ctx
@@ -65,7 +70,7 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
             &ctx.application.task_result_marker_dir(),
             LinkRpcMarkerHash {
                 component_name,
-                dependencies: &static_dependencies,
+                dependencies: &linked_dependencies,
             },
         )?;
 
@@ -114,7 +119,10 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
         if is_up_to_date(
             ctx.config.skip_up_to_date_checks || !task_result_marker.is_up_to_date(),
             || {
-                let mut inputs = wasms_to_compose_with.clone();
+                let mut inputs = wasms_to_compose_with
+                    .iter()
+                    .map(|(path, _)| path.clone())
+                    .collect::<Vec<_>>();
                 inputs.push(component_wasm.clone());
                 inputs
             },
@@ -157,7 +165,20 @@ pub async fn link(ctx: &ApplicationContext) -> anyhow::Result<()> {
                     );
                     let _indent = LogIndent::new();
 
-                    commands::composition::compose(
+                    for dep in &linked_dependencies {
+                        if !dep.plug_selection.is_default() {
+                            log_action(
+                                "Selecting",
+                                format!(
+                                    "imports plugged by {}: {}",
+                                    dep.source.to_string().log_color_highlight(),
+                                    dep.plug_selection
+                                ),
+                            );
+                        }
+                    }
+
+                    commands::composition::compose_with_plug_selections(
                         ctx.application
                             .component_wasm(component_name, ctx.build_profile())
                             .as_path(),
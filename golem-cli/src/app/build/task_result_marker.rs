@@ -16,13 +16,13 @@ use crate::app::build::task_result_marker::TaskResultMarkerHashSourceKind::{Hash
 use crate::fs;
 use crate::log::log_warn_action;
 use crate::model::app::{AppComponentName, DependentComponent};
-use crate::model::app_raw::{GenerateQuickJSCrate, GenerateQuickJSDTS};
+use crate::model::app_raw::{ComposeWac, GenerateQuickJSCrate, GenerateQuickJSDTS};
 use crate::model::ProjectId;
 use crate::model::{app_raw, ComponentName};
 use anyhow::{anyhow, bail, Context};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
 use wit_parser::PackageName;
 
@@ -133,6 +133,27 @@ impl TaskResultMarkerHashSource for GenerateQuickJSDTSCommandMarkerHash<'_> {
     }
 }
 
+#[derive(Serialize)]
+pub struct ComposeWacCommandMarkerHash<'a> {
+    pub build_dir: &'a Path,
+    pub command: &'a ComposeWac,
+    pub packages: &'a BTreeMap<String, PathBuf>,
+}
+
+impl TaskResultMarkerHashSource for ComposeWacCommandMarkerHash<'_> {
+    fn kind() -> &'static str {
+        "ComposeWacCommandMarkerHash"
+    }
+
+    fn id(&self) -> anyhow::Result<Option<String>> {
+        Ok(None)
+    }
+
+    fn source(&self) -> anyhow::Result<TaskResultMarkerHashSourceKind> {
+        Ok(HashFromString(serde_json::to_string(self)?))
+    }
+}
+
 pub struct ComponentGeneratorMarkerHash<'a> {
     pub component_name: &'a AppComponentName,
     pub generator_kind: &'a str,
@@ -175,7 +196,11 @@ impl TaskResultMarkerHashSource for LinkRpcMarkerHash<'_> {
             self.component_name,
             self.dependencies
                 .iter()
-                .map(|s| format!("{}#{}", s.source, s.dep_type.as_str()))
+                .map(|s| if s.plug_selection.is_default() {
+                    format!("{}#{}", s.source, s.dep_type.as_str())
+                } else {
+                    format!("{}#{}#{}", s.source, s.dep_type.as_str(), s.plug_selection)
+                })
                 .join(",")
         )))
     }
@@ -184,6 +209,7 @@ impl TaskResultMarkerHashSource for LinkRpcMarkerHash<'_> {
 pub struct AddMetadataMarkerHash<'a> {
     pub component_name: &'a AppComponentName,
     pub root_package_name: PackageName,
+    pub metadata: &'a app_raw::ComponentMetadata,
 }
 
 impl TaskResultMarkerHashSource for AddMetadataMarkerHash<'_> {
@@ -196,7 +222,14 @@ impl TaskResultMarkerHashSource for AddMetadataMarkerHash<'_> {
     }
 
     fn source(&self) -> anyhow::Result<TaskResultMarkerHashSourceKind> {
-        Ok(HashFromString(self.root_package_name.to_string()))
+        // NOTE: the source revision and the build tool versions are intentionally not part of the
+        //       hash, so that commits and tool detection do not invalidate up-to-date components
+        Ok(HashFromString(format!(
+            "{}#{}#{}",
+            self.root_package_name,
+            serde_json::to_string(self.metadata)?,
+            crate::version(),
+        )))
     }
 }
 
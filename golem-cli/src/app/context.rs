@@ -16,6 +16,7 @@ use crate::app::build::build_app;
 use crate::app::build::clean::clean_app;
 use crate::app::build::command::execute_custom_command;
 use crate::app::error::{format_warns, AppValidationError, CustomCommandError};
+use crate::app::lock_file::LOCK_FILE_NAME;
 use crate::app::remote_components::RemoteComponents;
 use crate::config::ProfileName;
 use crate::fs::{compile_and_collect_globs, PathExtra};
@@ -109,6 +110,7 @@ impl ApplicationContext {
                         remote_components: RemoteComponents::new(
                             file_download_client,
                             temp_dir,
+                            PathBuf::from(LOCK_FILE_NAME),
                             offline,
                         ),
                     }
@@ -229,6 +231,18 @@ impl ApplicationContext {
                 if sources.is_empty() {
                     bail!("No common witDeps were defined in the application manifest")
                 }
+                if !self.application.remote_wit_deps().is_empty()
+                    && !self
+                        .application
+                        .remote_wit_deps_wit_dir()
+                        .join(naming::wit::DEPS_DIR)
+                        .exists()
+                {
+                    bail!(
+                        "Remote WIT dependencies are not vendored yet, use '{}' to fetch them",
+                        "golem app deps update-wit".log_color_highlight()
+                    )
+                }
                 WitDepsResolver::new(sources)
             })
             .as_ref()
@@ -399,6 +413,10 @@ impl ApplicationContext {
         Ok(())
     }
 
+    pub fn remote_components(&self) -> &RemoteComponents {
+        &self.remote_components
+    }
+
     pub fn selected_component_names(&self) -> &BTreeSet<AppComponentName> {
         &self.selected_component_names
     }
@@ -428,7 +446,14 @@ impl ApplicationContext {
                 }
             }
             BinaryComponentSource::LocalFile { path } => Ok(path.clone()),
-            BinaryComponentSource::Url { url } => self.remote_components.get_from_url(url).await,
+            BinaryComponentSource::Url { url, hash } => {
+                self.remote_components
+                    .get_from_url(url, hash.as_ref())
+                    .await
+            }
+            BinaryComponentSource::Oci { reference } => {
+                self.remote_components.get_from_oci(reference).await
+            }
         }
     }
 
@@ -0,0 +1,136 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::fs;
+use crate::log::{log_action, LogColorize};
+use crate::model::content_hash::ContentHash;
+use anyhow::{anyhow, Context};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+pub const LOCK_FILE_NAME: &str = "golem.lock";
+
+const LOCK_FILE_HEADER: &str =
+    "# This file is generated by golem-cli, use 'golem app deps update' to refresh it\n";
+
+/// Resolved hashes of remote component and IFS file URLs and OCI references, and of vendored WIT
+/// packages, stored next to the root application manifest
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LockFile {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub remote: BTreeMap<String, ContentHash>,
+    /// Vendored WIT packages by package name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub wit: BTreeMap<String, LockedWitPackage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct LockedWitPackage {
+    /// Source of the package as defined in the manifest
+    pub source: String,
+    /// Fetched commit for git sources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// Hash of the vendored package directory
+    pub hash: ContentHash,
+}
+
+impl LockFile {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        serde_yaml::from_str(&fs::read_to_string(path)?)
+            .with_context(|| anyhow!("Failed to parse lock file {}", path.log_color_highlight()))
+    }
+
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        log_action(
+            "Updating",
+            format!("lock file {}", path.log_color_highlight()),
+        );
+        fs::write_str(
+            path,
+            format!("{LOCK_FILE_HEADER}{}", serde_yaml::to_string(self)?),
+        )
+    }
+}
+
+/// Lock file loaded on first use, changes are saved immediately
+pub struct LockFileState {
+    path: PathBuf,
+    lock_file: std::sync::Mutex<Option<LockFile>>,
+}
+
+impl LockFileState {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock_file: std::sync::Mutex::new(None),
+        }
+    }
+
+    pub fn get(&self, url: &str) -> anyhow::Result<Option<ContentHash>> {
+        self.with_lock_file(|lock_file| Ok(lock_file.remote.get(url).cloned()))
+    }
+
+    pub fn set(&self, url: &str, hash: ContentHash) -> anyhow::Result<()> {
+        self.update(|lock_file| {
+            lock_file.remote.insert(url.to_string(), hash.clone());
+        })
+    }
+
+    /// Removes all entries for which the predicate returns false
+    pub fn retain(&self, keep: impl Fn(&str) -> bool) -> anyhow::Result<()> {
+        self.update(|lock_file| lock_file.remote.retain(|url, _| keep(url)))
+    }
+
+    pub fn wit_packages(&self) -> anyhow::Result<BTreeMap<String, LockedWitPackage>> {
+        self.with_lock_file(|lock_file| Ok(lock_file.wit.clone()))
+    }
+
+    pub fn set_wit_packages(
+        &self,
+        packages: BTreeMap<String, LockedWitPackage>,
+    ) -> anyhow::Result<()> {
+        self.update(|lock_file| lock_file.wit = packages)
+    }
+
+    fn update(&self, update: impl FnOnce(&mut LockFile)) -> anyhow::Result<()> {
+        let path = self.path.clone();
+        self.with_lock_file(|lock_file| {
+            let original = lock_file.clone();
+            update(lock_file);
+            if *lock_file != original {
+                lock_file.save(&path)?;
+            }
+            Ok(())
+        })
+    }
+
+    fn with_lock_file<R>(
+        &self,
+        f: impl FnOnce(&mut LockFile) -> anyhow::Result<R>,
+    ) -> anyhow::Result<R> {
+        let mut lock_file = self.lock_file.lock().unwrap();
+        if lock_file.is_none() {
+            *lock_file = Some(LockFile::load(&self.path)?);
+        }
+        f(lock_file.as_mut().unwrap())
+    }
+}
@@ -15,5 +15,7 @@
 pub mod build;
 pub mod context;
 pub mod error;
+pub mod lock_file;
 pub mod remote_components;
+pub mod remote_wit_deps;
 pub mod yaml_edit;
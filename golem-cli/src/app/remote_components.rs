@@ -12,73 +12,645 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::app::lock_file::{LockFileState, LockedWitPackage};
 use crate::context::check_http_response_success;
-use crate::log::{log_action, LogColorize};
-use anyhow::{anyhow, Context};
+use crate::log::{log_action, log_warn_action, LogColorize};
+use crate::model::content_hash::ContentHash;
+use crate::oci::{OciClient, OciReference};
+use anyhow::{anyhow, bail, Context};
 use base64::prelude::*;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use url::Url;
 
 pub struct RemoteComponents {
     client: reqwest::Client,
     temp_dir: PathBuf,
     offline: bool,
+    lock_file: LockFileState,
 }
 
 impl RemoteComponents {
-    pub fn new(client: reqwest::Client, target: PathBuf, offline: bool) -> Self {
+    pub fn new(
+        client: reqwest::Client,
+        target: PathBuf,
+        lock_file_path: PathBuf,
+        offline: bool,
+    ) -> Self {
         Self {
             client,
             temp_dir: target,
             offline,
+            lock_file: LockFileState::new(lock_file_path),
         }
     }
 
-    pub async fn get_from_url(&self, url: &Url) -> anyhow::Result<PathBuf> {
-        let parent_dir = self.temp_dir.join("remote_components");
-        crate::fs::create_dir_all(&parent_dir)?;
+    fn cache_dir(&self) -> PathBuf {
+        self.temp_dir.join("remote_components")
+    }
+
+    fn wit_cache_dir(&self) -> PathBuf {
+        self.temp_dir.join("remote_wit_deps")
+    }
 
+    pub fn url_cache_path(&self, url: &Url) -> PathBuf {
         let url_hash = BASE64_STANDARD.encode(url.to_string());
-        let path = parent_dir.join(format!("{url_hash}.wasm"));
+        self.cache_dir().join(format!("{url_hash}.wasm"))
+    }
+
+    pub fn oci_cache_path(&self, reference: &OciReference) -> PathBuf {
+        let reference_hash = BASE64_URL_SAFE.encode(reference.to_string());
+        self.cache_dir().join(format!("{reference_hash}.wasm"))
+    }
+
+    /// Path of the manifest digest the cached OCI artifact was pulled from, used to detect tags
+    /// which were moved since the last pull
+    fn oci_digest_path(cache_path: &Path) -> PathBuf {
+        cache_path.with_extension("digest")
+    }
+
+    /// Hash recorded in the lock file for the URL
+    pub fn locked_hash(&self, url: &Url) -> anyhow::Result<Option<ContentHash>> {
+        self.lock_file.get(url.as_str())
+    }
+
+    /// Hash of the artifact content recorded in the lock file for the OCI reference
+    pub fn locked_oci_hash(&self, reference: &OciReference) -> anyhow::Result<Option<ContentHash>> {
+        self.lock_file.get(&reference.to_string())
+    }
+
+    /// Returns the path of the cached component, downloading it if it is not cached yet.
+    ///
+    /// Both the downloaded and the cached components are verified against the pinned hash, or if
+    /// there is no pin, against the hash recorded in the lock file.
+    pub async fn get_from_url(
+        &self,
+        url: &Url,
+        hash: Option<&ContentHash>,
+    ) -> anyhow::Result<PathBuf> {
+        crate::fs::create_dir_all(self.cache_dir())?;
+
+        let path = self.url_cache_path(url);
+        let expected_hash = match hash {
+            Some(hash) => Some(hash.clone()),
+            None => self.locked_hash(url)?,
+        };
 
         if std::fs::exists(&path)? {
-            log_action(
-                "Skipping",
+            let valid = match &expected_hash {
+                Some(expected_hash) => expected_hash.matches(&crate::fs::read(&path)?),
+                None => true,
+            };
+
+            if valid {
+                log_action(
+                    "Skipping",
+                    format!(
+                        "download of remote WASM component: {}, using a previously downloaded version",
+                        url.as_str().log_color_highlight()
+                    ),
+                );
+
+                // NOTE: components cached before the lock file was created are locked on first use
+                if expected_hash.is_none() {
+                    self.lock_file
+                        .set(url.as_str(), ContentHash::sha256(&crate::fs::read(&path)?))?;
+                }
+
+                return Ok(path);
+            }
+
+            log_warn_action(
+                "Removing",
                 format!(
-                    "download of remote WASM component: {}, using a previously downloaded version",
+                    "previously downloaded remote WASM component with mismatching hash: {}",
                     url.as_str().log_color_highlight()
                 ),
             );
+            crate::fs::remove(&path)?;
+        }
 
-            Ok(path)
-        } else if !self.offline {
-            log_action(
-                "Downloading",
-                format!(
-                    "remote WASM component: {}",
-                    url.as_str().log_color_highlight()
-                ),
+        if self.offline {
+            return Err(anyhow!(
+                "Offline mode is enabled, but the remote component '{}' is not downloaded yet",
+                url
+            ));
+        }
+
+        log_action(
+            "Downloading",
+            format!(
+                "remote WASM component: {}",
+                url.as_str().log_color_highlight()
+            ),
+        );
+
+        let bytes = self.download(url).await?;
+        if let Some(expected_hash) = &expected_hash {
+            expected_hash.verify(url.as_str(), &bytes).map_err(|err| {
+                anyhow!(
+                    "{err}\nIf the change is expected, update the pinned hash, or run 'golem app deps update' to refresh the lock file"
+                )
+            })?;
+        }
+
+        self.lock_file.set(
+            url.as_str(),
+            expected_hash.unwrap_or_else(|| ContentHash::sha256(&bytes)),
+        )?;
+        std::fs::write(&path, bytes)?;
+
+        Ok(path)
+    }
+
+    /// Downloads the resource ignoring the cache and the lock file, verifies it against the
+    /// pinned hash, then records the resolved hash in the lock file. If `cache` is true, the
+    /// downloaded resource also replaces the cached version.
+    pub async fn update_url(
+        &self,
+        url: &Url,
+        hash: Option<&ContentHash>,
+        cache: bool,
+    ) -> anyhow::Result<ContentHash> {
+        if self.offline {
+            return Err(anyhow!(
+                "Offline mode is enabled, cannot update remote resource '{}'",
+                url
+            ));
+        }
+
+        log_action(
+            "Downloading",
+            format!("remote resource: {}", url.as_str().log_color_highlight()),
+        );
+
+        let bytes = self.download(url).await?;
+        if let Some(hash) = hash {
+            hash.verify(url.as_str(), &bytes)?;
+        }
+
+        let resolved_hash = hash.cloned().unwrap_or_else(|| ContentHash::sha256(&bytes));
+        self.lock_file.set(url.as_str(), resolved_hash.clone())?;
+
+        if cache {
+            crate::fs::create_dir_all(self.cache_dir())?;
+            std::fs::write(self.url_cache_path(url), bytes)?;
+        }
+
+        Ok(resolved_hash)
+    }
+
+    /// Removes lock file entries for URLs for which the predicate returns false
+    pub fn retain_locked_urls(&self, keep: impl Fn(&str) -> bool) -> anyhow::Result<()> {
+        self.lock_file.retain(keep)
+    }
+
+    /// Removes the cached components which are not in `keep`, returns the removed paths
+    pub fn clean_cache(&self, keep: &BTreeSet<PathBuf>) -> anyhow::Result<Vec<PathBuf>> {
+        let cache_dir = self.cache_dir();
+        if !cache_dir.exists() {
+            return Ok(vec![]);
+        }
+
+        let mut removed = Vec::new();
+        for entry in std::fs::read_dir(&cache_dir).with_context(|| {
+            anyhow!(
+                "Failed to read remote component cache dir {}",
+                cache_dir.log_color_highlight()
+            )
+        })? {
+            let path = entry?.path();
+            // NOTE: OCI digest files are kept together with their cached components
+            let is_digest = path
+                .extension()
+                .is_some_and(|extension| extension == "digest");
+            let cached_path = if is_digest {
+                path.with_extension("wasm")
+            } else {
+                path.clone()
+            };
+            if !keep.contains(&cached_path) {
+                crate::fs::remove(&path)?;
+                if !is_digest {
+                    removed.push(path);
+                }
+            }
+        }
+
+        Ok(removed)
+    }
+
+    async fn download(&self, url: &Url) -> anyhow::Result<bytes::Bytes> {
+        let response = self
+            .client
+            .get(url.clone())
+            .send()
+            .await
+            .with_context(|| anyhow!("Failed to download remote resource: {}", url))?;
+
+        let response = check_http_response_success(response).await?;
+
+        response
+            .bytes()
+            .await
+            .with_context(|| anyhow!("Failed to download remote resource: {}", url))
+    }
+
+    pub async fn get_from_oci(&self, reference: &OciReference) -> anyhow::Result<PathBuf> {
+        crate::fs::create_dir_all(self.cache_dir())?;
+
+        let path = self.oci_cache_path(reference);
+        self.pull_oci(reference, &path, "component", true).await?;
+        Ok(path)
+    }
+
+    /// Returns the WIT package encoded as WASM, pulling it if it is not cached yet
+    pub async fn get_wit_package_from_oci(
+        &self,
+        reference: &OciReference,
+    ) -> anyhow::Result<Vec<u8>> {
+        let path = self.wit_cache_dir().join("oci").join(format!(
+            "{}.wasm",
+            BASE64_URL_SAFE.encode(reference.to_string())
+        ));
+        // NOTE: WIT packages are locked by their vendored content
+        self.pull_oci(reference, &path, "WIT package", false)
+            .await?;
+        crate::fs::read(&path)
+    }
+
+    /// Pulls the artifact ignoring the cache and the lock file, then records its hash in the lock
+    /// file. If `cache` is true, the pulled artifact also replaces the cached version.
+    pub async fn update_oci(
+        &self,
+        reference: &OciReference,
+        cache: bool,
+    ) -> anyhow::Result<ContentHash> {
+        if self.offline {
+            bail!(
+                "Offline mode is enabled, cannot update OCI artifact '{}'",
+                reference
             );
+        }
 
-            let response =
-                self.client.get(url.clone()).send().await.with_context(|| {
-                    anyhow!("Failed to download remote component WASM: {}", url)
-                })?;
+        log_action(
+            "Pulling",
+            format!(
+                "OCI artifact: {}",
+                reference.to_string().log_color_highlight()
+            ),
+        );
 
-            let response = check_http_response_success(response).await?;
+        let client = OciClient::new(self.client.clone());
+        let digest = client
+            .resolve_digest(reference)
+            .await
+            .with_context(|| anyhow!("Failed to resolve OCI artifact: {}", reference))?;
+        let bytes = client
+            .pull(&reference.with_digest(&digest))
+            .await
+            .with_context(|| anyhow!("Failed to pull OCI artifact: {}", reference))?;
+
+        let resolved_hash = ContentHash::sha256(&bytes);
+        self.lock_file
+            .set(&reference.to_string(), resolved_hash.clone())?;
+
+        if cache {
+            crate::fs::create_dir_all(self.cache_dir())?;
+            let path = self.oci_cache_path(reference);
+            crate::fs::write(&path, bytes)?;
+            crate::fs::write_str(Self::oci_digest_path(&path), digest)?;
+        }
 
-            let bytes = response
-                .bytes()
-                .await
-                .with_context(|| anyhow!("Failed to download remote component WASM: {}", url))?;
+        Ok(resolved_hash)
+    }
 
-            std::fs::write(&path, bytes)?;
-            Ok(path)
+    /// Pulls the artifact if it is not cached yet, or if its tag references a different manifest
+    /// than the one the cached version was pulled from. In offline mode cached versions are used
+    /// without checking the tag.
+    ///
+    /// If `lock` is true, the cached and pulled artifacts are verified against the hash recorded
+    /// in the lock file, and the hash is recorded if it is missing. Locked cached artifacts are
+    /// used without checking the tag.
+    async fn pull_oci(
+        &self,
+        reference: &OciReference,
+        path: &Path,
+        kind: &str,
+        lock: bool,
+    ) -> anyhow::Result<()> {
+        let digest_path = Self::oci_digest_path(path);
+        let client = OciClient::new(self.client.clone());
+        let locked_hash = if lock {
+            self.locked_oci_hash(reference)?
         } else {
-            Err(anyhow!(
-                "Offline mode is enabled, but the remote component '{}' is not downloaded yet",
-                url
-            ))
+            None
+        };
+
+        let mut digest = None;
+        if std::fs::exists(path)? {
+            match &locked_hash {
+                Some(locked_hash) => {
+                    if locked_hash.matches(&crate::fs::read(path)?) {
+                        log_skipping_oci_pull(reference, kind);
+                        return Ok(());
+                    }
+
+                    log_warn_action(
+                        "Removing",
+                        format!(
+                            "previously pulled OCI WASM {kind} with mismatching hash: {}",
+                            reference.to_string().log_color_highlight()
+                        ),
+                    );
+                    crate::fs::remove(path)?;
+                    crate::fs::remove(&digest_path)?;
+                }
+                None => {
+                    if !self.offline && reference.digest.is_none() {
+                        let resolved_digest =
+                            client.resolve_digest(reference).await.with_context(|| {
+                                anyhow!("Failed to resolve OCI {kind} tag: {}", reference)
+                            })?;
+                        let cached_digest = std::fs::read_to_string(&digest_path).ok();
+                        if cached_digest.as_deref() != Some(resolved_digest.as_str()) {
+                            log_warn_action(
+                                "Updating",
+                                format!(
+                                    "previously pulled OCI WASM {kind}: {}, the tag now references {}",
+                                    reference.to_string().log_color_highlight(),
+                                    resolved_digest.log_color_highlight()
+                                ),
+                            );
+                            digest = Some(resolved_digest);
+                        }
+                    }
+
+                    if digest.is_none() {
+                        log_skipping_oci_pull(reference, kind);
+                        if lock {
+                            self.lock_file.set(
+                                &reference.to_string(),
+                                ContentHash::sha256(&crate::fs::read(path)?),
+                            )?;
+                        }
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        if self.offline {
+            bail!(
+                "Offline mode is enabled, but the OCI {kind} '{}' is not pulled yet",
+                reference
+            );
         }
+
+        let digest = match digest {
+            Some(digest) => digest,
+            None => {
+                log_action(
+                    "Pulling",
+                    format!(
+                        "OCI WASM {kind}: {}",
+                        reference.to_string().log_color_highlight()
+                    ),
+                );
+                client
+                    .resolve_digest(reference)
+                    .await
+                    .with_context(|| anyhow!("Failed to resolve OCI {kind} tag: {}", reference))?
+            }
+        };
+
+        let bytes = client
+            .pull(&reference.with_digest(&digest))
+            .await
+            .with_context(|| anyhow!("Failed to pull OCI {kind} WASM: {}", reference))?;
+
+        match &locked_hash {
+            Some(locked_hash) => {
+                locked_hash
+                    .verify(&reference.to_string(), &bytes)
+                    .map_err(|err| {
+                        anyhow!(
+                            "{err}\nIf the change is expected, run 'golem app deps update' to refresh the lock file"
+                        )
+                    })?;
+            }
+            None if lock => {
+                self.lock_file
+                    .set(&reference.to_string(), ContentHash::sha256(&bytes))?;
+            }
+            None => {}
+        }
+
+        crate::fs::write(path, bytes)?;
+        crate::fs::write_str(&digest_path, digest)
+    }
+
+    /// Checks out the git revision into a cached working copy, returns the path of the working
+    /// copy and the resolved commit. In offline mode only previously fetched revisions can be used.
+    pub fn checkout_git(&self, url: &str, rev: Option<&str>) -> anyhow::Result<(PathBuf, String)> {
+        let checkout_dir = self
+            .wit_cache_dir()
+            .join("git")
+            .join(BASE64_URL_SAFE.encode(url));
+        let rev = rev.unwrap_or("HEAD");
+        let fetched_ref = fetched_git_ref(rev);
+
+        // NOTE: fetched branches and tags are only available as FETCH_HEAD, so they are also
+        //       recorded under a local ref, which is used when the rev itself cannot be resolved
+        let cached_commit = if checkout_dir.join(".git").exists() {
+            [rev, fetched_ref.as_str()].into_iter().find_map(|rev| {
+                git(
+                    &checkout_dir,
+                    &[
+                        "rev-parse",
+                        "--verify",
+                        "--quiet",
+                        &format!("{rev}^{{commit}}"),
+                    ],
+                )
+                .ok()
+            })
+        } else {
+            None
+        };
+
+        let commit = match cached_commit {
+            Some(commit) if self.offline || commit.starts_with(rev) => {
+                log_action(
+                    "Skipping",
+                    format!(
+                        "fetch of git repository: {}#{}, using a previously fetched version",
+                        url.log_color_highlight(),
+                        rev.log_color_highlight()
+                    ),
+                );
+                commit
+            }
+            _ if self.offline => {
+                bail!(
+                    "Offline mode is enabled, but the git revision '{}#{}' is not fetched yet",
+                    url,
+                    rev
+                );
+            }
+            _ => {
+                log_action(
+                    "Fetching",
+                    format!(
+                        "git repository: {}#{}",
+                        url.log_color_highlight(),
+                        rev.log_color_highlight()
+                    ),
+                );
+
+                if !checkout_dir.join(".git").exists() {
+                    crate::fs::create_dir_all(&checkout_dir)?;
+                    git(&checkout_dir, &["init", "--quiet"])?;
+                    git(&checkout_dir, &["remote", "add", "origin", url])?;
+                }
+                git(
+                    &checkout_dir,
+                    &["fetch", "--quiet", "--depth", "1", "origin", rev],
+                )?;
+                let commit = git(&checkout_dir, &["rev-parse", "FETCH_HEAD"])?;
+                git(&checkout_dir, &["update-ref", &fetched_ref, &commit])?;
+                commit
+            }
+        };
+
+        git(&checkout_dir, &["checkout", "--quiet", "--force", &commit])?;
+
+        Ok((checkout_dir, commit))
+    }
+
+    pub fn locked_wit_packages(&self) -> anyhow::Result<BTreeMap<String, LockedWitPackage>> {
+        self.lock_file.wit_packages()
+    }
+
+    pub fn set_locked_wit_packages(
+        &self,
+        packages: BTreeMap<String, LockedWitPackage>,
+    ) -> anyhow::Result<()> {
+        self.lock_file.set_wit_packages(packages)
+    }
+}
+
+/// Local ref for a fetched git revision, hex encoded, so any branch or tag name forms a valid ref
+fn fetched_git_ref(rev: &str) -> String {
+    format!(
+        "refs/golem-cli/fetched/{}",
+        rev.bytes()
+            .map(|byte| format!("{byte:02x}"))
+            .collect::<String>()
+    )
+}
+
+fn git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .with_context(|| anyhow!("Failed to run git {}", args.join(" ")))?;
+
+    if !output.status.success() {
+        bail!(
+            "git {} failed in {}: {}",
+            args.join(" "),
+            dir.log_color_highlight(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn log_skipping_oci_pull(reference: &OciReference, kind: &str) {
+    log_action(
+        "Skipping",
+        format!(
+            "pull of OCI WASM {kind}: {}, using a previously pulled version",
+            reference.to_string().log_color_highlight()
+        ),
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use crate::app::remote_components::{fetched_git_ref, git, RemoteComponents};
+    use assert2::{check, let_assert};
+    use std::path::Path;
+    use test_r::test;
+
+    fn commit_file(repo_dir: &Path, content: &str) -> String {
+        std::fs::write(repo_dir.join("package.wit"), content).unwrap();
+        git(repo_dir, &["add", "."]).unwrap();
+        git(
+            repo_dir,
+            &[
+                "-c",
+                "user.name=test",
+                "-c",
+                "user.email=test@example.com",
+                "commit",
+                "--quiet",
+                "-m",
+                content,
+            ],
+        )
+        .unwrap();
+        git(repo_dir, &["rev-parse", "HEAD"]).unwrap()
+    }
+
+    fn remote_components(temp_dir: &Path, offline: bool) -> RemoteComponents {
+        RemoteComponents::new(
+            reqwest::Client::new(),
+            temp_dir.join("target"),
+            temp_dir.join("golem.lock"),
+            offline,
+        )
+    }
+
+    #[test]
+    fn fetched_git_ref_is_valid_for_any_rev() {
+        check!(fetched_git_ref("main") == "refs/golem-cli/fetched/6d61696e");
+        check!(
+            fetched_git_ref("feature/a..b") == "refs/golem-cli/fetched/666561747572652f612e2e62"
+        );
+    }
+
+    #[test]
+    fn offline_checkout_reuses_fetched_branch_and_tag() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let repo_dir = temp_dir.path().join("repo");
+        std::fs::create_dir_all(&repo_dir).unwrap();
+        git(&repo_dir, &["init", "--quiet", "--initial-branch", "main"]).unwrap();
+        let first_commit = commit_file(&repo_dir, "package test:first;");
+        git(&repo_dir, &["tag", "v1"]).unwrap();
+        let second_commit = commit_file(&repo_dir, "package test:second;");
+        let url = format!("file://{}", repo_dir.display());
+
+        let online = remote_components(temp_dir.path(), false);
+        let_assert!(Ok((_, commit)) = online.checkout_git(&url, Some("main")));
+        check!(commit == second_commit);
+        let_assert!(Ok((_, commit)) = online.checkout_git(&url, Some("v1")));
+        check!(commit == first_commit);
+
+        let offline = remote_components(temp_dir.path(), true);
+        let_assert!(Ok((checkout_dir, commit)) = offline.checkout_git(&url, Some("main")));
+        check!(commit == second_commit);
+        check!(
+            std::fs::read_to_string(checkout_dir.join("package.wit")).unwrap()
+                == "package test:second;"
+        );
+        let_assert!(Ok((_, commit)) = offline.checkout_git(&url, Some("v1")));
+        check!(commit == first_commit);
+
+        let_assert!(Err(_) = offline.checkout_git(&url, Some("missing")));
     }
 }
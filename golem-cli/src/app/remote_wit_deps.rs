@@ -0,0 +1,321 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::app::context::ApplicationContext;
+use crate::app::lock_file::LockedWitPackage;
+use crate::fs;
+use crate::log::{log_action, LogColorize, LogIndent};
+use crate::model::app::{parse_wit_package_name, RemoteWitDep, RemoteWitDepSource};
+use crate::model::content_hash::ContentHash;
+use crate::wasm_rpc_stubgen::naming;
+use crate::wasm_rpc_stubgen::naming::wit::package_dep_dir_name_from_parser;
+use crate::wasm_rpc_stubgen::wit_resolve::WitDepsResolver;
+use anyhow::{anyhow, bail, Context};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use wit_component::WitPrinter;
+use wit_parser::decoding::DecodedWasm;
+use wit_parser::{PackageName, UnresolvedPackageGroup};
+
+/// Fetches the remote WIT dependencies, vendors them together with their transitive dependencies
+/// into the deps dir of the application wit dir, then records the vendored packages in the lock
+/// file. Returns the number of vendored packages.
+pub async fn update_remote_wit_deps(app_ctx: &ApplicationContext) -> anyhow::Result<usize> {
+    let remote_wit_deps = app_ctx.application.remote_wit_deps();
+    let remote_components = app_ctx.remote_components();
+
+    let staging_dir = app_ctx
+        .application
+        .temp_dir()
+        .join("remote_wit_deps")
+        .join("staging");
+    fs::remove(&staging_dir)?;
+
+    let mut requested = Vec::<(PackageName, &RemoteWitDep, Option<String>)>::new();
+    for dep in remote_wit_deps {
+        log_action(
+            "Fetching",
+            format!(
+                "WIT package {} from {}",
+                dep.package.to_string().log_color_highlight(),
+                dep.source.to_string().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let (package_name, commit) = match &dep.source {
+            RemoteWitDepSource::Registry { reference } => {
+                let wasm = remote_components
+                    .get_wit_package_from_oci(reference)
+                    .await?;
+                (stage_wit_package_wasm(dep, &wasm, &staging_dir)?, None)
+            }
+            RemoteWitDepSource::Git { url, rev, path } => {
+                let (checkout_dir, commit) = remote_components.checkout_git(url, rev.as_deref())?;
+                (
+                    stage_wit_package_dir(dep, &checkout_dir.join(path), &staging_dir)?,
+                    Some(commit),
+                )
+            }
+        };
+        requested.push((package_name, dep, commit));
+    }
+
+    let resolver = WitDepsResolver::new(vec![staging_dir])?;
+    let wit_dir = app_ctx.application.remote_wit_deps_wit_dir();
+    let deps_dir = wit_dir.join(naming::wit::DEPS_DIR);
+
+    // Previously vendored packages are removed first, so no stale files are left behind
+    for package_name in remote_components.locked_wit_packages()?.keys() {
+        if let Ok(package_name) = parse_wit_package_name(package_name) {
+            fs::remove(deps_dir.join(package_dep_dir_name_from_parser(&package_name)))?;
+        }
+    }
+
+    resolver.add_packages_with_transitive_deps_to_wit_dir(
+        &requested
+            .iter()
+            .map(|(package_name, _, _)| package_name.clone())
+            .collect::<Vec<_>>(),
+        &wit_dir,
+    )?;
+
+    let mut locked = BTreeMap::<String, LockedWitPackage>::new();
+    for (package_name, dep, commit) in &requested {
+        for package_name in
+            resolver.package_names_with_transitive_deps(std::slice::from_ref(package_name))?
+        {
+            if locked.contains_key(&package_name.to_string()) {
+                continue;
+            }
+            locked.insert(
+                package_name.to_string(),
+                LockedWitPackage {
+                    source: dep.source.to_string(),
+                    commit: commit.clone(),
+                    hash: hash_wit_package_dir(
+                        &deps_dir.join(package_dep_dir_name_from_parser(&package_name)),
+                    )?,
+                },
+            );
+        }
+    }
+
+    let count = locked.len();
+    remote_components.set_locked_wit_packages(locked)?;
+
+    Ok(count)
+}
+
+/// Checks the vendored remote WIT dependencies against the manifest and the lock file, returns
+/// the found problems
+pub fn verify_remote_wit_deps(app_ctx: &ApplicationContext) -> anyhow::Result<Vec<String>> {
+    let locked = app_ctx.remote_components().locked_wit_packages()?;
+    let deps_dir = app_ctx
+        .application
+        .remote_wit_deps_wit_dir()
+        .join(naming::wit::DEPS_DIR);
+
+    let mut problems = Vec::new();
+
+    for dep in app_ctx.application.remote_wit_deps() {
+        let locked_package = locked.iter().find(|(package_name, _)| {
+            parse_wit_package_name(package_name).is_ok_and(|package_name| {
+                package_name.namespace == dep.package.namespace
+                    && package_name.name == dep.package.name
+                    && (dep.package.version.is_none()
+                        || package_name.version == dep.package.version)
+            })
+        });
+
+        match locked_package {
+            Some((_, locked_package)) => {
+                if locked_package.source != dep.source.to_string() {
+                    problems.push(format!(
+                        "Package {} is locked with source {}, but the manifest uses {}",
+                        dep.package.to_string().log_color_highlight(),
+                        locked_package.source.log_color_error_highlight(),
+                        dep.source.to_string().log_color_highlight()
+                    ));
+                }
+            }
+            None => problems.push(format!(
+                "Package {} is not locked",
+                dep.package.to_string().log_color_error_highlight()
+            )),
+        }
+    }
+
+    for (package_name, locked_package) in &locked {
+        let package_dir = match parse_wit_package_name(package_name) {
+            Ok(package_name) => deps_dir.join(package_dep_dir_name_from_parser(&package_name)),
+            Err(err) => {
+                problems.push(err);
+                continue;
+            }
+        };
+
+        if !package_dir.is_dir() {
+            problems.push(format!(
+                "Package {} is missing from {}",
+                package_name.log_color_error_highlight(),
+                deps_dir.log_color_highlight()
+            ));
+            continue;
+        }
+
+        let hash = hash_wit_package_dir(&package_dir)?;
+        if hash != locked_package.hash {
+            problems.push(format!(
+                "Package {} in {} was modified, expected hash {}, got {}",
+                package_name.log_color_error_highlight(),
+                package_dir.log_color_highlight(),
+                locked_package.hash,
+                hash
+            ));
+        }
+    }
+
+    Ok(problems)
+}
+
+/// Decodes a WIT package encoded as WASM, and writes the package and all the packages it depends
+/// on into separate package dirs
+fn stage_wit_package_wasm(
+    dep: &RemoteWitDep,
+    wasm: &[u8],
+    staging_dir: &Path,
+) -> anyhow::Result<PackageName> {
+    let decoded = wit_parser::decoding::decode(wasm).with_context(|| {
+        anyhow!(
+            "Failed to decode WIT package {}",
+            dep.package.to_string().log_color_error_highlight()
+        )
+    })?;
+
+    let DecodedWasm::WitPackage(resolve, main_package_id) = decoded else {
+        bail!(
+            "Expected a WIT package for {}, got a component",
+            dep.package.to_string().log_color_error_highlight()
+        );
+    };
+
+    for (package_id, package) in resolve.packages.iter() {
+        let mut wit_printer = WitPrinter::default();
+        wit_printer.emit_docs(true);
+        wit_printer.print(&resolve, package_id, &[])?;
+
+        fs::write_str(
+            staging_dir
+                .join(package_dep_dir_name_from_parser(&package.name))
+                .join(format!("{}.wit", package.name.name)),
+            wit_printer.output.to_string(),
+        )?;
+    }
+
+    check_package_name(dep, &resolve.packages[main_package_id].name)
+}
+
+/// Copies a WIT package dir and the package dirs in its deps dir
+fn stage_wit_package_dir(
+    dep: &RemoteWitDep,
+    package_dir: &Path,
+    staging_dir: &Path,
+) -> anyhow::Result<PackageName> {
+    let package_name = stage_wit_package_files(package_dir, staging_dir)?;
+
+    let package_deps_dir = package_dir.join(naming::wit::DEPS_DIR);
+    if package_deps_dir.is_dir() {
+        for entry in std::fs::read_dir(&package_deps_dir).with_context(|| {
+            anyhow!(
+                "Failed to read WIT deps dir {}",
+                package_deps_dir.log_color_error_highlight()
+            )
+        })? {
+            let path = entry?.path();
+            if path.is_dir() {
+                stage_wit_package_files(&path, staging_dir)?;
+            }
+        }
+    }
+
+    check_package_name(dep, &package_name)
+}
+
+fn stage_wit_package_files(package_dir: &Path, staging_dir: &Path) -> anyhow::Result<PackageName> {
+    let package = UnresolvedPackageGroup::parse_dir(package_dir).with_context(|| {
+        anyhow!(
+            "Failed to parse WIT package {}",
+            package_dir.log_color_error_highlight()
+        )
+    })?;
+
+    let target_dir = staging_dir.join(package_dep_dir_name_from_parser(&package.main.name));
+    fs::remove(&target_dir)?;
+    for source in package.source_map.source_files() {
+        let file_name = source.file_name().ok_or_else(|| {
+            anyhow!(
+                "Invalid WIT source file {}",
+                source.log_color_error_highlight()
+            )
+        })?;
+        fs::copy(source, target_dir.join(file_name))?;
+    }
+
+    Ok(package.main.name)
+}
+
+fn check_package_name(
+    dep: &RemoteWitDep,
+    package_name: &PackageName,
+) -> anyhow::Result<PackageName> {
+    if package_name.namespace != dep.package.namespace
+        || package_name.name != dep.package.name
+        || (dep.package.version.is_some() && package_name.version != dep.package.version)
+    {
+        bail!(
+            "Expected WIT package {}, got {}",
+            dep.package.to_string().log_color_highlight(),
+            package_name.to_string().log_color_error_highlight()
+        );
+    }
+    Ok(package_name.clone())
+}
+
+/// Hashes the names and contents of the files in a vendored package dir
+fn hash_wit_package_dir(package_dir: &Path) -> anyhow::Result<ContentHash> {
+    let mut files = std::fs::read_dir(package_dir)
+        .and_then(|read_dir| read_dir.collect::<std::io::Result<Vec<_>>>())
+        .with_context(|| {
+            anyhow!(
+                "Failed to read WIT package dir {}",
+                package_dir.log_color_error_highlight()
+            )
+        })?
+        .into_iter()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+
+    let mut content = Vec::new();
+    for file in files {
+        content.extend_from_slice(file.file_name().unwrap_or_default().as_encoded_bytes());
+        content.push(0);
+        content.extend_from_slice(&fs::read(&file)?);
+        content.push(0);
+    }
+
+    Ok(ContentHash::sha256(&content))
+}
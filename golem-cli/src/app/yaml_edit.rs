@@ -132,8 +132,13 @@ impl<'a> AppYamlEditor<'a> {
                         path.display()
                     )
                 })?;
-                if let BinaryComponentSource::Url { url: target_url } = target_component_source {
-                    if url_value == target_url.to_string() {
+                let target_url = match target_component_source {
+                    BinaryComponentSource::Url { url, .. } => Some(url.to_string()),
+                    BinaryComponentSource::Oci { reference } => Some(reference.to_string()),
+                    _ => None,
+                };
+                if let Some(target_url) = target_url {
+                    if url_value == target_url {
                         dep_type_id = Some(
                             dep.get("type")
                                 .ok_or_else(|| {
@@ -181,9 +186,12 @@ impl<'a> AppYamlEditor<'a> {
                     BinaryComponentSource::LocalFile { path } => {
                         dep.insert_str("path", path.to_string_lossy());
                     }
-                    BinaryComponentSource::Url { url } => {
+                    BinaryComponentSource::Url { url, .. } => {
                         dep.insert_str("url", url);
                     }
+                    BinaryComponentSource::Oci { reference } => {
+                        dep.insert_str("url", reference.to_string());
+                    }
                 }
                 dep.insert_str("type", dependency_type.as_str());
 
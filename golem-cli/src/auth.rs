@@ -14,21 +14,30 @@
 
 use crate::config::{
     AuthSecret, AuthenticationConfig, OAuth2AuthenticationConfig, OAuth2AuthenticationData,
+    OAuth2Flow, ResolvedAuthenticationConfig,
 };
 use crate::config::{Config, ProfileName};
+use crate::credentials::store_authentication_config;
 use crate::error::service::AnyhowMapServiceError;
-use crate::log::LogColorize;
+use crate::log::{log_action, LogColorize};
 use crate::model::AccountId;
 use anyhow::{anyhow, bail, Context};
+use chrono::{DateTime, TimeDelta, Utc};
 use colored::Colorize;
-use golem_client::api::{LoginClient, LoginClientLive, LoginOauth2WebFlowPollError};
-use golem_client::model::{Token, TokenSecret, UnsafeToken, WebFlowAuthorizeUrlResponse};
+use golem_client::api::{
+    LoginClient, LoginClientLive, LoginOauth2WebFlowPollError, TokenClient, TokenClientLive,
+};
+use golem_client::model::{
+    CreateTokenDto, OAuth2Data, Token, TokenSecret, UnsafeToken, WebFlowAuthorizeUrlResponse,
+};
 use golem_client::Security;
-use indoc::printdoc;
 use std::path::Path;
-use tracing::info;
+use tracing::{debug, info};
 use uuid::Uuid;
 
+// OAuth2 tokens are refreshed if they expire within this time
+const TOKEN_REFRESH_THRESHOLD: TimeDelta = TimeDelta::days(1);
+
 #[derive(Clone, PartialEq, Debug)]
 pub struct Authentication(pub UnsafeToken);
 
@@ -58,6 +67,26 @@ impl From<&OAuth2AuthenticationData> for Authentication {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OAuth2TokenState {
+    Valid,
+    /// Still valid, but expires within TOKEN_REFRESH_THRESHOLD
+    ExpiresSoon,
+    Expired,
+}
+
+impl OAuth2TokenState {
+    fn of(data: &OAuth2AuthenticationData, now: DateTime<Utc>) -> Self {
+        if data.expires_at <= now {
+            Self::Expired
+        } else if data.expires_at - now <= TOKEN_REFRESH_THRESHOLD {
+            Self::ExpiresSoon
+        } else {
+            Self::Valid
+        }
+    }
+}
+
 pub struct Auth {
     login_client: LoginClientLive,
 }
@@ -70,7 +99,7 @@ impl Auth {
     pub async fn authenticate(
         &self,
         token_override: Option<Uuid>,
-        auth_config: &AuthenticationConfig,
+        auth_config: &ResolvedAuthenticationConfig,
         config_dir: &Path,
         profile_name: &ProfileName,
     ) -> anyhow::Result<Authentication> {
@@ -87,9 +116,63 @@ impl Auth {
         }
     }
 
+    /// Runs a new OAuth2 login for the profile, replacing the currently stored token
+    pub async fn login(
+        &self,
+        flow: OAuth2Flow,
+        config_dir: &Path,
+        profile_name: &ProfileName,
+    ) -> anyhow::Result<Authentication> {
+        let token = match flow {
+            OAuth2Flow::Web => {
+                let data = self.start_oauth2().await?;
+                inform_user(&data);
+                self.complete_oauth2(data.state).await?
+            }
+            OAuth2Flow::Device => {
+                let data = self.start_device_oauth2().await?;
+                inform_user_device_flow(&data);
+                self.complete_device_oauth2(&data).await?
+            }
+        };
+        self.save_auth(Some(&token), flow, profile_name, config_dir)?;
+        Ok(Authentication(token))
+    }
+
+    /// Deletes the stored OAuth2 token on the server and from the profile, returns false if the
+    /// profile was not logged in
+    pub async fn logout(
+        &self,
+        auth_config: &ResolvedAuthenticationConfig,
+        config_dir: &Path,
+        profile_name: &ProfileName,
+    ) -> anyhow::Result<bool> {
+        match auth_config {
+            ResolvedAuthenticationConfig::Static(_) => {
+                bail!(
+                    "Profile {} uses a static token, logging out is only supported for OAuth2 authentication",
+                    profile_name.0.log_color_error_highlight()
+                )
+            }
+            ResolvedAuthenticationConfig::OAuth2(inner) => match &inner.data {
+                Some(data) => {
+                    if OAuth2TokenState::of(data, Utc::now()) != OAuth2TokenState::Expired {
+                        if let Err(err) = self.delete_token(data).await {
+                            debug!("Failed to delete token on logout: {err:#}");
+                        }
+                    }
+                    self.save_auth(None, inner.flow, profile_name, config_dir)?;
+                    Ok(true)
+                }
+                None => Ok(false),
+            },
+        }
+    }
+
     fn save_auth(
         &self,
-        token: &UnsafeToken,
+        token: Option<&UnsafeToken>,
+        flow: OAuth2Flow,
         profile_name: &ProfileName,
         config_dir: &Path,
     ) -> anyhow::Result<()> {
@@ -100,49 +183,94 @@ impl Auth {
 
         let mut profile = named_profile.profile;
 
-        profile.auth = AuthenticationConfig::OAuth2(OAuth2AuthenticationConfig {
-            data: Some(unsafe_token_to_auth_data(token)),
+        let auth = AuthenticationConfig::OAuth2(OAuth2AuthenticationConfig {
+            data: token.map(unsafe_token_to_auth_data),
+            flow,
         });
-        Config::set_profile(profile_name.clone(), profile, config_dir)
-            .with_context(|| "Failed to save auth token")?;
 
-        Ok(())
-    }
+        match &profile.auth {
+            AuthenticationConfig::Stored(stored) => {
+                store_authentication_config(config_dir, stored, &auth)
+                    .with_context(|| "Failed to save auth token to credential store")?;
+            }
+            _ => {
+                profile.auth = auth;
+                Config::set_profile(profile_name.clone(), profile, config_dir)
+                    .with_context(|| "Failed to save auth token")?;
+            }
+        }
 
-    async fn oauth2(
-        &self,
-        profile_name: &ProfileName,
-        config_dir: &Path,
-    ) -> anyhow::Result<Authentication> {
-        let data = self.start_oauth2().await?;
-        inform_user(&data);
-        let token = self.complete_oauth2(data.state).await?;
-        self.save_auth(&token, profile_name, config_dir)?;
-        Ok(Authentication(token))
+        Ok(())
     }
 
     async fn profile_authentication(
         &self,
-        auth_config: &AuthenticationConfig,
+        auth_config: &ResolvedAuthenticationConfig,
         config_dir: &Path,
         profile_name: &ProfileName,
     ) -> anyhow::Result<Authentication> {
         match auth_config {
-            AuthenticationConfig::Static(inner) => {
+            ResolvedAuthenticationConfig::Static(inner) => {
                 let secret: TokenSecret = inner.secret.into();
                 let data = self.token_details(secret.clone()).await?;
                 Ok(Authentication(UnsafeToken { data, secret }))
             }
-            AuthenticationConfig::OAuth2(inner) => {
-                if let Some(data) = &inner.data {
-                    Ok(data.into())
-                } else {
-                    self.oauth2(profile_name, config_dir).await
-                }
-            }
+            ResolvedAuthenticationConfig::OAuth2(inner) => match &inner.data {
+                Some(data) => match OAuth2TokenState::of(data, Utc::now()) {
+                    OAuth2TokenState::Expired => {
+                        info!("OAuth2 token expired, starting new login");
+                        self.login(inner.flow, config_dir, profile_name).await
+                    }
+                    OAuth2TokenState::ExpiresSoon => match self.refresh_token(data).await {
+                        Ok(token) => {
+                            self.save_auth(Some(&token), inner.flow, profile_name, config_dir)?;
+                            if let Err(err) = self.delete_token(data).await {
+                                debug!("Failed to delete refreshed token: {err:#}");
+                            }
+                            Ok(Authentication(token))
+                        }
+                        Err(err) => {
+                            debug!("Failed to refresh OAuth2 token: {err:#}");
+                            Ok(data.into())
+                        }
+                    },
+                    OAuth2TokenState::Valid => Ok(data.into()),
+                },
+                None => self.login(inner.flow, config_dir, profile_name).await,
+            },
         }
     }
 
+    // NOTE: there are no refresh tokens, instead a new token is created with the same lifetime
+    //       using the current (still valid) token
+    async fn refresh_token(&self, data: &OAuth2AuthenticationData) -> anyhow::Result<UnsafeToken> {
+        info!("Refreshing OAuth2 token");
+        self.token_client(data.secret)
+            .create_token(
+                &data.account_id,
+                &CreateTokenDto {
+                    expires_at: refreshed_expires_at(data, Utc::now()),
+                },
+            )
+            .await
+            .map_service_error()
+    }
+
+    async fn delete_token(&self, data: &OAuth2AuthenticationData) -> anyhow::Result<()> {
+        info!("Deleting OAuth2 token");
+        self.token_client(data.secret)
+            .delete_token(&data.account_id, &data.id)
+            .await
+            .map_service_error()?;
+        Ok(())
+    }
+
+    fn token_client(&self, secret: AuthSecret) -> TokenClientLive {
+        let mut context = self.login_client.context.clone();
+        context.security_token = Security::Bearer(secret.0.to_string());
+        TokenClientLive { context }
+    }
+
     async fn token_details(&self, token_secret: TokenSecret) -> anyhow::Result<Token> {
         info!("Getting token info");
         let mut context = self.login_client.context.clone();
@@ -187,25 +315,81 @@ impl Auth {
             }
         }
     }
+
+    async fn start_device_oauth2(&self) -> anyhow::Result<OAuth2Data> {
+        info!("Start OAuth2 device workflow");
+        self.login_client
+            .start_login_oauth_2()
+            .await
+            .map_service_error()
+    }
+
+    // NOTE: completing the device flow is a long-polling call, timeouts are retried until the
+    //       device code expires
+    async fn complete_device_oauth2(&self, data: &OAuth2Data) -> anyhow::Result<UnsafeToken> {
+        info!("Complete OAuth2 device workflow");
+        loop {
+            match self
+                .login_client
+                .complete_login_oauth_2(&data.encoded_session)
+                .await
+            {
+                Ok(token) => return Ok(token),
+                Err(golem_client::Error::Reqwest(err)) if err.is_timeout() => {
+                    if Utc::now() >= data.expires {
+                        bail!("OAuth2 device workflow timeout")
+                    }
+                }
+                Err(err) => return Err(err).map_service_error(),
+            }
+        }
+    }
+}
+
+fn inform_user_device_flow(data: &OAuth2Data) {
+    inform_user_box(
+        &[
+            "Visit the following URL on any device",
+            "and enter the code below",
+        ],
+        &[
+            data.url.underline().to_string(),
+            format!("Code: {}", data.user_code.bold()),
+            format!("The code expires at {}", data.expires),
+        ],
+    );
 }
 
 fn inform_user(data: &WebFlowAuthorizeUrlResponse) {
-    let url = &data.url.underline();
-
-    printdoc! {
-        "
-        ┌────────────────────────────────────────┐
-        │       Authenticate with GitHub         │
-        │                                        │
-        │  Visit the following URL in a browser  │
-        │                                        │
-        └────────────────────────────────────────┘
-        {url}
-        ──────────────────────────────────────────
-        "
-    }
-
-    println!("Waiting for authentication...");
+    inform_user_box(
+        &["Visit the following URL in a browser"],
+        &[data.url.underline().to_string()],
+    );
+}
+
+// NOTE: the instructions are written to stderr, so they do not mix with the formatted output
+fn inform_user_box(instructions: &[&str], details: &[String]) {
+    const WIDTH: usize = 40;
+
+    eprintln!("┌{}┐", "─".repeat(WIDTH));
+    eprintln!("│{:^WIDTH$}│", "Authenticate with GitHub");
+    eprintln!("│{:WIDTH$}│", "");
+    for instruction in instructions {
+        eprintln!("│  {:<width$}│", instruction, width = WIDTH - 2);
+    }
+    eprintln!("│{:WIDTH$}│", "");
+    eprintln!("└{}┘", "─".repeat(WIDTH));
+    for detail in details {
+        eprintln!("{detail}");
+    }
+    eprintln!("{}", "─".repeat(WIDTH + 2));
+
+    log_action("Waiting", "for authentication...");
+}
+
+// Refreshed tokens get the same lifetime as the original one
+fn refreshed_expires_at(data: &OAuth2AuthenticationData, now: DateTime<Utc>) -> DateTime<Utc> {
+    now + (data.expires_at - data.created_at)
 }
 
 fn token_header(secret: &TokenSecret) -> String {
@@ -221,3 +405,102 @@ fn unsafe_token_to_auth_data(value: &UnsafeToken) -> OAuth2AuthenticationData {
         secret: AuthSecret(value.secret.value),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::auth::{
+        refreshed_expires_at, unsafe_token_to_auth_data, Authentication, OAuth2TokenState,
+    };
+    use crate::config::{
+        AuthSecret, AuthenticationConfig, OAuth2AuthenticationConfig, OAuth2AuthenticationData,
+        OAuth2Flow, ResolvedAuthenticationConfig, StaticAuthenticationConfig,
+    };
+    use assert2::{check, let_assert};
+    use chrono::{DateTime, TimeDelta, Utc};
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn auth_data(created_at: &str, expires_at: &str) -> OAuth2AuthenticationData {
+        OAuth2AuthenticationData {
+            id: Uuid::new_v4(),
+            account_id: "account-1".to_string(),
+            created_at: created_at.parse::<DateTime<Utc>>().unwrap(),
+            expires_at: expires_at.parse::<DateTime<Utc>>().unwrap(),
+            secret: AuthSecret(Uuid::new_v4()),
+        }
+    }
+
+    #[test]
+    fn oauth2_token_state() {
+        let data = auth_data("2025-01-01T00:00:00Z", "2025-01-31T00:00:00Z");
+        let at = |value: &str| value.parse::<DateTime<Utc>>().unwrap();
+
+        check!(OAuth2TokenState::of(&data, at("2025-01-15T00:00:00Z")) == OAuth2TokenState::Valid);
+        check!(
+            OAuth2TokenState::of(&data, at("2025-01-30T00:00:00Z"))
+                == OAuth2TokenState::ExpiresSoon
+        );
+        check!(
+            OAuth2TokenState::of(&data, at("2025-01-30T23:59:59Z"))
+                == OAuth2TokenState::ExpiresSoon
+        );
+        check!(
+            OAuth2TokenState::of(&data, at("2025-01-31T00:00:00Z")) == OAuth2TokenState::Expired
+        );
+    }
+
+    #[test]
+    fn refreshed_token_keeps_lifetime() {
+        let data = auth_data("2025-01-01T00:00:00Z", "2025-01-31T00:00:00Z");
+        let now = "2025-01-30T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        check!(refreshed_expires_at(&data, now) == now + TimeDelta::days(30));
+    }
+
+    #[test]
+    fn stored_token_round_trip() {
+        let data = auth_data("2025-01-01T00:00:00Z", "2025-01-31T00:00:00Z");
+
+        let authentication = Authentication::from(&data);
+        check!(authentication.account_id().0 == "account-1");
+        check!(authentication.header() == format!("bearer {}", data.secret.0));
+
+        let stored = unsafe_token_to_auth_data(&authentication.0);
+        check!(stored.id == data.id);
+        check!(stored.account_id == data.account_id);
+        check!(stored.created_at == data.created_at);
+        check!(stored.expires_at == data.expires_at);
+        check!(stored.secret.0 == data.secret.0);
+    }
+
+    #[test]
+    fn resolved_authentication_is_logged_in() {
+        let oauth2 = |data| {
+            ResolvedAuthenticationConfig::OAuth2(OAuth2AuthenticationConfig {
+                data,
+                flow: OAuth2Flow::Device,
+            })
+        };
+
+        check!(!oauth2(None).is_logged_in());
+        check!(oauth2(Some(auth_data(
+            "2025-01-01T00:00:00Z",
+            "2025-01-31T00:00:00Z"
+        )))
+        .is_logged_in());
+        check!(
+            ResolvedAuthenticationConfig::Static(StaticAuthenticationConfig {
+                secret: AuthSecret(Uuid::new_v4()),
+            })
+            .is_logged_in()
+        );
+
+        // Logging out keeps the flow, so the next login uses the same one
+        let_assert!(
+            AuthenticationConfig::OAuth2(OAuth2AuthenticationConfig {
+                data: None,
+                flow: OAuth2Flow::Device
+            }) = AuthenticationConfig::from(oauth2(None))
+        );
+    }
+}
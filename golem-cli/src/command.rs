@@ -549,6 +549,10 @@ pub enum GolemCliSubcommand {
 
 pub mod shared_args {
     use crate::model::app::AppBuildStep;
+    use crate::model::app_raw;
+    use crate::model::deploy::{
+        WorkerRolloutConfig, WorkerRolloutFailureAction, WorkerRolloutStrategy,
+    };
     use crate::model::{AccountId, PluginReference};
     use crate::model::{
         ComponentName, ProjectName, ProjectReference, WorkerName, WorkerUpdateMode,
@@ -668,6 +672,11 @@ pub mod shared_args {
         #[clap(long, short, conflicts_with_all = ["update_workers", "redeploy_workers", "redeploy_http_api"]
         )]
         pub redeploy_all: bool,
+        /// Allow automatic worker updates for components with incompatible export changes
+        #[clap(long)]
+        pub allow_incompatible: bool,
+        #[command(flatten)]
+        pub rollout: WorkerRolloutArgs,
     }
 
     impl UpdateOrRedeployArgs {
@@ -677,9 +686,15 @@ pub mod shared_args {
                 redeploy_workers: false,
                 redeploy_http_api: false,
                 redeploy_all: false,
+                allow_incompatible: false,
+                rollout: WorkerRolloutArgs::default(),
             }
         }
 
+        pub fn worker_rollout(&self, profile_args: &UpdateOrRedeployArgs) -> WorkerRolloutConfig {
+            self.rollout.config(&profile_args.rollout)
+        }
+
         pub fn redeploy_workers(&self, profile_args: &UpdateOrRedeployArgs) -> bool {
             profile_args.redeploy_all
                 || profile_args.redeploy_workers
@@ -695,6 +710,68 @@ pub mod shared_args {
         }
     }
 
+    #[derive(Debug, Args, Default)]
+    pub struct WorkerRolloutArgs {
+        /// Worker update rollout strategy, defaults to updating all workers at once
+        #[arg(long, value_enum)]
+        pub rollout: Option<WorkerRolloutStrategy>,
+        /// Percentage of workers updated first when using the canary rollout strategy, defaults to 10
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=100))]
+        pub canary_percent: Option<u8>,
+        /// Number of workers updated concurrently in one rollout batch, defaults to 10
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        pub rollout_concurrency: Option<u64>,
+        /// Maximum percentage of failed worker updates before the rollout is stopped, defaults to 0
+        #[arg(long, value_parser = clap::value_parser!(u8).range(0..=100))]
+        pub max_failure_percent: Option<u8>,
+        /// Action to take when the failure threshold is exceeded, defaults to abort
+        #[arg(long, value_enum)]
+        pub on_rollout_failure: Option<WorkerRolloutFailureAction>,
+    }
+
+    impl WorkerRolloutArgs {
+        pub fn from_manifest(rollout: &app_raw::WorkerRollout) -> Self {
+            Self {
+                rollout: rollout.strategy,
+                canary_percent: rollout.canary_percent,
+                rollout_concurrency: rollout.concurrency.map(|concurrency| concurrency as u64),
+                max_failure_percent: rollout.max_failure_percent,
+                on_rollout_failure: rollout.on_failure,
+            }
+        }
+
+        /// Resolves the rollout settings, the flags take precedence over the profile settings
+        pub fn config(&self, profile_args: &WorkerRolloutArgs) -> WorkerRolloutConfig {
+            let defaults = WorkerRolloutConfig::default();
+            WorkerRolloutConfig {
+                strategy: self
+                    .rollout
+                    .or(profile_args.rollout)
+                    .unwrap_or(defaults.strategy),
+                canary_percent: self
+                    .canary_percent
+                    .or(profile_args.canary_percent)
+                    .unwrap_or(defaults.canary_percent)
+                    .clamp(1, 100),
+                concurrency: self
+                    .rollout_concurrency
+                    .or(profile_args.rollout_concurrency)
+                    .map(|concurrency| concurrency as usize)
+                    .unwrap_or(defaults.concurrency)
+                    .max(1),
+                max_failure_percent: self
+                    .max_failure_percent
+                    .or(profile_args.max_failure_percent)
+                    .unwrap_or(defaults.max_failure_percent)
+                    .min(100),
+                on_failure: self
+                    .on_rollout_failure
+                    .or(profile_args.on_rollout_failure)
+                    .unwrap_or(defaults.on_failure),
+            }
+        }
+    }
+
     #[derive(Debug, Args)]
     pub struct ProjectArg {
         // DO NOT ADD EMPTY LINES TO THE DOC COMMENT
@@ -757,12 +834,18 @@ pub mod shared_args {
 }
 
 pub mod app {
+    use crate::command::app::deps::AppDepsSubcommand;
     use crate::command::shared_args::{
         AppOptionalComponentNames, BuildArgs, ForceBuildArg, UpdateOrRedeployArgs,
+        WorkerRolloutArgs,
     };
+    use crate::config::ProfileName;
+    use crate::model::app_docs::AppDocsFormat;
+    use crate::model::app_graph::AppGraphFormat;
     use crate::model::WorkerUpdateMode;
     use clap::Subcommand;
     use golem_templates::model::GuestLanguage;
+    use std::path::PathBuf;
 
     #[derive(Debug, Subcommand)]
     pub enum AppSubcommand {
@@ -788,6 +871,20 @@ pub mod app {
             force_build: ForceBuildArg,
             #[command(flatten)]
             update_or_redeploy: UpdateOrRedeployArgs,
+            /// Roll back HTTP APIs and updated workers to the pre-deploy component versions without asking, if the deployment fails
+            #[arg(long)]
+            rollback_on_failure: bool,
+        },
+        /// Promote the deployed components, HTTP API definitions and deployments from one profile to another, without rebuilding
+        Promote {
+            #[command(flatten)]
+            component_name: AppOptionalComponentNames,
+            /// Source profile, the currently deployed component versions are taken from here
+            #[arg(long)]
+            from: ProfileName,
+            /// Target profile, the components and HTTP APIs are deployed here, using its HTTP API deployments from the manifest
+            #[arg(long)]
+            to: ProfileName,
         },
         /// Clean all components in the application or by selection
         Clean {
@@ -804,6 +901,9 @@ pub mod app {
             /// Await the update to be completed
             #[arg(long, default_value_t = false)]
             r#await: bool,
+
+            #[command(flatten)]
+            rollout: WorkerRolloutArgs,
         },
         /// Redeploy all workers of the application using the latest version
         RedeployWorkers {
@@ -815,20 +915,63 @@ pub mod app {
             #[command(flatten)]
             component_name: AppOptionalComponentNames,
         },
+        /// Render the dependency graph of the application components and HTTP API definitions, and report dependency cycles and unused library components
+        Graph {
+            /// Syntax of the rendered graph for text output, use the global --format for JSON or YAML output
+            #[arg(long, value_enum, default_value_t = AppGraphFormat::Dot)]
+            graph_format: AppGraphFormat,
+        },
+        /// Generate static documentation from the resolved WIT of the components, including exported interfaces, agent types, wasm-rpc client interfaces and bound HTTP API routes
+        Docs {
+            #[command(flatten)]
+            component_name: AppOptionalComponentNames,
+            /// Output format of the documentation
+            #[arg(long, value_enum, default_value_t = AppDocsFormat::Html)]
+            docs_format: AppDocsFormat,
+            /// Output directory, defaults to the docs directory in the application temp directory
+            #[arg(long)]
+            output_dir: Option<PathBuf>,
+        },
+        /// Manage remote component and WIT dependencies, their lock file and download cache
+        Deps {
+            #[command(subcommand)]
+            subcommand: AppDepsSubcommand,
+        },
         /// Run custom command
         #[clap(external_subcommand)]
         CustomCommand(Vec<String>),
     }
+
+    pub mod deps {
+        use clap::Subcommand;
+
+        #[derive(Debug, Subcommand)]
+        pub enum AppDepsSubcommand {
+            /// Download all remote component dependencies and IFS files, verify their pinned hashes and refresh the lock file
+            Update,
+            /// Fetch remote WIT dependencies from registries and git repositories, vendor them with their transitive dependencies into wit/deps and refresh the lock file
+            UpdateWit,
+            /// Verify that the vendored remote WIT dependencies match the manifest and the lock file
+            VerifyWit,
+            /// Remove cached remote components which are no longer used by the application
+            CleanCache {
+                /// Remove all cached remote components, including the ones still in use
+                #[arg(long)]
+                all: bool,
+            },
+        }
+    }
 }
 
 pub mod component {
     use crate::command::component::plugin::ComponentPluginSubcommand;
     use crate::command::shared_args::{
         BuildArgs, ComponentOptionalComponentName, ComponentOptionalComponentNames,
-        ComponentTemplateName, ForceBuildArg, UpdateOrRedeployArgs,
+        ComponentTemplateName, ForceBuildArg, UpdateOrRedeployArgs, WorkerRolloutArgs,
     };
     use crate::model::app::DependencyType;
     use crate::model::{ComponentName, WorkerUpdateMode};
+    use crate::oci::OciReference;
     use clap::Subcommand;
     use golem_templates::model::PackageName;
     use std::path::PathBuf;
@@ -880,7 +1023,7 @@ pub mod component {
             /// The path to the local component WASM that will be used as the target
             #[arg(long, conflicts_with_all = ["target_component_name", "target_component_url"])]
             target_component_path: Option<PathBuf>,
-            /// The URL to the remote component WASM that will be used as the target
+            /// The URL to the remote component WASM that will be used as the target, or an oci://<REGISTRY>/<REPOSITORY>[:<TAG>] reference
             #[arg(long, conflicts_with_all = ["target_component_name", "target_component_path"])]
             target_component_url: Option<Url>,
             /// The type of the dependency, defaults to wasm-rpc
@@ -898,6 +1041,32 @@ pub mod component {
             component_name: ComponentOptionalComponentName,
             /// Optional component version to get
             version: Option<u64>,
+            /// Download the component WASM to show its embedded metadata, like the source revision and build tools
+            #[arg(long)]
+            wasm_metadata: bool,
+        },
+        /// Inspect exports, imports, agent types and metadata of a local WASM file or a deployed component
+        Inspect {
+            /// Path of a local WASM file, or a deployed component in <COMPONENT_NAME>[@<VERSION>] form
+            component: String,
+        },
+        /// Check whether the exports of the built component(s) are compatible with the deployed version, for automatic worker updates
+        CheckCompat {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentNames,
+            /// Deployed component version or local WASM path to compare with, defaults to the latest deployed version
+            #[arg(long)]
+            against: Option<String>,
+        },
+        /// Build and publish the component to an OCI registry as a WASM artifact
+        Publish {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Target OCI reference in oci://<REGISTRY>/<REPOSITORY>[:<TAG>] form
+            #[arg(long)]
+            oci: OciReference,
+            #[command(flatten)]
+            force_build: ForceBuildArg,
         },
         /// Try to automatically update all existing workers of the selected component to the latest version
         UpdateWorkers {
@@ -909,6 +1078,9 @@ pub mod component {
             /// Await the update to be completed
             #[arg(long, default_value_t = false)]
             r#await: bool,
+
+            #[command(flatten)]
+            rollout: WorkerRolloutArgs,
         },
         /// Redeploy all workers of the selected component using the latest version
         RedeployWorkers {
@@ -994,9 +1166,14 @@ pub mod worker {
         ComponentOptionalComponentName, NewWorkerArgument, StreamArgs, WorkerFunctionArgument,
         WorkerFunctionName, WorkerNameArg,
     };
+    use crate::command::worker::files::WorkerFilesSubcommand;
+    use crate::model::worker_logs::WorkerLogLevel;
+    use crate::model::worker_query::TimeValue;
+    use crate::model::worker_top::WorkerTopSortBy;
     use crate::model::{IdempotencyKey, WorkerUpdateMode};
     use clap::Subcommand;
     use golem_client::model::ScanCursor;
+    use std::path::PathBuf;
 
     #[derive(Debug, Subcommand)]
     pub enum WorkerSubcommand {
@@ -1032,6 +1209,14 @@ pub mod worker {
             #[command(flatten)]
             stream_args: StreamArgs,
         },
+        /// Start an interactive shell for invoking worker functions with WAVE arguments,
+        /// while live streaming the worker's standard output, error and log channels
+        Shell {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            #[command(flatten)]
+            stream_args: StreamArgs,
+        },
         /// Get worker metadata
         Get {
             #[command(flatten)]
@@ -1046,9 +1231,12 @@ pub mod worker {
         List {
             #[command(flatten)]
             component_name: ComponentOptionalComponentName,
-            /// Filter for worker metadata in form of `property op value`.
+            /// Filter for worker metadata, using `property op value` expressions combined with `and`, `or`, `not` and parentheses.
             ///
-            /// Filter examples: `name = worker-name`, `version >= 0`, `status = Running`, `env.var1 = value`.
+            /// Properties: `name`, `status`, `version`, `created_at`, `args` and `env.<NAME>`.
+            /// Operators: `=`, `!=`, `>`, `>=`, `<`, `<=`, `like`, `not like`, `startswith`, `in`, `not in`.
+            /// Filter examples: `name = worker-name`, `name like order-*`, `version >= 0`, `status in (Running, Idle)`,
+            /// `env.var1 = value`, `created_at > now-1h`, `not (args = --debug or status = Failed)`.
             /// Can be used multiple times (AND condition is applied between them)
             #[arg(long)]
             filter: Vec<String>,
@@ -1074,6 +1262,81 @@ pub mod worker {
             #[command(flatten)]
             stream_args: StreamArgs,
         },
+        /// Show the standard output, error and log entries of a worker, reconstructed from its oplog
+        Logs {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Only show entries from the given time, e.g. `2025-01-31`, `2025-01-31T12:00:00Z`, `now-1h` or `30m`
+            #[arg(long)]
+            since: Option<TimeValue>,
+            /// Only show entries before the given time, accepts the same formats as `--since`
+            #[arg(long, conflicts_with = "follow")]
+            until: Option<TimeValue>,
+            /// Minimum log level to show, standard output counts as info, standard error as error
+            #[arg(long)]
+            level: Option<WorkerLogLevel>,
+            /// Only show log entries with a context starting with the given prefix
+            #[arg(long)]
+            context: Option<String>,
+            /// Keep streaming the live output of the worker after the historical entries
+            #[arg(long, short)]
+            follow: bool,
+            #[command(flatten)]
+            stream_args: StreamArgs,
+        },
+        /// Live dashboard of the workers of a component or application
+        Top {
+            #[command(flatten)]
+            component_name: ComponentOptionalComponentName,
+            /// Filter for worker metadata, using `property op value` expressions combined with `and`, `or`, `not` and parentheses.
+            ///
+            /// Properties: `name`, `status`, `version`, `created_at`, `args` and `env.<NAME>`.
+            /// Operators: `=`, `!=`, `>`, `>=`, `<`, `<=`, `like`, `not like`, `startswith`, `in`, `not in`.
+            /// Filter examples: `name = worker-name`, `name like order-*`, `version >= 0`, `status in (Running, Idle)`,
+            /// `env.var1 = value`, `created_at > now-1h`, `not (args = --debug or status = Failed)`.
+            /// Can be used multiple times (AND condition is applied between them)
+            #[arg(long)]
+            filter: Vec<String>,
+            /// Initial sort column, can be changed in the dashboard
+            #[arg(long, default_value_t = WorkerTopSortBy::Name, value_enum)]
+            sort_by: WorkerTopSortBy,
+            /// Refresh interval in seconds
+            #[arg(long, default_value_t = 2)]
+            refresh_interval: u64,
+        },
+        /// Fork a worker into a new worker of the same component, using the server-side fork support
+        Fork {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Name of the new worker
+            target_worker_name: String,
+            /// Index of the last oplog entry included in the fork, defaults to the latest entry
+            #[arg(long)]
+            at_oplog_index: Option<u64>,
+        },
+        /// Export the oplog, component version, arguments and environment of a worker into a snapshot file
+        Export {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Index of the last oplog entry included in the snapshot, defaults to the latest entry
+            #[arg(long)]
+            at_oplog_index: Option<u64>,
+            /// Snapshot file, defaults to <WORKER_NAME>.snapshot.json in the current directory
+            #[arg(long)]
+            output: Option<PathBuf>,
+        },
+        /// Recreate a worker from a snapshot file by replaying its recorded invocations, possibly using another profile
+        Import {
+            /// Snapshot file created by worker export
+            snapshot: PathBuf,
+            /// Name of the recreated worker, defaults to the name of the exported worker
+            target_worker_name: Option<String>,
+        },
+        /// Browse and download the file system of a worker
+        Files {
+            #[command(subcommand)]
+            subcommand: WorkerFilesSubcommand,
+        },
         /// Updates a worker
         Update {
             #[command(flatten)]
@@ -1132,6 +1395,61 @@ pub mod worker {
             /// Idempotency key of the invocation to be cancelled
             idempotency_key: IdempotencyKey,
         },
+        /// List the invocations processed or enqueued by a worker, based on its oplog
+        Invocations {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Cancel all pending invocations after listing them
+            #[arg(long)]
+            cancel_pending: bool,
+        },
+        /// Get the result of an invocation, e.g. one enqueued with `worker invoke --enqueue`
+        Result {
+            #[command(flatten)]
+            worker_name: WorkerNameArg,
+            /// Idempotency key of the invocation
+            idempotency_key: IdempotencyKey,
+            /// Wait until the invocation is completed or cancelled, or the worker failed
+            #[arg(long, short)]
+            wait: bool,
+            /// Maximum time to wait for the invocation in seconds, waits without limit if not set
+            #[arg(long, requires = "wait")]
+            timeout: Option<u64>,
+        },
+    }
+
+    pub mod files {
+        use crate::command::shared_args::WorkerNameArg;
+        use clap::Subcommand;
+        use std::path::PathBuf;
+
+        #[derive(Debug, Subcommand)]
+        pub enum WorkerFilesSubcommand {
+            /// List files and directories of a worker
+            Ls {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                /// Directory to list, defaults to the root directory
+                #[arg(default_value = "/")]
+                path: String,
+                /// List subdirectories recursively
+                #[arg(long)]
+                recursive: bool,
+            },
+            /// Download a file or a directory of a worker
+            Get {
+                #[command(flatten)]
+                worker_name: WorkerNameArg,
+                /// File or directory to download
+                path: String,
+                /// Local target file or directory, defaults to the name of the downloaded path in the current directory
+                #[arg(long)]
+                output: Option<PathBuf>,
+                /// Download directories recursively
+                #[arg(long)]
+                recursive: bool,
+            },
+        }
     }
 }
 
@@ -1426,8 +1744,9 @@ pub mod plugin {
 pub mod profile {
     use crate::command::profile::config::ProfileConfigSubcommand;
     use crate::config::ProfileName;
-    use crate::model::Format;
+    use crate::model::{Format, ProjectReference};
     use clap::Subcommand;
+    use std::path::PathBuf;
     use url::Url;
     use uuid::Uuid;
 
@@ -1456,6 +1775,15 @@ pub mod profile {
             /// Token to use for authenticating against Golem. If not provided an OAuth2 flow will be performed when authentication is needed for the first time.
             #[arg(long)]
             static_token: Option<Uuid>,
+            /// Keep the authentication secret in an encrypted credential file in the config directory instead of the profile config
+            #[arg(long, conflicts_with = "credential_helper")]
+            encrypted_credentials: bool,
+            /// age identity file used for the encrypted credential file, if not provided a passphrase is used
+            #[arg(long, requires = "encrypted_credentials")]
+            credential_identity_file: Option<PathBuf>,
+            /// External credential helper command used for keeping the authentication secret, similar to git credential helpers
+            #[arg(long)]
+            credential_helper: Option<String>,
             /// Accept invalid certificates.
             ///
             /// Disables certificate validation.
@@ -1482,6 +1810,78 @@ pub mod profile {
             /// Profile name to delete
             profile_name: ProfileName,
         },
+        /// Update global profile settings
+        Update {
+            /// Name of the profile to update
+            profile_name: ProfileName,
+            /// URL of Golem Component service
+            #[arg(long)]
+            component_url: Option<Url>,
+            /// URL of Golem Worker service
+            #[arg(long)]
+            worker_url: Option<Url>,
+            /// URL of Golem Cloud service
+            #[arg(long)]
+            cloud_url: Option<Url>,
+            /// Default project, in <PROJECT_NAME> or <ACCOUNT_EMAIL>/<PROJECT_NAME> form
+            #[arg(long)]
+            project: Option<ProjectReference>,
+            /// Accept invalid certificates.
+            ///
+            /// Disables certificate validation.
+            /// Warning! Any certificate will be trusted for use.
+            /// This includes expired certificates.
+            /// This introduces significant vulnerabilities, and should only be used as a last resort.
+            #[arg(long, hide = true)]
+            allow_insecure: Option<bool>,
+            /// Remove optional profile settings, so their defaults are used
+            #[arg(long, value_enum)]
+            unset: Vec<ProfileUnsetField>,
+        },
+        /// Rename global profile
+        Rename {
+            /// Current name of the profile
+            profile_name: ProfileName,
+            /// New name of the profile
+            new_profile_name: ProfileName,
+        },
+        /// Export global profiles as JSON, authentication secrets are only included if requested
+        Export {
+            /// Names of the profiles to export, exports all non-builtin profiles if not specified
+            profile_name: Vec<ProfileName>,
+            /// Output file, prints to the standard output if not specified
+            #[arg(long)]
+            output: Option<PathBuf>,
+            /// Include authentication secrets in the export
+            #[arg(long)]
+            include_secrets: bool,
+        },
+        /// Import global profiles from a file created by profile export
+        Import {
+            /// Path to the exported profiles
+            file: PathBuf,
+            /// Overwrite existing profiles with the same name
+            #[arg(long)]
+            overwrite: bool,
+        },
+        /// Log in with OAuth2 for the selected global profile, replacing the stored authentication
+        Login {
+            /// Profile name, uses the selected profile if not specified
+            profile_name: Option<ProfileName>,
+            /// Use the OAuth2 device code flow, which does not require a browser on the current machine
+            #[arg(long)]
+            device: bool,
+        },
+        /// Log out from the selected global profile, deleting the stored OAuth2 token
+        Logout {
+            /// Profile name, uses the selected profile if not specified
+            profile_name: Option<ProfileName>,
+        },
+        /// Show the account and token details of the selected global profile
+        Whoami {
+            /// Profile name, uses the selected profile if not specified
+            profile_name: Option<ProfileName>,
+        },
         /// Configure global profile
         Config {
             /// Profile name
@@ -1491,6 +1891,18 @@ pub mod profile {
         },
     }
 
+    #[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum ProfileUnsetField {
+        /// Use the default component service URL
+        ComponentUrl,
+        /// Use the component service URL for the worker service
+        WorkerUrl,
+        /// Use the component service URL for the cloud service
+        CloudUrl,
+        /// Use the default project
+        Project,
+    }
+
     pub mod config {
         use crate::model::Format;
         use clap::Subcommand;
@@ -16,7 +16,7 @@ use crate::app::yaml_edit::AppYamlEditor;
 use crate::command::api::definition::ApiDefinitionSubcommand;
 use crate::command::shared_args::{ProjectOptionalFlagArg, UpdateOrRedeployArgs};
 use crate::command_handler::Handlers;
-use crate::context::Context;
+use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
 use crate::log::{
@@ -35,12 +35,13 @@ use crate::model::text::api_definition::{
 use crate::model::text::fmt::{log_deploy_diff, log_error, log_warn};
 use crate::model::{ComponentName, ProjectRefAndId};
 use anyhow::{bail, Context as AnyhowContext};
-use golem_client::api::ApiDefinitionClient;
+use golem_client::api::{ApiDefinitionClient, ProjectClient};
 use golem_client::model::{HttpApiDefinitionRequest, HttpApiDefinitionResponseData};
 use itertools::Itertools;
 use serde::de::DeserializeOwned;
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct ApiDefinitionCommandHandler {
     ctx: Arc<Context>,
@@ -567,6 +568,243 @@ impl ApiDefinitionCommandHandler {
         Ok(latest_components)
     }
 
+    pub async fn server_api_definitions_by_app(
+        &self,
+        project: Option<&ProjectRefAndId>,
+    ) -> anyhow::Result<BTreeMap<String, DiffableHttpApiDefinition>> {
+        let api_definitions = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            app_ctx.application.http_api_definitions().clone()
+        };
+
+        if api_definitions.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        // NOTE: listing all the definitions is a single round trip, instead of one per definition
+        let clients = self.ctx.golem_clients().await?;
+        let server_api_definitions = clients
+            .api_definition
+            .list_definitions(
+                &self
+                    .ctx
+                    .cloud_project_handler()
+                    .selected_project_id_or_default(project)
+                    .await?
+                    .0,
+                None,
+            )
+            .await
+            .map_service_error()?;
+
+        let mut result = BTreeMap::new();
+        for server_api_definition in server_api_definitions {
+            let is_in_manifest =
+                api_definitions
+                    .iter()
+                    .any(|(api_definition_name, api_definition)| {
+                        api_definition_name.as_str() == server_api_definition.id
+                            && api_definition.value.version == server_api_definition.version
+                    });
+            if is_in_manifest {
+                result.insert(
+                    server_api_definition.id.clone(),
+                    DiffableHttpApiDefinition::from_server(server_api_definition)?,
+                );
+            }
+        }
+
+        Ok(result)
+    }
+
+    pub async fn rollback_api_definition(
+        &self,
+        project: Option<&ProjectRefAndId>,
+        previous_api_definition: &DiffableHttpApiDefinition,
+    ) -> anyhow::Result<()> {
+        let id = &previous_api_definition.0.id;
+        let version = &previous_api_definition.0.version;
+
+        let Some(server_api_definition) = self
+            .api_definition(project, id, version)
+            .await?
+            .map(DiffableHttpApiDefinition::from_server)
+            .transpose()?
+        else {
+            log_warn(format!(
+                "HTTP API definition {}@{} is not available anymore, cannot roll back",
+                id.log_color_highlight(),
+                version.log_color_highlight()
+            ));
+            return Ok(());
+        };
+
+        if &server_api_definition == previous_api_definition {
+            log_skipping_up_to_date(format!(
+                "rolling back HTTP API definition {}@{}",
+                id.log_color_highlight(),
+                version.log_color_highlight()
+            ));
+            return Ok(());
+        }
+
+        if !server_api_definition.0.draft {
+            log_warn(format!(
+                "HTTP API definition {}@{} is already deployed as non-draft, cannot roll back its content",
+                id.log_color_highlight(),
+                version.log_color_highlight()
+            ));
+            return Ok(());
+        }
+
+        log_warn_action(
+            "Rolling back",
+            format!(
+                "HTTP API definition {}@{}",
+                id.log_color_highlight(),
+                version.log_color_highlight()
+            ),
+        );
+
+        {
+            let _indent = self.ctx.log_handler().nested_text_view_indent();
+            log_deploy_diff(&server_api_definition, previous_api_definition)?;
+        }
+
+        let result = self
+            .update_api_definition(project, &previous_api_definition.0)
+            .await?;
+
+        self.ctx
+            .log_handler()
+            .log_view(&ApiDefinitionUpdateView(result));
+
+        Ok(())
+    }
+
+    pub async fn manifest_api_definition_ids(&self) -> anyhow::Result<Vec<(String, String)>> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+        Ok(app_ctx
+            .application
+            .http_api_definitions()
+            .iter()
+            .map(|(name, api_definition)| (name.to_string(), api_definition.value.version.clone()))
+            .collect())
+    }
+
+    pub async fn promote_api_definition(
+        &self,
+        source_clients: &GolemClients,
+        target_clients: &GolemClients,
+        id: &str,
+        version: &str,
+        component_versions: &BTreeMap<String, u64>,
+    ) -> anyhow::Result<()> {
+        let source_project_id = default_project_id(source_clients).await?;
+        let target_project_id = default_project_id(target_clients).await?;
+
+        let Some(source_api_definition) = source_clients
+            .api_definition
+            .get_definition(&source_project_id, id, version)
+            .await
+            .map_service_error_not_found_as_opt()?
+            .map(DiffableHttpApiDefinition::from_server)
+            .transpose()?
+            .map(|api_definition| api_definition.with_component_versions(component_versions))
+        else {
+            log_warn(format!(
+                "HTTP API definition {}@{} is not deployed in the source profile, skipping",
+                id.log_color_highlight(),
+                version.log_color_highlight()
+            ));
+            return Ok(());
+        };
+
+        let target_api_definition = target_clients
+            .api_definition
+            .get_definition(&target_project_id, id, version)
+            .await
+            .map_service_error_not_found_as_opt()?
+            .map(DiffableHttpApiDefinition::from_server)
+            .transpose()?;
+
+        let request = parse_api_definition(&serde_yaml::to_string(&source_api_definition.0)?)?;
+
+        match target_api_definition {
+            Some(target_api_definition) => {
+                if target_api_definition == source_api_definition {
+                    log_skipping_up_to_date(format!(
+                        "promoting HTTP API definition {}@{}",
+                        id.log_color_highlight(),
+                        version.log_color_highlight()
+                    ));
+                    return Ok(());
+                }
+
+                log_warn_action(
+                    "Found",
+                    format!(
+                        "changes for HTTP API definition {}@{}",
+                        id.log_color_highlight(),
+                        version.log_color_highlight()
+                    ),
+                );
+                {
+                    let _indent = self.ctx.log_handler().nested_text_view_indent();
+                    log_deploy_diff(&target_api_definition, &source_api_definition)?;
+                }
+
+                if !target_api_definition.0.draft {
+                    log_error(format!(
+                        "HTTP API definition {}@{} is already deployed as non-draft in the target profile, increase the version in the source profile before promoting",
+                        id.log_color_highlight(),
+                        version.log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+
+                log_action(
+                    "Updating",
+                    format!(
+                        "HTTP API definition {}@{} in the target profile",
+                        id.log_color_highlight(),
+                        version.log_color_highlight()
+                    ),
+                );
+                let result = target_clients
+                    .api_definition
+                    .update_definition_json(&target_project_id, id, version, &request)
+                    .await
+                    .map_service_error()?;
+                self.ctx
+                    .log_handler()
+                    .log_view(&ApiDefinitionUpdateView(result));
+            }
+            None => {
+                log_action(
+                    "Creating",
+                    format!(
+                        "HTTP API definition {}@{} in the target profile",
+                        id.log_color_highlight(),
+                        version.log_color_highlight()
+                    ),
+                );
+                let result = target_clients
+                    .api_definition
+                    .create_definition_json(&target_project_id, &request)
+                    .await
+                    .map_service_error()?;
+                self.ctx
+                    .log_handler()
+                    .log_view(&ApiDefinitionNewView(result));
+            }
+        }
+
+        Ok(())
+    }
+
     async fn api_definition(
         &self,
         project: Option<&ProjectRefAndId>,
@@ -643,3 +881,12 @@ impl ApiDefinitionCommandHandler {
 fn parse_api_definition<T: DeserializeOwned>(input: &str) -> anyhow::Result<T> {
     serde_yaml::from_str(input).context("Failed to parse API definition")
 }
+
+pub(super) async fn default_project_id(clients: &GolemClients) -> anyhow::Result<Uuid> {
+    clients
+        .project
+        .get_default_project()
+        .await
+        .map_service_error()
+        .map(|project| project.project_id)
+}
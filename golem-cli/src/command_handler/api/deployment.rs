@@ -14,8 +14,10 @@
 
 use crate::command::api::deployment::ApiDeploymentSubcommand;
 use crate::command::shared_args::{ProjectOptionalFlagArg, UpdateOrRedeployArgs};
+use crate::command_handler::api::definition::default_project_id;
 use crate::command_handler::Handlers;
-use crate::context::Context;
+use crate::config::ProfileName;
+use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
 use crate::log::{
@@ -37,6 +39,7 @@ use golem_client::model::{
 };
 use std::collections::{BTreeMap, BTreeSet};
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct ApiDeploymentCommandHandler {
     ctx: Arc<Context>,
@@ -409,6 +412,118 @@ impl ApiDeploymentCommandHandler {
         Ok(())
     }
 
+    pub async fn server_api_deployments_by_app(
+        &self,
+        project: Option<&ProjectRefAndId>,
+    ) -> anyhow::Result<BTreeMap<HttpApiDeploymentSite, Option<DiffableHttpApiDeployment>>> {
+        let mut server_api_deployments = BTreeMap::new();
+        for site in self.manifest_api_deployments().await?.into_keys() {
+            let server_api_deployment = self
+                .api_deployment(project, &site.to_string())
+                .await?
+                .map(DiffableHttpApiDeployment::from_server)
+                .transpose()?;
+            server_api_deployments.insert(site, server_api_deployment);
+        }
+        Ok(server_api_deployments)
+    }
+
+    pub async fn rollback_api_deployment(
+        &self,
+        project: Option<&ProjectRefAndId>,
+        site: &HttpApiDeploymentSite,
+        previous_api_deployment: Option<&DiffableHttpApiDeployment>,
+    ) -> anyhow::Result<()> {
+        let site_as_str = site.to_string();
+
+        let server_api_deployment = self
+            .api_deployment(project, &site_as_str)
+            .await?
+            .map(DiffableHttpApiDeployment::from_server)
+            .transpose()?;
+
+        if server_api_deployment.as_ref() == previous_api_deployment {
+            log_skipping_up_to_date(format!(
+                "rolling back HTTP API deployment {}",
+                site_as_str.log_color_highlight()
+            ));
+            return Ok(());
+        }
+
+        match (server_api_deployment, previous_api_deployment) {
+            (Some(_), None) => {
+                log_warn_action(
+                    "Rolling back",
+                    format!(
+                        "HTTP API deployment {} by deleting it, as it was not deployed before",
+                        site_as_str.log_color_highlight()
+                    ),
+                );
+
+                let clients = self.ctx.golem_clients().await?;
+
+                clients
+                    .api_deployment
+                    .delete_deployment(
+                        &self
+                            .ctx
+                            .cloud_project_handler()
+                            .selected_project_id_or_default(project)
+                            .await?
+                            .0,
+                        &site_as_str,
+                    )
+                    .await
+                    .map(|_| ())
+                    .map_service_error()?;
+
+                log_warn_action(
+                    "Deleted",
+                    format!("site {}", site_as_str.log_color_highlight()),
+                );
+            }
+            (server_api_deployment, Some(previous_api_deployment)) => {
+                log_warn_action(
+                    "Rolling back",
+                    format!("HTTP API deployment {}", site_as_str.log_color_highlight()),
+                );
+                let _indent = LogIndent::new();
+
+                if let Some(server_api_deployment) = server_api_deployment {
+                    {
+                        let _indent = self.ctx.log_handler().nested_text_view_indent();
+                        log_deploy_diff(&server_api_deployment, previous_api_deployment)?;
+                    }
+
+                    for (name, version) in
+                        server_api_deployment.plan(previous_api_deployment).delete
+                    {
+                        log_warn_action(
+                            "Undeploying",
+                            format!(
+                                "HTTP API definition {}@{} from {}",
+                                name.log_color_highlight(),
+                                version.log_color_highlight(),
+                                site_as_str.log_color_highlight()
+                            ),
+                        );
+                        self.undeploy_api_definition(project, site, &name, &version)
+                            .await?;
+                    }
+                }
+
+                let result = self
+                    .create_or_update_api_deployment(project, site, previous_api_deployment)
+                    .await?;
+
+                self.ctx.log_handler().log_view(&result);
+            }
+            (None, None) => {}
+        }
+
+        Ok(())
+    }
+
     async fn deploy_required_api_definitions<'a, I: Iterator<Item = &'a HttpApiDeployment>>(
         &self,
         project: Option<&ProjectRefAndId>,
@@ -502,25 +617,15 @@ impl ApiDeploymentCommandHandler {
 
         let result: ApiDeployment = clients
             .api_deployment
-            .deploy(&ApiDeploymentRequestCloud {
-                project_id: self
-                    .ctx
+            .deploy(&api_deployment_request(
+                self.ctx
                     .cloud_project_handler()
                     .selected_project_id_or_default(project)
                     .await?
                     .0,
-                api_definitions: api_deployment
-                    .definitions()
-                    .map(|(name, version)| ApiDefinitionInfoCloud {
-                        id: name.to_string(),
-                        version: version.to_string(),
-                    })
-                    .collect::<Vec<_>>(),
-                site: ApiSiteCloud {
-                    host: site.host.clone(),
-                    subdomain: site.subdomain.clone(),
-                },
-            })
+                site,
+                api_deployment,
+            ))
             .await
             .map_service_error()?
             .into();
@@ -528,6 +633,114 @@ impl ApiDeploymentCommandHandler {
         Ok(result)
     }
 
+    pub async fn promote_api_deployments(
+        &self,
+        target_clients: &GolemClients,
+        target_profile: &ProfileName,
+        api_definition_versions: &BTreeMap<String, String>,
+    ) -> anyhow::Result<()> {
+        let api_deployments = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            app_ctx
+                .application
+                .http_api_deployments(target_profile)
+                .cloned()
+                .unwrap_or_default()
+        };
+        if api_deployments.is_empty() {
+            return Ok(());
+        }
+
+        let target_project_id = default_project_id(target_clients).await?;
+
+        log_action(
+            "Promoting",
+            format!(
+                "HTTP API deployments to profile {}",
+                target_profile.0.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        for (site, api_deployment) in api_deployments {
+            let site_as_str = site.to_string();
+
+            let target_api_deployment = target_clients
+                .api_deployment
+                .get_deployment(&target_project_id, &site_as_str)
+                .await
+                .map_service_error_not_found_as_opt()?
+                .map(ApiDeployment::from)
+                .map(DiffableHttpApiDeployment::from_server)
+                .transpose()?;
+            let promoted_api_deployment = DiffableHttpApiDeployment::from_manifest(
+                &api_deployment.value,
+                api_definition_versions,
+            )?;
+
+            if let Some(target_api_deployment) = &target_api_deployment {
+                if target_api_deployment == &promoted_api_deployment {
+                    log_skipping_up_to_date(format!(
+                        "promoting HTTP API deployment {}",
+                        site_as_str.log_color_highlight()
+                    ));
+                    continue;
+                }
+
+                log_warn_action(
+                    "Found",
+                    format!(
+                        "changes in HTTP API deployment {}",
+                        site_as_str.log_color_highlight()
+                    ),
+                );
+                {
+                    let _indent = self.ctx.log_handler().nested_text_view_indent();
+                    log_deploy_diff(target_api_deployment, &promoted_api_deployment)?;
+                }
+
+                if !target_api_deployment
+                    .plan(&promoted_api_deployment)
+                    .delete
+                    .is_empty()
+                {
+                    log_error(format!(
+                        "HTTP API deployment {} requires undeploying definitions in the target profile, use {} with the target profile instead of promoting",
+                        site_as_str.log_color_highlight(),
+                        "golem api deployment deploy".log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+            }
+
+            log_action(
+                if target_api_deployment.is_some() {
+                    "Updating"
+                } else {
+                    "Creating"
+                },
+                format!(
+                    "HTTP API deployment {} in the target profile",
+                    site_as_str.log_color_highlight()
+                ),
+            );
+            let result: ApiDeployment = target_clients
+                .api_deployment
+                .deploy(&api_deployment_request(
+                    target_project_id,
+                    &site,
+                    &promoted_api_deployment,
+                ))
+                .await
+                .map_service_error()?
+                .into();
+            self.ctx.log_handler().log_view(&result);
+        }
+
+        Ok(())
+    }
+
     async fn undeploy_api_definition(
         &self,
         project: Option<&ProjectRefAndId>,
@@ -653,3 +866,24 @@ impl ApiDeploymentCommandHandler {
             .unwrap_or_default())
     }
 }
+
+fn api_deployment_request(
+    project_id: Uuid,
+    site: &HttpApiDeploymentSite,
+    api_deployment: &DiffableHttpApiDeployment,
+) -> ApiDeploymentRequestCloud {
+    ApiDeploymentRequestCloud {
+        project_id,
+        api_definitions: api_deployment
+            .definitions()
+            .map(|(name, version)| ApiDefinitionInfoCloud {
+                id: name.to_string(),
+                version: version.to_string(),
+            })
+            .collect::<Vec<_>>(),
+        site: ApiSiteCloud {
+            host: site.host.clone(),
+            subdomain: site.subdomain.clone(),
+        },
+    }
+}
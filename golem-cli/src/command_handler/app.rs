@@ -13,32 +13,42 @@
 // limitations under the License.
 
 use crate::app::error::CustomCommandError;
+use crate::app::remote_wit_deps::{update_remote_wit_deps, verify_remote_wit_deps};
+use crate::command::app::deps::AppDepsSubcommand;
 use crate::command::app::AppSubcommand;
 use crate::command::builtin_app_subcommands;
 use crate::command::shared_args::{
-    AppOptionalComponentNames, BuildArgs, ForceBuildArg, UpdateOrRedeployArgs,
+    AppOptionalComponentNames, BuildArgs, ForceBuildArg, UpdateOrRedeployArgs, WorkerRolloutArgs,
 };
 use crate::command_handler::Handlers;
+use crate::config::ProfileName;
 use crate::context::Context;
 use crate::diagnose::diagnose;
 use crate::error::{HintError, NonSuccessfulExit, ShowClapHelpTarget};
 use crate::fs;
 use crate::fuzzy::{Error, FuzzySearch};
-use crate::log::{log_action, logln, LogColorize, LogIndent, LogOutput, Output};
+use crate::log::{log_action, log_warn_action, logln, LogColorize, LogIndent, LogOutput, Output};
 use crate::model::api::HttpApiDeployMode;
-use crate::model::app::{ApplicationComponentSelectMode, DynamicHelpSections};
+use crate::model::app::{
+    ApplicationComponentSelectMode, BinaryComponentSource, DynamicHelpSections,
+};
+use crate::model::app_docs::{render_docs_index, AppDocsFormat, ComponentDocs, HttpApiRouteDocs};
+use crate::model::app_graph::{AppGraph, AppGraphFormat};
 use crate::model::component::Component;
+use crate::model::deploy::DeploySnapshot;
+use crate::model::text::app_graph::AppGraphView;
 use crate::model::text::fmt::{log_error, log_fuzzy_matches, log_text_view, log_warn};
 use crate::model::text::help::AvailableComponentNamesHelp;
-use crate::model::{ComponentName, WorkerUpdateMode};
+use crate::model::{ComponentName, ProjectRefAndId, WorkerUpdateMode};
 use anyhow::{anyhow, bail};
 use colored::Colorize;
+use futures_util::try_join;
 use golem_templates::add_component_by_template;
 use golem_templates::model::{
     ComposableAppGroupName, GuestLanguage, PackageName, Template, TemplateName,
 };
 use itertools::Itertools;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
 use std::sync::Arc;
 use strum::IntoEnumIterator;
@@ -66,24 +76,53 @@ impl AppCommandHandler {
                 component_name,
                 force_build,
                 update_or_redeploy,
+                rollback_on_failure,
             } => {
-                self.cmd_deploy(component_name, force_build, update_or_redeploy)
-                    .await
+                self.cmd_deploy(
+                    component_name,
+                    force_build,
+                    update_or_redeploy,
+                    rollback_on_failure,
+                )
+                .await
             }
+            AppSubcommand::Promote {
+                component_name,
+                from,
+                to,
+            } => self.cmd_promote(component_name, from, to).await,
             AppSubcommand::Clean { component_name } => self.cmd_clean(component_name).await,
             AppSubcommand::UpdateWorkers {
                 component_name,
                 update_mode,
                 r#await,
+                rollout,
             } => {
-                self.cmd_update_workers(component_name.component_name, update_mode, r#await)
-                    .await
+                self.cmd_update_workers(
+                    component_name.component_name,
+                    update_mode,
+                    r#await,
+                    rollout,
+                )
+                .await
             }
             AppSubcommand::RedeployWorkers { component_name } => {
                 self.cmd_redeploy_workers(component_name.component_name)
                     .await
             }
             AppSubcommand::Diagnose { component_name } => self.cmd_diagnose(component_name).await,
+            AppSubcommand::Graph { graph_format } => self.cmd_graph(graph_format).await,
+            AppSubcommand::Docs {
+                component_name,
+                docs_format,
+                output_dir,
+            } => self.cmd_docs(component_name, docs_format, output_dir).await,
+            AppSubcommand::Deps { subcommand } => match subcommand {
+                AppDepsSubcommand::Update => self.cmd_deps_update().await,
+                AppDepsSubcommand::UpdateWit => self.cmd_deps_update_wit().await,
+                AppDepsSubcommand::VerifyWit => self.cmd_deps_verify_wit().await,
+                AppDepsSubcommand::CleanCache { all } => self.cmd_deps_clean_cache(all).await,
+            },
             AppSubcommand::CustomCommand(command) => self.cmd_custom_command(command).await,
         }
     }
@@ -290,9 +329,442 @@ impl AppCommandHandler {
         component_name: AppOptionalComponentNames,
         force_build: ForceBuildArg,
         update_or_redeploy: UpdateOrRedeployArgs,
+        rollback_on_failure: bool,
     ) -> anyhow::Result<()> {
-        self.deploy(component_name, force_build, update_or_redeploy)
-            .await
+        self.deploy(
+            component_name,
+            force_build,
+            update_or_redeploy,
+            rollback_on_failure,
+        )
+        .await
+    }
+
+    async fn cmd_promote(
+        &self,
+        component_name: AppOptionalComponentNames,
+        from: ProfileName,
+        to: ProfileName,
+    ) -> anyhow::Result<()> {
+        if from == to {
+            log_error("The source and the target profiles must be different");
+            bail!(NonSuccessfulExit);
+        }
+
+        // NOTE: promoting uses the default projects of the profiles
+        for profile_name in [&from, &to] {
+            if self.ctx.profile_has_project(profile_name)? {
+                log_error(format!(
+                    "Profile {} has a selected project, promoting is only supported between the default projects",
+                    profile_name.0.log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+
+        self.must_select_components(
+            component_name.component_name,
+            &ApplicationComponentSelectMode::All,
+        )
+        .await?;
+
+        let selected_component_names = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            app_ctx
+                .some_or_err()?
+                .selected_component_names()
+                .iter()
+                .map(|cn| cn.as_str().into())
+                .collect::<Vec<ComponentName>>()
+        };
+
+        let source_clients = self.ctx.golem_clients_for_profile(&from).await?;
+        let target_clients = self.ctx.golem_clients_for_profile(&to).await?;
+
+        log_action(
+            "Promoting",
+            format!(
+                "components from profile {} to profile {}",
+                from.0.log_color_highlight(),
+                to.0.log_color_highlight()
+            ),
+        );
+
+        let mut component_versions = BTreeMap::new();
+        {
+            let _indent = LogIndent::new();
+            for component_name in &selected_component_names {
+                if let Some(component) = self
+                    .ctx
+                    .component_handler()
+                    .promote_component(&source_clients, &target_clients, component_name)
+                    .await?
+                {
+                    component_versions.insert(
+                        component.component_name.0,
+                        component.versioned_component_id.version,
+                    );
+                }
+            }
+        }
+
+        let api_definition_ids = self
+            .ctx
+            .api_definition_handler()
+            .manifest_api_definition_ids()
+            .await?;
+        if !api_definition_ids.is_empty() {
+            log_action(
+                "Promoting",
+                format!(
+                    "HTTP API definitions from profile {} to profile {}",
+                    from.0.log_color_highlight(),
+                    to.0.log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
+            for (id, version) in &api_definition_ids {
+                self.ctx
+                    .api_definition_handler()
+                    .promote_api_definition(
+                        &source_clients,
+                        &target_clients,
+                        id,
+                        version,
+                        &component_versions,
+                    )
+                    .await?;
+            }
+        }
+
+        self.ctx
+            .api_deployment_handler()
+            .promote_api_deployments(
+                &target_clients,
+                &to,
+                &api_definition_ids.into_iter().collect(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn cmd_graph(&self, graph_format: AppGraphFormat) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+
+        let graph = AppGraph::new(&app_ctx.application, self.ctx.build_profile());
+
+        self.ctx.log_handler().log_view(&AppGraphView {
+            graph: graph.clone(),
+            text_format: graph_format,
+        });
+
+        // NOTE: warnings are written to stderr, so the rendered graph can be piped
+        let _output = LogOutput::new(Output::Stderr);
+        for cycle in &graph.cycles {
+            log_warn(format!(
+                "Dependency cycle between components: {}",
+                cycle
+                    .iter()
+                    .map(|component_name| component_name.log_color_highlight())
+                    .join(", ")
+            ));
+        }
+        for library in &graph.unreachable_libraries {
+            log_warn(format!(
+                "Library component {} is not used by any deployable component or HTTP API definition",
+                library.log_color_highlight()
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_docs(
+        &self,
+        component_names: AppOptionalComponentNames,
+        docs_format: AppDocsFormat,
+        output_dir: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        self.must_select_components(
+            component_names.component_name,
+            &ApplicationComponentSelectMode::All,
+        )
+        .await?;
+
+        let mut app_ctx = self.ctx.app_context_lock_mut().await?;
+        let app_ctx = app_ctx.some_or_err_mut()?;
+        let build_profile = app_ctx.build_profile().cloned();
+
+        let output_dir = output_dir.unwrap_or_else(|| app_ctx.application.temp_dir().join("docs"));
+
+        log_action(
+            "Generating",
+            format!(
+                "component documentation into {}",
+                output_dir.log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let mut components = Vec::new();
+        for component_name in app_ctx.selected_component_names().clone() {
+            // NOTE: agent types are extracted before borrowing the generated WIT, as the extraction
+            //       caches the results
+            let is_agent = app_ctx.wit.is_agent(&component_name);
+            let component_wasm = app_ctx
+                .application
+                .component_wasm(&component_name, build_profile.as_ref());
+            let agent_types = if is_agent && component_wasm.exists() {
+                Some(
+                    app_ctx
+                        .wit
+                        .get_extracted_agent_types(&component_name, &component_wasm)
+                        .await?,
+                )
+            } else {
+                None
+            };
+
+            let Some(wit) = app_ctx.wit.component_generated_wit(&component_name)? else {
+                log_warn_action(
+                    "Skipping",
+                    format!(
+                        "component {}, its WIT is not generated yet, use '{}' first",
+                        component_name.as_str().log_color_highlight(),
+                        "golem app build".log_color_highlight()
+                    ),
+                );
+                continue;
+            };
+
+            let agent_types = match agent_types {
+                Some(agent_types) => agent_types,
+                None => {
+                    if is_agent {
+                        log_warn_action(
+                            "Skipping",
+                            format!(
+                                "agent types of component {}, the component is not built yet",
+                                component_name.as_str().log_color_highlight()
+                            ),
+                        );
+                    }
+                    vec![]
+                }
+            };
+
+            let docs = ComponentDocs::new(
+                component_name.clone(),
+                app_ctx
+                    .application
+                    .component_properties(&component_name, build_profile.as_ref())
+                    .component_type(),
+                wit,
+                app_ctx.wit.component_client_package(&component_name)?,
+                &agent_types,
+                HttpApiRouteDocs::for_component(&app_ctx.application, &component_name),
+            )?;
+
+            let path = output_dir.join(docs.file_name(docs_format));
+            log_action(
+                "Writing",
+                format!(
+                    "documentation of {} to {}",
+                    component_name.as_str().log_color_highlight(),
+                    path.log_color_highlight()
+                ),
+            );
+            fs::write_str(&path, docs.render(docs_format))?;
+
+            components.push(docs);
+        }
+
+        let index_path = output_dir.join(format!("index.{}", docs_format.extension()));
+        log_action(
+            "Writing",
+            format!(
+                "documentation index to {}",
+                index_path.log_color_highlight()
+            ),
+        );
+        fs::write_str(
+            &index_path,
+            render_docs_index(docs_format, "Components", &components),
+        )?;
+
+        Ok(())
+    }
+
+    async fn cmd_deps_update(&self) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+        let remote_components = app_ctx.remote_components();
+
+        log_action("Updating", "remote dependencies");
+        let _indent = LogIndent::new();
+
+        let mut urls = BTreeSet::<String>::new();
+
+        for dependency in app_ctx.application.all_dependencies() {
+            match &dependency.source {
+                BinaryComponentSource::Url { url, hash } => {
+                    if urls.insert(url.to_string()) {
+                        remote_components
+                            .update_url(url, hash.as_ref(), true)
+                            .await?;
+                    }
+                }
+                BinaryComponentSource::Oci { reference } => {
+                    if urls.insert(reference.to_string()) {
+                        remote_components.update_oci(reference, true).await?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for component_name in app_ctx.application.component_names() {
+            for build_profile in app_ctx.application.all_option_build_profiles() {
+                let files = &app_ctx
+                    .application
+                    .component_properties(component_name, build_profile.as_ref())
+                    .files;
+                for file in files {
+                    if file.source.is_remote() && urls.insert(file.source.as_url().to_string()) {
+                        remote_components
+                            .update_url(file.source.as_url(), file.hash.as_ref(), false)
+                            .await?;
+                    } else if let Some(reference) = file.source.oci_reference() {
+                        if urls.insert(reference.to_string()) {
+                            remote_components.update_oci(&reference, false).await?;
+                        }
+                    }
+                }
+            }
+        }
+
+        remote_components.retain_locked_urls(|url| urls.contains(url))?;
+
+        log_action(
+            "Updated",
+            format!(
+                "{} remote dependencies",
+                urls.len().to_string().log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn cmd_deps_update_wit(&self) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+
+        if app_ctx.application.remote_wit_deps().is_empty() {
+            log_warn_action(
+                "Skipping",
+                "updating remote WIT dependencies, no remoteWitDeps are defined",
+            );
+            return Ok(());
+        }
+
+        log_action("Updating", "remote WIT dependencies");
+        let count = {
+            let _indent = LogIndent::new();
+            update_remote_wit_deps(app_ctx).await?
+        };
+
+        log_action(
+            "Vendored",
+            format!(
+                "{} WIT package(s) into {}",
+                count.to_string().log_color_highlight(),
+                app_ctx
+                    .application
+                    .remote_wit_deps_wit_dir()
+                    .log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn cmd_deps_verify_wit(&self) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+
+        let problems = verify_remote_wit_deps(app_ctx)?;
+        if !problems.is_empty() {
+            log_error(format!(
+                "Vendored remote WIT dependencies are not up to date:\n{}",
+                problems
+                    .iter()
+                    .map(|problem| format!("  - {problem}"))
+                    .join("\n")
+            ));
+            logln("");
+            logln(format!(
+                "Use '{}' to refresh them",
+                "golem app deps update-wit".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        log_action(
+            "Verified",
+            format!(
+                "{} remote WIT dependencies",
+                app_ctx
+                    .application
+                    .remote_wit_deps()
+                    .len()
+                    .to_string()
+                    .log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn cmd_deps_clean_cache(&self, all: bool) -> anyhow::Result<()> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        let app_ctx = app_ctx.some_or_err()?;
+        let remote_components = app_ctx.remote_components();
+
+        let keep = if all {
+            BTreeSet::new()
+        } else {
+            app_ctx
+                .application
+                .all_dependencies()
+                .iter()
+                .filter_map(|dependency| match &dependency.source {
+                    BinaryComponentSource::Url { url, .. } => {
+                        Some(remote_components.url_cache_path(url))
+                    }
+                    BinaryComponentSource::Oci { reference } => {
+                        Some(remote_components.oci_cache_path(reference))
+                    }
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let removed = remote_components.clean_cache(&keep)?;
+        for path in &removed {
+            log_warn_action("Removed", path.log_color_highlight().to_string());
+        }
+        log_action(
+            "Removed",
+            format!(
+                "{} cached remote component(s)",
+                removed.len().to_string().log_color_highlight()
+            ),
+        );
+
+        Ok(())
     }
 
     async fn cmd_custom_command(&self, command: Vec<String>) -> anyhow::Result<()> {
@@ -351,6 +823,7 @@ impl AppCommandHandler {
         component_names: Vec<ComponentName>,
         update_mode: WorkerUpdateMode,
         await_update: bool,
+        rollout: WorkerRolloutArgs,
     ) -> anyhow::Result<()> {
         self.must_select_components(component_names, &ApplicationComponentSelectMode::All)
             .await?;
@@ -358,7 +831,12 @@ impl AppCommandHandler {
         let components = self.components_for_update_or_redeploy().await?;
         self.ctx
             .component_handler()
-            .update_workers_by_components(&components, update_mode, await_update)
+            .update_workers_by_components(
+                &components,
+                update_mode,
+                await_update,
+                &rollout.config(&self.ctx.update_or_redeploy().rollout),
+            )
             .await?;
 
         Ok(())
@@ -393,6 +871,7 @@ impl AppCommandHandler {
         component_name: AppOptionalComponentNames,
         force_build: ForceBuildArg,
         update_or_redeploy: UpdateOrRedeployArgs,
+        rollback_on_failure: bool,
     ) -> anyhow::Result<()> {
         let is_any_component_explicitly_selected = !component_name.component_name.is_empty();
 
@@ -402,40 +881,179 @@ impl AppCommandHandler {
             .opt_select_project(None)
             .await?;
 
-        let components = self
-            .ctx
-            .component_handler()
-            .deploy(
-                project.as_ref(),
-                component_name.component_name,
-                Some(force_build),
-                &ApplicationComponentSelectMode::All,
-                &update_or_redeploy,
-            )
-            .await?;
+        let snapshot = self.deploy_snapshot(project.as_ref()).await?;
+
+        let result = async {
+            let components = self
+                .ctx
+                .component_handler()
+                .deploy(
+                    project.as_ref(),
+                    component_name.component_name,
+                    Some(force_build),
+                    &ApplicationComponentSelectMode::All,
+                    &update_or_redeploy,
+                )
+                .await?;
 
-        let components = components
-            .into_iter()
-            .map(|component| (component.component_name.0.clone(), component))
-            .collect::<BTreeMap<_, _>>();
+            let components = components
+                .into_iter()
+                .map(|component| (component.component_name.0.clone(), component))
+                .collect::<BTreeMap<_, _>>();
 
-        self.ctx
-            .api_handler()
-            .deploy(
-                project.as_ref(),
-                if is_any_component_explicitly_selected {
-                    HttpApiDeployMode::Matching
-                } else {
-                    HttpApiDeployMode::All
-                },
-                &update_or_redeploy,
-                &components,
-            )
-            .await?;
+            self.ctx
+                .api_handler()
+                .deploy(
+                    project.as_ref(),
+                    if is_any_component_explicitly_selected {
+                        HttpApiDeployMode::Matching
+                    } else {
+                        HttpApiDeployMode::All
+                    },
+                    &update_or_redeploy,
+                    &components,
+                )
+                .await
+        }
+        .await;
+
+        if let Err(error) = result {
+            logln("");
+            log_error(format!("Deployment failed: {error:#}"));
+            logln("");
+
+            if rollback_on_failure || self.ctx.interactive_handler().confirm_deploy_rollback()? {
+                if let Err(rollback_error) = self
+                    .rollback_deploy(
+                        project.as_ref(),
+                        &snapshot,
+                        update_or_redeploy.update_workers,
+                    )
+                    .await
+                {
+                    logln("");
+                    log_error("Rollback failed, some of the previous state could not be restored");
+                    return Err(error.context(format!("rollback also failed: {rollback_error:#}")));
+                }
+            }
+
+            return Err(error);
+        }
 
         Ok(())
     }
 
+    // NOTE: the snapshot parts are independent, so they are fetched concurrently to keep
+    //       the extra round trips of the snapshot off the critical path of the deployment
+    async fn deploy_snapshot(
+        &self,
+        project: Option<&ProjectRefAndId>,
+    ) -> anyhow::Result<DeploySnapshot> {
+        let component_handler = self.ctx.component_handler();
+        let api_definition_handler = self.ctx.api_definition_handler();
+        let api_deployment_handler = self.ctx.api_deployment_handler();
+
+        let (components, api_definitions, api_deployments) = try_join!(
+            component_handler.latest_components_by_app(project),
+            api_definition_handler.server_api_definitions_by_app(project),
+            api_deployment_handler.server_api_deployments_by_app(project),
+        )?;
+
+        Ok(DeploySnapshot {
+            components,
+            api_definitions,
+            api_deployments,
+        })
+    }
+
+    async fn rollback_deploy(
+        &self,
+        project: Option<&ProjectRefAndId>,
+        snapshot: &DeploySnapshot,
+        update_workers: Option<WorkerUpdateMode>,
+    ) -> anyhow::Result<()> {
+        log_warn_action("Rolling back", "deployment");
+        let _indent = LogIndent::new();
+
+        // Every step is tried even if a previous one failed, so as much as possible is restored
+        let mut errors = Vec::<String>::new();
+        let mut collect_error = |subject: String, result: anyhow::Result<()>| {
+            if let Err(error) = result {
+                log_error(format!("Failed to roll back {subject}: {error:#}"));
+                errors.push(format!("failed to roll back {subject}: {error:#}"));
+            }
+        };
+
+        for api_definition in snapshot.api_definitions.values() {
+            collect_error(
+                format!(
+                    "HTTP API definition {}@{}",
+                    api_definition.0.id, api_definition.0.version
+                ),
+                self.ctx
+                    .api_definition_handler()
+                    .rollback_api_definition(project, api_definition)
+                    .await,
+            );
+        }
+
+        for (site, api_deployment) in &snapshot.api_deployments {
+            collect_error(
+                format!("HTTP API deployment {site}"),
+                self.ctx
+                    .api_deployment_handler()
+                    .rollback_api_deployment(project, site, api_deployment.as_ref())
+                    .await,
+            );
+        }
+
+        if let Some(update_workers) = update_workers {
+            collect_error(
+                "workers".to_string(),
+                self.ctx
+                    .component_handler()
+                    .rollback_workers_by_components(&snapshot.components, update_workers)
+                    .await,
+            );
+        }
+
+        match self
+            .ctx
+            .component_handler()
+            .latest_components_by_app(project)
+            .await
+        {
+            Ok(latest_components) => {
+                let new_component_versions = snapshot
+                    .new_component_versions(&latest_components)
+                    .into_iter()
+                    .map(|(name, version)| {
+                        format!(
+                            "{}@{}",
+                            name.log_color_highlight(),
+                            version.to_string().log_color_highlight()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if !new_component_versions.is_empty() {
+                    log_warn(format!(
+                        "Component versions cannot be deleted, the following versions remain on the server: {}",
+                        new_component_versions.join(", ")
+                    ));
+                }
+            }
+            Err(error) => log_warn(format!(
+                "Failed to check the component versions remaining on the server: {error:#}"
+            )),
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(errors.join("\n")))
+        }
+    }
+
     pub async fn build(
         &self,
         component_names: Vec<ComponentName>,
@@ -15,14 +15,19 @@
 use crate::context::check_http_response_success;
 use crate::log::{log_action, LogColorize, LogIndent};
 use crate::model::app::InitialComponentFile;
+use crate::model::content_hash::{ContentHash, HashAlgorithm};
+use crate::oci::{OciClient, OciReference};
 use anyhow::{anyhow, bail, Context};
 use async_trait::async_trait;
 use async_zip::tokio::write::ZipFileWriter;
 use async_zip::{Compression, ZipEntryBuilder};
 use golem_common::model::{ComponentFilePathWithPermissions, ComponentFilePathWithPermissionsList};
 use itertools::Itertools;
-use std::collections::VecDeque;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, VecDeque};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Mutex;
 use tempfile::TempDir;
 use tokio::fs::File;
 use tokio_stream::wrappers::ReadDirStream;
@@ -180,6 +185,18 @@ impl IfsFileManager {
                 .process_remote_file(file_processor, component_file)
                 .await
                 .map(|f| vec![f]),
+            "oci" => {
+                let reference = OciReference::from_str(component_file.source.as_url().as_str())
+                    .map_err(|err| anyhow!(err))?;
+                file_processor
+                    .process_oci_file(
+                        &reference,
+                        component_file.hash.as_ref(),
+                        &component_file.target,
+                    )
+                    .await
+                    .map(|f| vec![f])
+            }
             _ => Err(anyhow!(
                 "Unsupported scheme '{}' for IFS file: {}",
                 scheme,
@@ -238,7 +255,11 @@ impl IfsFileManager {
         component_file: &InitialComponentFile,
     ) -> anyhow::Result<R> {
         file_processor
-            .process_remote_file(component_file.source.as_url(), &component_file.target)
+            .process_remote_file(
+                component_file.source.as_url(),
+                component_file.hash.as_ref(),
+                &component_file.target,
+            )
             .await
     }
 }
@@ -254,6 +275,14 @@ trait FileProcessor<R> {
     async fn process_remote_file(
         &self,
         url: &Url,
+        hash: Option<&ContentHash>,
+        target: &ComponentFilePathWithPermissions,
+    ) -> anyhow::Result<R>;
+
+    async fn process_oci_file(
+        &self,
+        reference: &OciReference,
+        hash: Option<&ContentHash>,
         target: &ComponentFilePathWithPermissions,
     ) -> anyhow::Result<R>;
 }
@@ -290,6 +319,7 @@ impl FileProcessor<LoadedFile> for FileLoader {
     async fn process_remote_file(
         &self,
         url: &Url,
+        hash: Option<&ContentHash>,
         target: &ComponentFilePathWithPermissions,
     ) -> anyhow::Result<LoadedFile> {
         log_action(
@@ -311,13 +341,49 @@ impl FileProcessor<LoadedFile> for FileLoader {
             .await
             .with_context(|| anyhow!("Failed to download remote IFS file: {}", url))?;
 
+        if let Some(hash) = hash {
+            hash.verify(url.as_str(), &bytes)?;
+        }
+
         Ok(LoadedFile {
             content: bytes.into(),
             target: target.clone(),
         })
     }
+
+    async fn process_oci_file(
+        &self,
+        reference: &OciReference,
+        hash: Option<&ContentHash>,
+        target: &ComponentFilePathWithPermissions,
+    ) -> anyhow::Result<LoadedFile> {
+        log_action(
+            "Pulling",
+            format!(
+                "OCI IFS file: {}",
+                reference.to_string().log_color_highlight()
+            ),
+        );
+
+        let content = OciClient::new(self.client.clone())
+            .pull(reference)
+            .await
+            .with_context(|| anyhow!("Failed to pull OCI IFS file: {}", reference))?;
+
+        if let Some(hash) = hash {
+            hash.verify(&reference.to_string(), &content)?;
+        }
+
+        Ok(LoadedFile {
+            content,
+            target: target.clone(),
+        })
+    }
 }
 
+// NOTE: OCI layers are content addressed, so their hashes are only calculated once per process
+static OCI_LAYER_HASHES: Mutex<BTreeMap<String, String>> = Mutex::new(BTreeMap::new());
+
 struct FileHasher {
     client: reqwest::Client,
 }
@@ -354,6 +420,7 @@ impl FileProcessor<HashedFile> for FileHasher {
     async fn process_remote_file(
         &self,
         url: &Url,
+        hash: Option<&ContentHash>,
         target: &ComponentFilePathWithPermissions,
     ) -> anyhow::Result<HashedFile> {
         log_action(
@@ -373,11 +440,20 @@ impl FileProcessor<HashedFile> for FileHasher {
         let response = check_http_response_success(response).await?;
 
         let mut hasher = blake3::Hasher::new();
+        // NOTE: pinned files are also collected, so they can be verified using the pinned algorithm
+        let mut content = hash.map(|_| Vec::new());
         let mut stream = response.bytes_stream();
         while let Some(chunk) = stream.next().await {
             let bytes =
                 chunk.with_context(|| anyhow!("Failed to stream remote IFS file: {}", url))?;
             hasher.update(&bytes);
+            if let Some(content) = &mut content {
+                content.extend_from_slice(&bytes);
+            }
+        }
+
+        if let (Some(hash), Some(content)) = (hash, &content) {
+            hash.verify(url.as_str(), content)?;
         }
 
         Ok(HashedFile {
@@ -385,6 +461,84 @@ impl FileProcessor<HashedFile> for FileHasher {
             target: target.clone(),
         })
     }
+
+    async fn process_oci_file(
+        &self,
+        reference: &OciReference,
+        hash: Option<&ContentHash>,
+        target: &ComponentFilePathWithPermissions,
+    ) -> anyhow::Result<HashedFile> {
+        log_action(
+            "Calculating hash",
+            format!(
+                "for OCI IFS file: {}",
+                reference.to_string().log_color_highlight()
+            ),
+        );
+
+        let client = OciClient::new(self.client.clone());
+        let layer = client
+            .wasm_layer(reference)
+            .await
+            .with_context(|| anyhow!("Failed to resolve OCI IFS file: {}", reference))?;
+
+        // NOTE: locked OCI files are verified using the layer digest, which is the sha256 hash of
+        //       the content
+        if let Some(hash) = hash {
+            if hash.algorithm == HashAlgorithm::Sha256 && hash.to_string() != layer.digest {
+                bail!(
+                    "Hash mismatch for {}, expected {}, got {}",
+                    reference,
+                    hash,
+                    layer.digest
+                );
+            }
+        }
+
+        let known_hash_hex = OCI_LAYER_HASHES.lock().unwrap().get(&layer.digest).cloned();
+        let hash_hex = match known_hash_hex {
+            Some(hash_hex) => hash_hex,
+            None => {
+                let response = client
+                    .blob(reference, &layer.digest)
+                    .await
+                    .with_context(|| anyhow!("Failed to pull OCI IFS file: {}", reference))?;
+
+                // NOTE: the layer is streamed, and verified against its digest while hashing
+                let mut hasher = blake3::Hasher::new();
+                let mut digest_hasher = Sha256::new();
+                let mut stream = response.bytes_stream();
+                while let Some(chunk) = stream.next().await {
+                    let bytes = chunk
+                        .with_context(|| anyhow!("Failed to pull OCI IFS file: {}", reference))?;
+                    hasher.update(&bytes);
+                    digest_hasher.update(&bytes);
+                }
+
+                let digest = format!("sha256:{:x}", digest_hasher.finalize());
+                if digest != layer.digest {
+                    bail!(
+                        "Digest mismatch for OCI IFS file {}, expected {}, got {}",
+                        reference.to_string().log_color_error_highlight(),
+                        layer.digest,
+                        digest
+                    );
+                }
+
+                let hash_hex = hasher.finalize().to_hex().to_string();
+                OCI_LAYER_HASHES
+                    .lock()
+                    .unwrap()
+                    .insert(layer.digest, hash_hex.clone());
+                hash_hex
+            }
+        };
+
+        Ok(HashedFile {
+            hash_hex,
+            target: target.clone(),
+        })
+    }
 }
 
 // TODO: add this to manifest validation (too or instead of doing it here)?
@@ -20,22 +20,28 @@ use crate::app::yaml_edit::AppYamlEditor;
 use crate::command::component::ComponentSubcommand;
 use crate::command::shared_args::{
     BuildArgs, ComponentOptionalComponentNames, ComponentTemplateName, ForceBuildArg,
-    UpdateOrRedeployArgs,
+    UpdateOrRedeployArgs, WorkerRolloutArgs,
 };
 use crate::command_handler::component::ifs::IfsFileManager;
 use crate::command_handler::Handlers;
-use crate::context::Context;
+use crate::context::{Context, GolemClients};
 use crate::error::service::AnyhowMapServiceError;
 use crate::error::{HintError, NonSuccessfulExit, ShowClapHelpTarget};
+use crate::fs;
 use crate::log::{
     log_action, log_skipping_up_to_date, log_warn_action, logln, LogColorize, LogIndent,
 };
+use crate::model::agent::extraction::extract_agent_types;
 use crate::model::app::{
     AppComponentName, ApplicationComponentSelectMode, BuildProfileName, DynamicHelpSections,
 };
-use crate::model::app::{DependencyType, InitialComponentFile};
-use crate::model::component::{Component, ComponentSelection, ComponentView};
-use crate::model::deploy::TryUpdateAllWorkersResult;
+use crate::model::app::{DependencyType, InitialComponentFile, InitialComponentFileSource};
+use crate::model::compat::{check_exports_compatibility, CompatibilityLevel};
+use crate::model::component::{
+    Component, ComponentCompatibilityView, ComponentInspectSource, ComponentInspectView,
+    ComponentSelection, ComponentView, ComponentWasmMetadataView,
+};
+use crate::model::deploy::{TryUpdateAllWorkersResult, WorkerRolloutConfig};
 use crate::model::deploy_diff::component::{DiffableComponent, DiffableComponentFile};
 use crate::model::text::component::{ComponentCreateView, ComponentGetView, ComponentUpdateView};
 use crate::model::text::fmt::{log_deploy_diff, log_error, log_text_view, log_warn};
@@ -44,6 +50,8 @@ use crate::model::{
     AccountDetails, ComponentName, ComponentNameMatchKind, ComponentVersionSelection,
     ProjectRefAndId, ProjectReference, SelectedComponents, WorkerUpdateMode,
 };
+use crate::oci::{oci_annotations, OciClient, OciReference};
+use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
 use anyhow::{anyhow, bail, Context as AnyhowContext};
 use golem_client::api::ComponentClient;
 use golem_client::model::ComponentQuery;
@@ -55,12 +63,14 @@ use golem_client::model::DynamicLinking as DynamicLinkingOss;
 use golem_client::model::{AgentTypes, ComponentEnv as ComponentEnvCloud};
 use golem_common::model::agent::AgentType;
 use golem_common::model::component_metadata::WasmRpcTarget;
-use golem_common::model::{ComponentId, ComponentType};
+use golem_common::model::{ComponentFilePathWithPermissions, ComponentId, ComponentType};
 use golem_templates::add_component_by_template;
 use golem_templates::model::{GuestLanguage, PackageName};
+use golem_wasm_ast::analysis::{AnalysedExport, AnalysisContext};
+use golem_wasm_ast::IgnoreAllButMetadata;
 use itertools::Itertools;
-use std::collections::{BTreeMap, HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::fs::File;
 use tracing::debug;
@@ -124,15 +134,38 @@ impl ComponentCommandHandler {
             ComponentSubcommand::Get {
                 component_name,
                 version,
-            } => self.cmd_get(component_name.component_name, version).await,
+                wasm_metadata,
+            } => {
+                self.cmd_get(component_name.component_name, version, wasm_metadata)
+                    .await
+            }
+            ComponentSubcommand::Inspect { component } => self.cmd_inspect(component).await,
+            ComponentSubcommand::Publish {
+                component_name,
+                oci,
+                force_build,
+            } => {
+                self.cmd_publish(component_name.component_name, oci, force_build)
+                    .await
+            }
+            ComponentSubcommand::CheckCompat {
+                component_name,
+                against,
+            } => self.cmd_check_compat(component_name, against).await,
 
             ComponentSubcommand::UpdateWorkers {
                 component_name,
                 update_mode,
                 r#await,
+                rollout,
             } => {
-                self.cmd_update_workers(component_name.component_name, update_mode, r#await)
-                    .await
+                self.cmd_update_workers(
+                    component_name.component_name,
+                    update_mode,
+                    r#await,
+                    rollout,
+                )
+                .await
             }
             ComponentSubcommand::RedeployWorkers { component_name } => {
                 self.cmd_redeploy_workers(component_name.component_name)
@@ -390,6 +423,7 @@ impl ComponentCommandHandler {
         &self,
         component_name: Option<ComponentName>,
         version: Option<u64>,
+        wasm_metadata: bool,
     ) -> anyhow::Result<()> {
         let selected_components = self
             .must_select_components_by_app_dir_or_name(component_name.as_ref())
@@ -424,7 +458,14 @@ impl ComponentCommandHandler {
                 .await?;
 
             if let Some(component) = component {
-                component_views.push(ComponentView::new(self.ctx.show_sensitive(), component));
+                let wasm_metadata = if wasm_metadata {
+                    self.server_component_wasm_metadata(&component).await
+                } else {
+                    None
+                };
+                let mut component_view = ComponentView::new(self.ctx.show_sensitive(), component);
+                component_view.wasm_metadata = wasm_metadata;
+                component_views.push(component_view);
             }
         }
 
@@ -492,17 +533,452 @@ impl ComponentCommandHandler {
         Ok(())
     }
 
+    async fn cmd_inspect(&self, component: String) -> anyhow::Result<()> {
+        let inspect_source = match ComponentInspectSource::parse(&component) {
+            Ok(inspect_source) => inspect_source,
+            Err(err) => {
+                log_error(format!("{err:#}"));
+                bail!(NonSuccessfulExit);
+            }
+        };
+
+        // NOTE: the temp dir has to outlive the inspection of the downloaded component
+        let (source, wasm_path, _temp_dir) = match inspect_source {
+            ComponentInspectSource::LocalWasm(local_path) => (component, local_path, None),
+            ComponentInspectSource::Deployed {
+                component_name,
+                version,
+            } => {
+                let selected_components = self
+                    .must_select_components_by_app_dir_or_name(Some(&component_name))
+                    .await?;
+                let component_name = &selected_components.component_names[0];
+
+                let Some(component) = self
+                    .component(
+                        selected_components.project.as_ref(),
+                        component_name.into(),
+                        version.map(|version| version.into()),
+                    )
+                    .await?
+                else {
+                    log_error(format!(
+                        "Component {} not found",
+                        component.log_color_error_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                };
+
+                let clients = self.ctx.golem_clients().await?;
+                let wasm = clients
+                    .component
+                    .download_component(
+                        &component.versioned_component_id.component_id,
+                        Some(component.versioned_component_id.version),
+                    )
+                    .await?;
+
+                let temp_dir = tempfile::Builder::new()
+                    .prefix("golem-cli-inspect")
+                    .tempdir()
+                    .context("Failed to create temporary dir for component inspection")?;
+                let wasm_path = temp_dir.path().join("component.wasm");
+                fs::write(&wasm_path, &wasm)?;
+
+                (
+                    format!(
+                        "{}@{}",
+                        component.component_name.0, component.versioned_component_id.version
+                    ),
+                    wasm_path,
+                    Some(temp_dir),
+                )
+            }
+        };
+
+        let wasm = fs::read(&wasm_path)?;
+        let decoded_wasm = wit_parser::decoding::decode(&wasm).with_context(|| {
+            anyhow!(
+                "Failed to decode WASM component: {}",
+                wasm_path.log_color_highlight()
+            )
+        })?;
+        let resolved_wit = ResolvedWitDir::from_wasm(&wasm_path, &decoded_wasm);
+
+        let used_interfaces = resolved_wit.used_interfaces()?;
+        let golem_packages = used_interfaces
+            .iter()
+            .filter(|(_, package_name, _)| package_name.namespace == "golem")
+            .map(|(_, package_name, _)| package_name.to_string())
+            .collect::<BTreeSet<_>>();
+        let used_interfaces = used_interfaces
+            .iter()
+            .map(|(interface_id, _, _)| resolved_wit.resolve.id_of(*interface_id))
+            .collect::<Option<BTreeSet<_>>>()
+            .ok_or_else(|| anyhow!("Failed to get used interface names"))?;
+
+        let agent_types = if resolved_wit.is_agent()? {
+            extract_agent_types(&wasm_path).await?
+        } else {
+            vec![]
+        };
+
+        self.ctx.log_handler().log_view(&ComponentInspectView {
+            source,
+            exports: resolved_wit
+                .exported_functions()?
+                .iter()
+                .map(|function| function.to_string())
+                .collect(),
+            imports: resolved_wit.imports()?,
+            used_interfaces: used_interfaces.into_iter().collect(),
+            golem_packages: golem_packages.into_iter().collect(),
+            agent_types,
+            wasm_metadata: ComponentWasmMetadataView::from_wasm(&wasm)?,
+        });
+
+        Ok(())
+    }
+
+    async fn cmd_check_compat(
+        &self,
+        component_name: ComponentOptionalComponentNames,
+        against: Option<String>,
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .app_handler()
+            .build(
+                component_name.component_name,
+                None,
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let selected_component_names = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            app_ctx
+                .some_or_err()?
+                .selected_component_names()
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+        };
+        let build_profile = self.ctx.build_profile().cloned();
+        let project = self
+            .ctx
+            .cloud_project_handler()
+            .opt_select_project(None)
+            .await?;
+
+        let mut any_incompatible = false;
+        for component_name in &selected_component_names {
+            if !self
+                .is_deployable(component_name, build_profile.as_ref())
+                .await?
+            {
+                continue;
+            }
+
+            match self
+                .check_compatibility(
+                    project.as_ref(),
+                    component_name,
+                    build_profile.as_ref(),
+                    against.as_deref(),
+                )
+                .await?
+            {
+                Some(view) => {
+                    any_incompatible |= view.level == CompatibilityLevel::Incompatible;
+                    self.ctx.log_handler().log_view(&view);
+                    logln("");
+                }
+                None => {
+                    log_warn_action(
+                        "Skipping",
+                        format!(
+                            "compatibility check of component {}, no deployed version found",
+                            component_name.as_str().log_color_highlight()
+                        ),
+                    );
+                }
+            }
+        }
+
+        if any_incompatible {
+            log_error("Incompatible changes found");
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok(())
+    }
+
+    /// Compares the exports of the built component with the deployed one, or with the one
+    /// selected by `against` (version or local WASM path), returns None if no deployed component is found
+    async fn check_compatibility(
+        &self,
+        project: Option<&ProjectRefAndId>,
+        component_name: &AppComponentName,
+        build_profile: Option<&BuildProfileName>,
+        against: Option<&str>,
+    ) -> anyhow::Result<Option<ComponentCompatibilityView>> {
+        let against_version = match against {
+            Some(against) => match against.parse::<u64>() {
+                Ok(version) => Some(version),
+                Err(_) => {
+                    let current_exports = analysed_exports(&PathBuf::from(against))?;
+                    return Ok(Some(ComponentCompatibilityView::new(
+                        ComponentName::from(component_name.as_str()),
+                        against.to_string(),
+                        check_exports_compatibility(
+                            &current_exports,
+                            &self
+                                .linked_wasm_exports(component_name, build_profile)
+                                .await?,
+                        ),
+                    )));
+                }
+            },
+            None => None,
+        };
+
+        let Some(component) = self
+            .component(
+                project,
+                (&ComponentName::from(component_name.as_str())).into(),
+                against_version.map(|version| version.into()),
+            )
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        Ok(Some(ComponentCompatibilityView::new(
+            component.component_name.clone(),
+            format!("version {}", component.versioned_component_id.version),
+            check_exports_compatibility(
+                component.metadata.exports(),
+                &self
+                    .linked_wasm_exports(component_name, build_profile)
+                    .await?,
+            ),
+        )))
+    }
+
+    async fn linked_wasm_exports(
+        &self,
+        component_name: &AppComponentName,
+        build_profile: Option<&BuildProfileName>,
+    ) -> anyhow::Result<Vec<AnalysedExport>> {
+        let linked_wasm = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            app_ctx
+                .some_or_err()?
+                .application
+                .component_linked_wasm(component_name, build_profile)
+        };
+        analysed_exports(&linked_wasm)
+    }
+
+    async fn is_deployable(
+        &self,
+        component_name: &AppComponentName,
+        build_profile: Option<&BuildProfileName>,
+    ) -> anyhow::Result<bool> {
+        let app_ctx = self.ctx.app_context_lock().await;
+        Ok(app_ctx
+            .some_or_err()?
+            .application
+            .component_properties(component_name, build_profile)
+            .is_deployable())
+    }
+
+    async fn check_compatibility_for_automatic_update(
+        &self,
+        project: Option<&ProjectRefAndId>,
+        component_names: &[AppComponentName],
+        build_profile: Option<&BuildProfileName>,
+        allow_incompatible: bool,
+    ) -> anyhow::Result<()> {
+        log_action("Checking", "compatibility for automatic worker updates");
+        let _indent = LogIndent::new();
+
+        let mut incompatible_component_names = Vec::new();
+        for component_name in component_names {
+            if !self.is_deployable(component_name, build_profile).await? {
+                continue;
+            }
+
+            let Some(view) = self
+                .check_compatibility(project, component_name, build_profile, None)
+                .await?
+            else {
+                continue;
+            };
+
+            match view.level {
+                CompatibilityLevel::Compatible => {}
+                CompatibilityLevel::Risky => {
+                    log_warn(format!(
+                        "Found risky changes in component {}",
+                        view.component_name.0.log_color_highlight()
+                    ));
+                    log_text_view(&view);
+                }
+                CompatibilityLevel::Incompatible => {
+                    log_text_view(&view);
+                    incompatible_component_names.push(view.component_name);
+                }
+            }
+        }
+
+        if !incompatible_component_names.is_empty() {
+            let component_names = incompatible_component_names
+                .iter()
+                .map(|component_name| component_name.0.log_color_highlight())
+                .join(", ");
+            if allow_incompatible {
+                log_warn(format!(
+                    "Automatic worker updates will most likely fail for component(s) with incompatible changes: {component_names}"
+                ));
+            } else {
+                log_error(format!(
+                    "Automatic worker updates will most likely fail for component(s) with incompatible changes: {component_names}"
+                ));
+                logln("");
+                logln(
+                    "Use '--update-workers manual' or '--redeploy-workers' to update the workers of incompatible components, or '--allow-incompatible' to update them automatically anyway.",
+                );
+                bail!(NonSuccessfulExit);
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_publish(
+        &self,
+        component_name: Option<ComponentName>,
+        reference: OciReference,
+        force_build: ForceBuildArg,
+    ) -> anyhow::Result<()> {
+        self.ctx
+            .app_handler()
+            .build(
+                component_name.into_iter().collect(),
+                Some(BuildArgs {
+                    step: vec![],
+                    force_build,
+                }),
+                &ApplicationComponentSelectMode::CurrentDir,
+            )
+            .await?;
+
+        let linked_wasm = {
+            let app_ctx = self.ctx.app_context_lock().await;
+            let app_ctx = app_ctx.some_or_err()?;
+            let selected_component_names = app_ctx.selected_component_names();
+
+            if selected_component_names.len() != 1 {
+                log_error("Publishing requires exactly one selected component!");
+                logln("");
+                logln(format!(
+                    "Selected components: {}",
+                    selected_component_names
+                        .iter()
+                        .map(|cn| cn.as_str().log_color_highlight())
+                        .join(", ")
+                ));
+                logln("");
+                logln("Specify the requested component name or switch to an application directory with exactly one component!");
+                logln("");
+                bail!(NonSuccessfulExit);
+            }
+
+            let component_name = selected_component_names.iter().next().unwrap();
+            app_ctx
+                .application
+                .component_linked_wasm(component_name, self.ctx.build_profile())
+        };
+
+        let wasm = fs::read(&linked_wasm)?;
+        let annotations = oci_annotations(&ComponentWasmMetadataView::from_wasm(&wasm)?);
+
+        log_action(
+            "Publishing",
+            format!(
+                "component {} to {}",
+                linked_wasm.log_color_highlight(),
+                reference.to_string().log_color_highlight()
+            ),
+        );
+
+        let digest = OciClient::new(self.ctx.file_download_client().clone())
+            .push_wasm(&reference, wasm, annotations)
+            .await?;
+
+        log_action(
+            "Published",
+            format!(
+                "component to {}, digest: {}",
+                reference.to_string().log_color_highlight(),
+                digest.log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    // NOTE: the metadata is optional extra information, so failures are only reported as warnings
+    async fn server_component_wasm_metadata(
+        &self,
+        component: &Component,
+    ) -> Option<ComponentWasmMetadataView> {
+        let wasm_metadata = async {
+            let clients = self.ctx.golem_clients().await?;
+
+            let wasm = clients
+                .component
+                .download_component(
+                    &component.versioned_component_id.component_id,
+                    Some(component.versioned_component_id.version),
+                )
+                .await
+                .map_service_error()?;
+
+            ComponentWasmMetadataView::from_wasm(&wasm)
+        }
+        .await;
+
+        match wasm_metadata {
+            Ok(wasm_metadata) => Some(wasm_metadata),
+            Err(err) => {
+                log_warn(format!(
+                    "Failed to read WASM metadata of component {}: {err:#}",
+                    component.component_name.0.log_color_highlight()
+                ));
+                None
+            }
+        }
+    }
+
     async fn cmd_update_workers(
         &self,
         component_name: Option<ComponentName>,
         update_mode: WorkerUpdateMode,
         await_update: bool,
+        rollout: WorkerRolloutArgs,
     ) -> anyhow::Result<()> {
         let components = self
             .components_for_update_or_redeploy(component_name)
             .await?;
-        self.update_workers_by_components(&components, update_mode, await_update)
-            .await?;
+        self.update_workers_by_components(
+            &components,
+            update_mode,
+            await_update,
+            &rollout.config(&self.ctx.update_or_redeploy().rollout),
+        )
+        .await?;
 
         Ok(())
     }
@@ -614,6 +1090,16 @@ impl ComponentCommandHandler {
         };
         let build_profile = self.ctx.build_profile().cloned();
 
+        if update_or_redeploy.update_workers == Some(WorkerUpdateMode::Automatic) {
+            self.check_compatibility_for_automatic_update(
+                project,
+                &selected_component_names,
+                build_profile.as_ref(),
+                update_or_redeploy.allow_incompatible,
+            )
+            .await?;
+        }
+
         let plugin_installation_handler = self.ctx.plugin_installation_handler();
 
         let components = {
@@ -649,8 +1135,13 @@ impl ComponentCommandHandler {
         };
 
         if let Some(update) = update_or_redeploy.update_workers {
-            self.update_workers_by_components(&components, update, true)
-                .await?;
+            self.update_workers_by_components(
+                &components,
+                update,
+                true,
+                &update_or_redeploy.worker_rollout(self.ctx.update_or_redeploy()),
+            )
+            .await?;
         } else if update_or_redeploy.redeploy_workers(self.ctx.update_or_redeploy()) {
             self.redeploy_workers_by_components(&components).await?;
         }
@@ -868,6 +1359,213 @@ impl ComponentCommandHandler {
         Ok(component)
     }
 
+    // NOTE: all of this is naive for now (as in performance, streaming, parallelism)
+    pub async fn promote_component(
+        &self,
+        source_clients: &GolemClients,
+        target_clients: &GolemClients,
+        component_name: &ComponentName,
+    ) -> anyhow::Result<Option<Component>> {
+        let Some(source_component) = self
+            .latest_component_by_name_with_clients(source_clients, None, component_name)
+            .await?
+        else {
+            log_warn(format!(
+                "Component {} is not deployed in the source profile, skipping",
+                component_name.0.log_color_highlight()
+            ));
+            return Ok(None);
+        };
+        let target_component = self
+            .latest_component_by_name_with_clients(target_clients, None, component_name)
+            .await?;
+
+        log_action(
+            "Downloading",
+            format!(
+                "component {}@{}",
+                component_name.0.log_color_highlight(),
+                source_component
+                    .versioned_component_id
+                    .version
+                    .to_string()
+                    .log_color_highlight()
+            ),
+        );
+        let source_downloaded =
+            DownloadedComponent::download(source_clients, &source_component).await?;
+        let source_diffable_component = source_downloaded.diffable(&source_component)?;
+
+        if let Some(target_component) = &target_component {
+            let target_diffable_component =
+                DownloadedComponent::download(target_clients, target_component)
+                    .await?
+                    .diffable(target_component)?;
+
+            if target_diffable_component == source_diffable_component {
+                log_skipping_up_to_date(format!(
+                    "promoting component {}",
+                    component_name.0.log_color_highlight()
+                ));
+                return Ok(Some(target_component.clone()));
+            }
+
+            log_warn_action(
+                "Found",
+                format!(
+                    "changes for component {}",
+                    component_name.0.log_color_highlight()
+                ),
+            );
+            {
+                let _indent = self.ctx.log_handler().nested_text_view_indent();
+                log_deploy_diff(&target_diffable_component, &source_diffable_component)?;
+            }
+        }
+
+        let temp_dir = tempfile::Builder::new()
+            .prefix("golem-cli-promote")
+            .tempdir()
+            .context("Failed to create temporary dir for component promotion")?;
+
+        let wasm_path = temp_dir.path().join("component.wasm");
+        fs::write(&wasm_path, &source_downloaded.wasm)?;
+        let wasm = File::open(&wasm_path)
+            .await
+            .with_context(|| anyhow!("Failed to open {}", wasm_path.display()))?;
+
+        let ifs_files = {
+            let mut files = Vec::with_capacity(source_downloaded.files.len());
+            for (file, (_, content)) in source_component.files.iter().zip(&source_downloaded.files)
+            {
+                let file_path = temp_dir
+                    .path()
+                    .join("files")
+                    .join(file.path.to_rel_string());
+                fs::write(&file_path, content)?;
+                files.push(InitialComponentFile {
+                    source: InitialComponentFileSource::new(
+                        Url::from_file_path(&file_path)
+                            .map_err(|_| {
+                                anyhow!("Failed to convert {} to URL", file_path.display())
+                            })?
+                            .as_str(),
+                        &file_path,
+                    )
+                    .map_err(|err| anyhow!(err))?,
+                    target: ComponentFilePathWithPermissions {
+                        path: file.path.clone(),
+                        permissions: file.permissions,
+                    },
+                    hash: None,
+                });
+            }
+
+            if files.is_empty() {
+                None
+            } else {
+                Some(
+                    IfsFileManager::new(self.ctx.file_download_client().clone())
+                        .build_files_archive(&files)
+                        .await?,
+                )
+            }
+        };
+        let ifs_properties = ifs_files.as_ref().map(|f| &f.properties);
+        let ifs_archive = match ifs_files.as_ref() {
+            Some(files) => Some(File::open(&files.archive_path).await.with_context(|| {
+                anyhow!(
+                    "Failed to open IFS archive: {}",
+                    files.archive_path.display()
+                )
+            })?),
+            None => None,
+        };
+
+        let dynamic_linking = server_component_dynamic_linking(&source_component);
+        let env = (!source_component.env.is_empty()).then(|| ComponentEnvCloud {
+            key_values: source_component.env.clone().into_iter().collect(),
+        });
+        let agent_types = source_component.metadata.agent_types().to_vec();
+        let agent_types = (!agent_types.is_empty()).then_some(AgentTypes { types: agent_types });
+
+        let component = match target_component {
+            Some(target_component) => {
+                log_action(
+                    "Updating",
+                    format!(
+                        "component {} in the target profile",
+                        component_name.0.log_color_highlight()
+                    ),
+                );
+
+                let component = target_clients
+                    .component
+                    .update_component(
+                        &target_component.versioned_component_id.component_id,
+                        Some(&source_component.component_type),
+                        wasm,
+                        ifs_properties,
+                        ifs_archive,
+                        dynamic_linking.as_ref(),
+                        env.as_ref(),
+                        agent_types.as_ref(),
+                    )
+                    .await
+                    .map_service_error()?;
+                let component = Component::from(component);
+
+                self.ctx
+                    .log_handler()
+                    .log_view(&ComponentUpdateView(ComponentView::new(
+                        self.ctx.show_sensitive(),
+                        component.clone(),
+                    )));
+
+                component
+            }
+            None => {
+                log_action(
+                    "Creating",
+                    format!(
+                        "component {} in the target profile",
+                        component_name.0.log_color_highlight()
+                    ),
+                );
+
+                let component = target_clients
+                    .component
+                    .create_component(
+                        &ComponentQuery {
+                            project_id: None,
+                            component_name: component_name.0.clone(),
+                        },
+                        wasm,
+                        Some(&source_component.component_type),
+                        ifs_properties,
+                        ifs_archive,
+                        dynamic_linking.as_ref(),
+                        env.as_ref(),
+                        agent_types.as_ref(),
+                    )
+                    .await
+                    .map_service_error()?;
+                let component = Component::from(component);
+
+                self.ctx
+                    .log_handler()
+                    .log_view(&ComponentCreateView(ComponentView::new(
+                        self.ctx.show_sensitive(),
+                        component.clone(),
+                    )));
+
+                component
+            }
+        };
+
+        Ok(Some(component))
+    }
+
     async fn components_for_update_or_redeploy(
         &self,
         component_name: Option<ComponentName>,
@@ -905,6 +1603,7 @@ impl ComponentCommandHandler {
         components: &[Component],
         update: WorkerUpdateMode,
         await_updates: bool,
+        rollout: &WorkerRolloutConfig,
     ) -> anyhow::Result<()> {
         if components.is_empty() {
             return Ok(());
@@ -924,6 +1623,7 @@ impl ComponentCommandHandler {
                     update,
                     component.versioned_component_id.version,
                     await_updates,
+                    rollout,
                 )
                 .await?;
             update_results.extend(result);
@@ -933,6 +1633,77 @@ impl ComponentCommandHandler {
         Ok(())
     }
 
+    pub async fn rollback_workers_by_components(
+        &self,
+        previous_components: &BTreeMap<String, Component>,
+        update: WorkerUpdateMode,
+    ) -> anyhow::Result<()> {
+        if previous_components.is_empty() {
+            return Ok(());
+        }
+
+        log_warn_action(
+            "Rolling back",
+            format!("updated workers using {update} mode"),
+        );
+        let _indent = LogIndent::new();
+
+        // Failing components do not stop rolling back the workers of the others
+        let mut update_results = TryUpdateAllWorkersResult::default();
+        let mut errors = Vec::<String>::new();
+        for previous_component in previous_components.values() {
+            let result = async {
+                let Some(latest_version) = self
+                    .latest_component_version_by_id(
+                        previous_component.versioned_component_id.component_id,
+                    )
+                    .await?
+                else {
+                    return Ok(None);
+                };
+
+                if latest_version == previous_component.versioned_component_id.version {
+                    return Ok(None);
+                }
+
+                self.ctx
+                    .worker_handler()
+                    .rollback_component_workers(
+                        &previous_component.component_name,
+                        previous_component.versioned_component_id.component_id,
+                        update,
+                        previous_component.versioned_component_id.version,
+                    )
+                    .await
+                    .map(Some)
+            }
+            .await;
+
+            match result {
+                Ok(Some(result)) => update_results.extend(result),
+                Ok(None) => {}
+                Err(error) => {
+                    log_error(format!(
+                        "Failed to roll back the workers of component {}: {error:#}",
+                        previous_component.component_name.0.log_color_highlight()
+                    ));
+                    errors.push(format!(
+                        "component {}: {error:#}",
+                        previous_component.component_name.0
+                    ));
+                }
+            }
+        }
+
+        self.ctx.log_handler().log_view(&update_results);
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(errors.join("\n")))
+        }
+    }
+
     pub async fn redeploy_workers_by_components(
         &self,
         components: &[Component],
@@ -1286,7 +2057,17 @@ impl ComponentCommandHandler {
         component_name: &ComponentName,
     ) -> anyhow::Result<Option<Component>> {
         let clients = self.ctx.golem_clients().await?;
+        self.latest_component_by_name_with_clients(clients, project, component_name)
+            .await
+    }
 
+    // NOTE: used for cross-profile operations, where the clients are not the selected ones
+    pub async fn latest_component_by_name_with_clients(
+        &self,
+        clients: &GolemClients,
+        project: Option<&ProjectRefAndId>,
+        component_name: &ComponentName,
+    ) -> anyhow::Result<Option<Component>> {
         let result = clients
             .component
             .search_components(&ComponentSearchCloud {
@@ -1598,6 +2379,26 @@ struct ComponentDeployProperties {
     env: Option<HashMap<String, String>>,
 }
 
+fn analysed_exports(wasm_path: &Path) -> anyhow::Result<Vec<AnalysedExport>> {
+    let wasm = fs::read(wasm_path)?;
+    let component = golem_wasm_ast::component::Component::<IgnoreAllButMetadata>::from_bytes(&wasm)
+        .map_err(|err| {
+            anyhow!(
+                "Failed to parse WASM component {}: {err}",
+                wasm_path.log_color_highlight()
+            )
+        })?;
+    AnalysisContext::new(component)
+        .get_top_level_exports()
+        .map_err(|err| {
+            anyhow!(
+                "Failed to analyse exports of WASM component {}: {}",
+                wasm_path.log_color_highlight(),
+                err.reason
+            )
+        })
+}
+
 fn component_deploy_properties(
     app_ctx: &mut ApplicationContext,
     component_name: &AppComponentName,
@@ -1613,7 +2414,24 @@ fn component_deploy_properties(
         .component_type()
         .as_deployable_component_type()
         .ok_or_else(|| anyhow!("Component {component_name} is not deployable"))?;
-    let files = component_properties.files.clone();
+    // NOTE: unpinned remote and OCI files are verified against the lock file, if they are recorded there
+    let files = component_properties
+        .files
+        .iter()
+        .cloned()
+        .map(|mut file| {
+            if file.hash.is_none() {
+                if file.source.is_remote() {
+                    file.hash = app_ctx
+                        .remote_components()
+                        .locked_hash(file.source.as_url())?;
+                } else if let Some(reference) = file.source.oci_reference() {
+                    file.hash = app_ctx.remote_components().locked_oci_hash(&reference)?;
+                }
+            }
+            Ok(file)
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
     let env = (!component_properties.env.is_empty()).then(|| component_properties.env.clone());
     let dynamic_linking = app_component_dynamic_linking(app_ctx, component_name)?;
 
@@ -1680,3 +2498,93 @@ fn app_component_dynamic_linking(
         }))
     }
 }
+
+// Component WASM and IFS file contents downloaded from the server, used for promoting components
+// between profiles
+struct DownloadedComponent {
+    wasm: Vec<u8>,
+    files: Vec<(String, Vec<u8>)>,
+}
+
+impl DownloadedComponent {
+    async fn download(clients: &GolemClients, component: &Component) -> anyhow::Result<Self> {
+        let component_id = component.versioned_component_id.component_id;
+        let component_version = component.versioned_component_id.version;
+
+        let wasm = clients
+            .component
+            .download_component(&component_id, Some(component_version))
+            .await?
+            .to_vec();
+
+        let mut files = Vec::with_capacity(component.files.len());
+        for file in &component.files {
+            let target_path = file.path.to_rel_string();
+            let content = clients
+                .component
+                .download_component_file(
+                    &component_id,
+                    &component_version.to_string(),
+                    &target_path,
+                )
+                .await?
+                .to_vec();
+            files.push((target_path, content));
+        }
+
+        Ok(Self { wasm, files })
+    }
+
+    fn diffable(&self, component: &Component) -> anyhow::Result<DiffableComponent> {
+        let files = component
+            .files
+            .iter()
+            .zip(self.files.iter())
+            .map(|(file, (target_path, content))| {
+                (
+                    target_path.clone(),
+                    DiffableComponentFile {
+                        hash: blake3::hash(content).to_hex().to_string(),
+                        permissions: file.permissions,
+                    },
+                )
+            })
+            .collect();
+
+        DiffableComponent::from_server(
+            false,
+            component,
+            blake3::hash(&self.wasm).to_hex().to_string(),
+            files,
+        )
+    }
+}
+
+fn server_component_dynamic_linking(component: &Component) -> Option<DynamicLinkingOss> {
+    let dynamic_linking = component.metadata.dynamic_linking();
+    if dynamic_linking.is_empty() {
+        None
+    } else {
+        Some(DynamicLinkingOss {
+            dynamic_linking: dynamic_linking
+                .iter()
+                .map(|(name, link)| {
+                    (
+                        name.clone(),
+                        match link {
+                            golem_common::model::component_metadata::DynamicLinkedInstance::WasmRpc(
+                                links,
+                            ) => DynamicLinkedInstanceOss::WasmRpc(DynamicLinkedWasmRpcOss {
+                                targets: links
+                                    .targets
+                                    .iter()
+                                    .map(|(resource, target)| (resource.clone(), target.clone()))
+                                    .collect(),
+                            }),
+                        },
+                    )
+                })
+                .collect(),
+        })
+    }
+}
@@ -22,7 +22,7 @@ use crate::model::app::{
 use crate::model::component::AppComponentType;
 use crate::model::text::fmt::{log_error, log_warn};
 use crate::model::{ComponentName, Format, NewInteractiveApp, WorkerName};
-use anyhow::bail;
+use anyhow::{anyhow, bail};
 use colored::Colorize;
 use golem_client::model::Account;
 use golem_client::model::HttpApiDefinitionRequest;
@@ -30,7 +30,7 @@ use golem_common::model::ComponentVersion;
 use golem_templates::model::{ComposableAppGroupName, GuestLanguage, PackageName};
 use inquire::error::InquireResult;
 use inquire::validator::{ErrorMessage, Validation};
-use inquire::{Confirm, CustomType, InquireError, Select, Text};
+use inquire::{Confirm, CustomType, InquireError, Password, Select, Text};
 use itertools::Itertools;
 use std::collections::HashSet;
 use std::fmt::{Display, Formatter};
@@ -83,6 +83,31 @@ impl InteractiveHandler {
         )
     }
 
+    // NOTE: static because happens during authentication, outside of handlers
+    pub fn prompt_credential_store_passphrase(
+        env_var_name: &str,
+        new_store: bool,
+    ) -> anyhow::Result<String> {
+        let help_message = format!(
+            "To avoid this prompt the passphrase can be provided using the {env_var_name} environment variable."
+        );
+        let prompt = Password::new("Credential store passphrase:").with_help_message(&help_message);
+        // A new store is encrypted with the entered passphrase, so a typo would make it unreadable
+        let prompt = if new_store {
+            prompt.with_custom_confirmation_message("Confirm credential store passphrase:")
+        } else {
+            prompt.without_confirmation()
+        };
+
+        match prompt.prompt().none_if_not_interactive()? {
+            Some(passphrase) => Ok(passphrase),
+            None => bail!(
+                "The credential store passphrase is required, but the current input device is not an interactive one, set {} instead",
+                env_var_name.log_color_highlight()
+            ),
+        }
+    }
+
     pub fn confirm_auto_deploy_component(
         &self,
         component_name: &ComponentName,
@@ -109,6 +134,59 @@ impl InteractiveHandler {
         )
     }
 
+    pub fn confirm_deploy_rollback(&self) -> anyhow::Result<bool> {
+        self.confirm(
+            true,
+            format!(
+                "Deployment failed, do you want to {} HTTP APIs and updated workers to the previous versions?",
+                "roll back".log_color_warn(),
+            ),
+            None,
+        )
+    }
+
+    pub fn confirm_cancel_pending_invocations(
+        &self,
+        number_of_invocations: usize,
+    ) -> anyhow::Result<bool> {
+        self.confirm(
+            true,
+            format!(
+                "Do you want to {} {} pending invocation(s)?",
+                "cancel".log_color_warn(),
+                number_of_invocations.to_string().log_color_highlight()
+            ),
+            None,
+        )
+    }
+
+    pub fn confirm_continue_worker_rollout(
+        &self,
+        component_name: &ComponentName,
+        remaining_workers: usize,
+    ) -> anyhow::Result<bool> {
+        // NOTE: pausing is meant for a manual decision, so it is not auto confirmed by --yes
+        if self.ctx.yes() {
+            log_warn(format!(
+                "Worker update failure threshold exceeded for component {}, paused rollouts are not continued when using the {} flag",
+                component_name.0.log_color_highlight(),
+                "--yes".log_color_highlight(),
+            ));
+            return Ok(false);
+        }
+
+        self.confirm(
+            false,
+            format!(
+                "Worker update failure threshold exceeded for component {}, do you want to {} the rollout for the remaining {} worker(s)?",
+                component_name.0.log_color_highlight(),
+                "continue".log_color_warn(),
+                remaining_workers.to_string().log_color_highlight(),
+            ),
+            None,
+        )
+    }
+
     pub fn confirm_undeploy_api_from_sites_for_redeploy(
         &self,
         api: &str,
@@ -251,6 +329,7 @@ impl InteractiveHandler {
             custom_cloud_url: cloud_service_url,
             custom_worker_url: worker_service_url,
             allow_insecure: false,
+            project: None,
             config: ProfileConfig { default_format },
             auth,
         };
@@ -420,9 +499,10 @@ impl InteractiveHandler {
             (None, Some(target_component_file), None) => BinaryComponentSource::LocalFile {
                 path: target_component_file,
             },
-            (None, None, Some(target_component_url)) => BinaryComponentSource::Url {
-                url: target_component_url,
-            },
+            (None, None, Some(target_component_url)) => {
+                BinaryComponentSource::from_url(target_component_url.as_str())
+                    .map_err(|err| anyhow!(err))?
+            }
             (Some(target_component_name), None, None) => {
                 if !component_names.contains(&target_component_name) {
                     log_error(format!(
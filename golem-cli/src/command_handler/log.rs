@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::context::Context;
+use crate::model::records::{ndjson_lines, CsvRecords};
 use crate::model::text::fmt::{NestedTextViewIndent, TextView};
 use crate::model::Format;
 use serde::de::DeserializeOwned;
@@ -29,17 +30,15 @@ impl LogHandler {
     }
 
     pub fn log_view<View: TextView + Serialize + DeserializeOwned>(&self, view: &View) {
-        match self.ctx.format() {
-            Format::Json => {
-                println!("{}", serde_json::to_string(view).unwrap());
-            }
-            Format::Yaml => {
-                // TODO: handle "streaming" optionally
-                println!("---\n{}", serde_yaml::to_string(view).unwrap());
-            }
-            Format::Text => {
-                view.log();
-            }
+        log_view(self.ctx.format(), &mut CsvRecords::default(), view);
+    }
+
+    /// Logger for views arriving page by page, line based formats are streamed as the pages
+    /// arrive, using a single CSV header for all pages
+    pub fn view_pages(&self) -> ViewPagesLogger {
+        ViewPagesLogger {
+            format: self.ctx.format(),
+            csv: CsvRecords::default(),
         }
     }
 
@@ -47,3 +46,43 @@ impl LogHandler {
         NestedTextViewIndent::new(self.ctx.format())
     }
 }
+
+pub struct ViewPagesLogger {
+    format: Format,
+    csv: CsvRecords,
+}
+
+impl ViewPagesLogger {
+    pub fn log_page<View: TextView + Serialize + DeserializeOwned>(&mut self, view: &View) {
+        log_view(self.format, &mut self.csv, view);
+    }
+}
+
+fn log_view<View: TextView + Serialize + DeserializeOwned>(
+    format: Format,
+    csv: &mut CsvRecords,
+    view: &View,
+) {
+    match format {
+        Format::Json => {
+            println!("{}", serde_json::to_string(view).unwrap());
+        }
+        Format::Yaml => {
+            // TODO: handle "streaming" optionally
+            println!("---\n{}", serde_yaml::to_string(view).unwrap());
+        }
+        Format::Csv => {
+            for line in csv.lines(&view.records()) {
+                println!("{line}");
+            }
+        }
+        Format::Ndjson => {
+            for line in ndjson_lines(&view.records()) {
+                println!("{line}");
+            }
+        }
+        Format::Text => {
+            view.log();
+        }
+    }
+}
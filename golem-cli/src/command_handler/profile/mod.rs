@@ -14,18 +14,30 @@
 
 pub mod config;
 
-use crate::command::profile::ProfileSubcommand;
+use crate::command::profile::{ProfileSubcommand, ProfileUnsetField};
 use crate::command_handler::Handlers;
 use crate::config::{
-    AuthenticationConfig, Config, NamedProfile, Profile, ProfileConfig, ProfileName,
+    AuthenticationConfig, ClientConfig, Config, CredentialStoreConfig,
+    EncryptedFileCredentialStoreConfig, HelperCredentialStoreConfig, NamedProfile, OAuth2Flow,
+    Profile, ProfileConfig, ProfileName, ProfilesExport, ResolvedAuthenticationConfig,
+    StoredAuthenticationConfig,
 };
-use crate::context::Context;
+use crate::context::{new_auth, Context};
+use crate::credentials::{
+    erase_authentication_config, move_authentication_config, resolve_authentication_config,
+    store_authentication_config,
+};
+use crate::error::service::AnyhowMapServiceError;
 use crate::error::NonSuccessfulExit;
+use crate::fs;
 use crate::log::{log_action, log_warn_action, LogColorize};
 use crate::model::text::fmt::log_error;
-use crate::model::{Format, ProfileView};
-use anyhow::bail;
+use crate::model::text::profile::ProfileWhoamiView;
+use crate::model::{Format, ProfileView, ProjectReference};
+use anyhow::{anyhow, bail, Context as AnyhowContext};
+use golem_client::api::AccountClient;
 use std::collections::BTreeMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use url::Url;
 use uuid::Uuid;
@@ -50,6 +62,9 @@ impl ProfileCommandHandler {
                 default_format,
                 allow_insecure,
                 static_token,
+                encrypted_credentials,
+                credential_identity_file,
+                credential_helper,
             } => self.cmd_new(
                 name,
                 set_active,
@@ -59,11 +74,47 @@ impl ProfileCommandHandler {
                 default_format,
                 allow_insecure,
                 static_token,
+                encrypted_credentials,
+                credential_identity_file,
+                credential_helper,
             ),
             ProfileSubcommand::List => self.cmd_list(),
             ProfileSubcommand::Switch { profile_name } => self.cmd_switch(profile_name),
             ProfileSubcommand::Get { profile_name } => self.cmd_get(profile_name),
             ProfileSubcommand::Delete { profile_name } => self.cmd_delete(profile_name),
+            ProfileSubcommand::Update {
+                profile_name,
+                component_url,
+                worker_url,
+                cloud_url,
+                project,
+                allow_insecure,
+                unset,
+            } => self.cmd_update(
+                profile_name,
+                component_url,
+                worker_url,
+                cloud_url,
+                project,
+                allow_insecure,
+                unset,
+            ),
+            ProfileSubcommand::Rename {
+                profile_name,
+                new_profile_name,
+            } => self.cmd_rename(profile_name, new_profile_name),
+            ProfileSubcommand::Export {
+                profile_name,
+                output,
+                include_secrets,
+            } => self.cmd_export(profile_name, output, include_secrets),
+            ProfileSubcommand::Import { file, overwrite } => self.cmd_import(file, overwrite),
+            ProfileSubcommand::Login {
+                profile_name,
+                device,
+            } => self.cmd_login(profile_name, device).await,
+            ProfileSubcommand::Logout { profile_name } => self.cmd_logout(profile_name).await,
+            ProfileSubcommand::Whoami { profile_name } => self.cmd_whoami(profile_name).await,
             ProfileSubcommand::Config {
                 profile_name,
                 subcommand,
@@ -86,8 +137,11 @@ impl ProfileCommandHandler {
         default_format: Format,
         allow_insecure: bool,
         static_token: Option<Uuid>,
+        encrypted_credentials: bool,
+        credential_identity_file: Option<PathBuf>,
+        credential_helper: Option<String>,
     ) -> anyhow::Result<()> {
-        let (name, profile, set_active) = match name {
+        let (name, mut profile, set_active) = match name {
             Some(name) => {
                 if name.is_builtin() {
                     log_error("The requested profile name {} is a builtin profile. Please choose another profile name!");
@@ -106,6 +160,7 @@ impl ProfileCommandHandler {
                     custom_cloud_url: cloud_url,
                     custom_worker_url: worker_url,
                     allow_insecure,
+                    project: None,
                     config: ProfileConfig { default_format },
                     auth,
                 };
@@ -115,6 +170,38 @@ impl ProfileCommandHandler {
             None => self.ctx.interactive_handler().create_profile()?,
         };
 
+        let credential_store = match (encrypted_credentials, credential_helper) {
+            (true, _) => Some(CredentialStoreConfig::EncryptedFile(
+                EncryptedFileCredentialStoreConfig {
+                    identity_file: credential_identity_file,
+                },
+            )),
+            (false, Some(command)) => {
+                Some(CredentialStoreConfig::Helper(HelperCredentialStoreConfig {
+                    command,
+                }))
+            }
+            (false, None) => None,
+        };
+
+        if let Some(credential_store) = credential_store {
+            let stored = StoredAuthenticationConfig {
+                store: credential_store,
+                key: name.0.clone(),
+            };
+
+            log_action(
+                "Storing",
+                format!(
+                    "credentials for profile {} in the credential store",
+                    name.0.log_color_highlight()
+                ),
+            );
+            store_authentication_config(self.ctx.config_dir(), &stored, &profile.auth)?;
+
+            profile.auth = AuthenticationConfig::Stored(stored);
+        }
+
         log_action(
             "Creating",
             format!("new profile: {}", name.0.log_color_highlight()),
@@ -212,6 +299,18 @@ impl ProfileCommandHandler {
             bail!(NonSuccessfulExit);
         }
 
+        if let Some(NamedProfile {
+            profile:
+                Profile {
+                    auth: AuthenticationConfig::Stored(stored),
+                    ..
+                },
+            ..
+        }) = Config::get_profile(self.ctx.config_dir(), &profile_name)?
+        {
+            erase_authentication_config(self.ctx.config_dir(), &stored)?;
+        }
+
         Config::delete_profile(&profile_name, self.ctx.config_dir())?;
 
         log_warn_action(
@@ -221,4 +320,365 @@ impl ProfileCommandHandler {
 
         Ok(())
     }
+
+    fn cmd_update(
+        &self,
+        profile_name: ProfileName,
+        component_url: Option<Url>,
+        worker_url: Option<Url>,
+        cloud_url: Option<Url>,
+        project: Option<ProjectReference>,
+        allow_insecure: Option<bool>,
+        unset: Vec<ProfileUnsetField>,
+    ) -> anyhow::Result<()> {
+        if profile_name.is_builtin() {
+            log_error(format!(
+                "Cannot update builtin profile: {}",
+                profile_name.0.log_color_error_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        let mut profile = self.global_profile(Some(profile_name))?;
+
+        for field in unset {
+            match field {
+                ProfileUnsetField::ComponentUrl => profile.profile.custom_url = None,
+                ProfileUnsetField::WorkerUrl => profile.profile.custom_worker_url = None,
+                ProfileUnsetField::CloudUrl => profile.profile.custom_cloud_url = None,
+                ProfileUnsetField::Project => profile.profile.project = None,
+            }
+        }
+
+        if component_url.is_some() {
+            profile.profile.custom_url = component_url;
+        }
+        if worker_url.is_some() {
+            profile.profile.custom_worker_url = worker_url;
+        }
+        if cloud_url.is_some() {
+            profile.profile.custom_cloud_url = cloud_url;
+        }
+        if let Some(project) = project {
+            profile.profile.project = Some(project.to_string());
+        }
+        if let Some(allow_insecure) = allow_insecure {
+            profile.profile.allow_insecure = allow_insecure;
+        }
+
+        log_action(
+            "Updating",
+            format!("profile {}", profile.name.0.log_color_highlight()),
+        );
+        Config::set_profile(profile.name.clone(), profile.profile, self.ctx.config_dir())?;
+
+        self.cmd_get(Some(profile.name))
+    }
+
+    fn cmd_rename(
+        &self,
+        profile_name: ProfileName,
+        new_profile_name: ProfileName,
+    ) -> anyhow::Result<()> {
+        for profile_name in [&profile_name, &new_profile_name] {
+            if profile_name.is_builtin() {
+                log_error(format!(
+                    "Cannot rename from or to builtin profile: {}",
+                    profile_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+
+        let config = Config::from_dir(self.ctx.config_dir())?;
+        let Some(profile) = config.profiles.get(&profile_name).cloned() else {
+            log_error(format!(
+                "Profile {} not found",
+                profile_name.0.log_color_error_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        };
+        if config.profiles.contains_key(&new_profile_name) {
+            log_error(format!(
+                "Profile {} already exists",
+                new_profile_name.0.log_color_error_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        Config::rename_profile(
+            &profile_name,
+            new_profile_name.clone(),
+            self.ctx.config_dir(),
+        )?;
+
+        // NOTE: stored credentials are keyed by the profile name, so they are moved too, otherwise
+        //       a new profile with the old name would share them
+        if let AuthenticationConfig::Stored(stored) = &profile.auth {
+            if stored.key == profile_name.0 {
+                log_action(
+                    "Moving",
+                    format!(
+                        "stored credentials of profile {}",
+                        profile_name.0.log_color_highlight()
+                    ),
+                );
+
+                let renamed_stored = move_authentication_config(
+                    self.ctx.config_dir(),
+                    stored,
+                    new_profile_name.0.clone(),
+                )?;
+                let mut profile = profile.clone();
+                profile.auth = AuthenticationConfig::Stored(renamed_stored);
+                Config::set_profile(new_profile_name.clone(), profile, self.ctx.config_dir())?;
+            }
+        }
+
+        log_action(
+            "Renamed",
+            format!(
+                "profile {} to {}",
+                profile_name.0.log_color_highlight(),
+                new_profile_name.0.log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    fn cmd_export(
+        &self,
+        profile_names: Vec<ProfileName>,
+        output: Option<PathBuf>,
+        include_secrets: bool,
+    ) -> anyhow::Result<()> {
+        let config = Config::from_dir(self.ctx.config_dir())?;
+
+        let profile_names = if profile_names.is_empty() {
+            config
+                .profiles
+                .keys()
+                .filter(|name| !name.is_builtin())
+                .cloned()
+                .collect::<Vec<_>>()
+        } else {
+            profile_names
+        };
+
+        let mut export = ProfilesExport::default();
+        for profile_name in profile_names {
+            let Some(mut profile) = config.profiles.get(&profile_name).cloned() else {
+                log_error(format!(
+                    "Profile {} not found",
+                    profile_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            };
+
+            // NOTE: stored credentials are always resolved, as stores are not portable
+            profile.auth = if include_secrets {
+                resolve_authentication_config(self.ctx.config_dir(), &profile.auth)?.into()
+            } else {
+                match &profile.auth {
+                    AuthenticationConfig::Static(_) => {
+                        log_warn_action(
+                            "Skipping",
+                            format!(
+                                "static token of profile {}, use {} to include it",
+                                profile_name.0.log_color_highlight(),
+                                "--include-secrets".log_color_highlight()
+                            ),
+                        );
+                    }
+                    AuthenticationConfig::OAuth2(_) | AuthenticationConfig::Stored(_) => {}
+                }
+                AuthenticationConfig::empty_oauth2()
+            };
+
+            export.profiles.insert(profile_name, profile);
+        }
+
+        let export = serde_json::to_string_pretty(&export)?;
+
+        match output {
+            Some(output) => {
+                log_action(
+                    "Exporting",
+                    format!(
+                        "profiles to {}",
+                        output.display().to_string().log_color_highlight()
+                    ),
+                );
+                fs::write_str(output, export)?;
+            }
+            None => println!("{export}"),
+        }
+
+        Ok(())
+    }
+
+    fn cmd_import(&self, file: PathBuf, overwrite: bool) -> anyhow::Result<()> {
+        let export: ProfilesExport = serde_json::from_str(&fs::read_to_string(&file)?)
+            .with_context(|| {
+                anyhow!(
+                    "Failed to deserialize exported profiles: {}",
+                    file.display().to_string().log_color_error_highlight()
+                )
+            })?;
+
+        let config = Config::from_dir(self.ctx.config_dir())?;
+
+        for profile_name in export.profiles.keys() {
+            if profile_name.is_builtin() {
+                log_error(format!(
+                    "Cannot import builtin profile: {}",
+                    profile_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+            if !overwrite && config.profiles.contains_key(profile_name) {
+                log_error(format!(
+                    "Profile {} already exists, use {} to overwrite it",
+                    profile_name.0.log_color_error_highlight(),
+                    "--overwrite".log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+
+        for (profile_name, profile) in export.profiles {
+            log_action(
+                "Importing",
+                format!("profile {}", profile_name.0.log_color_highlight()),
+            );
+            Config::set_profile(profile_name, profile, self.ctx.config_dir())?;
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_login(
+        &self,
+        profile_name: Option<ProfileName>,
+        device: bool,
+    ) -> anyhow::Result<()> {
+        let (profile, _) = self.oauth2_profile(profile_name)?;
+
+        log_action(
+            "Logging in",
+            format!("with profile {}", profile.name.0.log_color_highlight()),
+        );
+
+        let authentication = new_auth(&ClientConfig::from(&profile.profile))?
+            .login(
+                if device {
+                    OAuth2Flow::Device
+                } else {
+                    OAuth2Flow::Web
+                },
+                self.ctx.config_dir(),
+                &profile.name,
+            )
+            .await?;
+
+        log_action(
+            "Logged in",
+            format!(
+                "with account {}",
+                authentication.account_id().0.log_color_highlight()
+            ),
+        );
+
+        Ok(())
+    }
+
+    async fn cmd_logout(&self, profile_name: Option<ProfileName>) -> anyhow::Result<()> {
+        let (profile, auth_config) = self.oauth2_profile(profile_name)?;
+
+        let logged_out = new_auth(&ClientConfig::from(&profile.profile))?
+            .logout(&auth_config, self.ctx.config_dir(), &profile.name)
+            .await?;
+
+        if logged_out {
+            log_warn_action(
+                "Logged out",
+                format!("from profile {}", profile.name.0.log_color_highlight()),
+            );
+        } else {
+            log_warn_action(
+                "Skipping",
+                format!(
+                    "logging out, profile {} is not logged in",
+                    profile.name.0.log_color_highlight()
+                ),
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn cmd_whoami(&self, profile_name: Option<ProfileName>) -> anyhow::Result<()> {
+        let profile = self.global_profile(profile_name)?;
+
+        if !resolve_authentication_config(self.ctx.config_dir(), &profile.profile.auth)?
+            .is_logged_in()
+        {
+            log_error(format!(
+                "Profile {} is not logged in, use {} to log in",
+                profile.name.0.log_color_error_highlight(),
+                "golem profile login".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        let clients = self.ctx.golem_clients_for_profile(&profile.name).await?;
+        let account = clients
+            .account
+            .get_account(&clients.account_id().0)
+            .await
+            .map_service_error()?;
+
+        self.ctx.log_handler().log_view(&ProfileWhoamiView {
+            profile_name: profile.name,
+            account,
+            token_id: clients.token().id,
+            token_expires_at: clients.token().expires_at,
+        });
+
+        Ok(())
+    }
+
+    fn global_profile(&self, profile_name: Option<ProfileName>) -> anyhow::Result<NamedProfile> {
+        let profile_name = profile_name.unwrap_or_else(|| self.ctx.profile_name().clone());
+        match Config::get_profile(self.ctx.config_dir(), &profile_name)? {
+            Some(profile) => Ok(profile),
+            None => {
+                log_error(format!(
+                    "Profile {} not found",
+                    profile_name.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+    }
+
+    fn oauth2_profile(
+        &self,
+        profile_name: Option<ProfileName>,
+    ) -> anyhow::Result<(NamedProfile, ResolvedAuthenticationConfig)> {
+        let profile = self.global_profile(profile_name)?;
+
+        let auth_config =
+            resolve_authentication_config(self.ctx.config_dir(), &profile.profile.auth)?;
+        if let ResolvedAuthenticationConfig::Static(_) = auth_config {
+            log_error(format!(
+                "Profile {} uses a static token, logging in and out is only supported for OAuth2 authentication",
+                profile.name.0.log_color_error_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        Ok((profile, auth_config))
+    }
 }
@@ -0,0 +1,250 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::WorkerNameArg;
+use crate::command_handler::worker::WorkerCommandHandler;
+use crate::command_handler::Handlers;
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::fs;
+use crate::log::{log_action, LogColorize, LogIndent};
+use crate::model::component::Component;
+use crate::model::text::fmt::{format_worker_name_match, log_error};
+use crate::model::worker_files::{
+    local_download_path, normalize_worker_path, split_worker_path, WorkerDownloadedFileView,
+    WorkerFileKind, WorkerFileView, WorkerFilesDownloadView, WorkerFilesView,
+};
+use crate::model::WorkerName;
+use anyhow::bail;
+use golem_client::api::WorkerClient;
+use std::collections::VecDeque;
+use std::path::PathBuf;
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_files_ls(
+        &self,
+        worker_name: WorkerNameArg,
+        path: String,
+        recursive: bool,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let path = normalize_worker_path(&path);
+        let files = if recursive {
+            self.list_worker_files_recursive(&component, &worker_name, &path)
+                .await?
+        } else {
+            self.list_worker_files(&component, &worker_name, &path)
+                .await?
+        };
+
+        self.ctx.log_handler().log_view(&WorkerFilesView {
+            component_name: component.component_name,
+            worker_name,
+            path,
+            files,
+        });
+
+        Ok(())
+    }
+
+    pub(super) async fn cmd_files_get(
+        &self,
+        worker_name: WorkerNameArg,
+        path: String,
+        output: Option<PathBuf>,
+        recursive: bool,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let path = normalize_worker_path(&path);
+        let (is_dir, name) = match split_worker_path(&path) {
+            Some((parent, name)) => {
+                let Some(file) = self
+                    .list_worker_files(&component, &worker_name, &parent)
+                    .await?
+                    .into_iter()
+                    .find(|file| file.path == path)
+                else {
+                    log_error(format!(
+                        "File {} not found for worker {}",
+                        path.log_color_highlight(),
+                        format_worker_name_match(&worker_name_match)
+                    ));
+                    bail!(NonSuccessfulExit);
+                };
+                (file.is_dir(), name)
+            }
+            None => (true, worker_name.0.clone()),
+        };
+
+        let mut downloaded = Vec::new();
+        if is_dir {
+            if !recursive {
+                log_error(format!(
+                    "{} is a directory, use {} to download it",
+                    path.log_color_highlight(),
+                    "--recursive".log_color_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+
+            let local_root = output.unwrap_or_else(|| PathBuf::from(&name));
+
+            log_action(
+                "Downloading",
+                format!(
+                    "directory {} of worker {} to {}",
+                    path.log_color_highlight(),
+                    format_worker_name_match(&worker_name_match),
+                    local_root.display().to_string().log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
+
+            fs::create_dir_all(&local_root)?;
+            for file in self
+                .list_worker_files_recursive(&component, &worker_name, &path)
+                .await?
+            {
+                let local_path = local_download_path(&local_root, &path, &file.path);
+                if file.is_dir() {
+                    fs::create_dir_all(&local_path)?;
+                } else {
+                    downloaded.push(
+                        self.download_worker_file(&component, &worker_name, &file, local_path)
+                            .await?,
+                    );
+                }
+            }
+        } else {
+            let local_path = match output {
+                Some(output) if output.is_dir() => output.join(&name),
+                Some(output) => output,
+                None => PathBuf::from(&name),
+            };
+
+            log_action(
+                "Downloading",
+                format!(
+                    "file {} of worker {} to {}",
+                    path.log_color_highlight(),
+                    format_worker_name_match(&worker_name_match),
+                    local_path.display().to_string().log_color_highlight()
+                ),
+            );
+
+            let file = WorkerFileView {
+                path: path.clone(),
+                kind: WorkerFileKind::File,
+                size: None,
+                permissions: None,
+                last_modified: None,
+            };
+            downloaded.push(
+                self.download_worker_file(&component, &worker_name, &file, local_path)
+                    .await?,
+            );
+        }
+
+        self.ctx.log_handler().log_view(&WorkerFilesDownloadView {
+            component_name: component.component_name,
+            worker_name,
+            files: downloaded,
+        });
+
+        Ok(())
+    }
+
+    async fn list_worker_files(
+        &self,
+        component: &Component,
+        worker_name: &WorkerName,
+        path: &str,
+    ) -> anyhow::Result<Vec<WorkerFileView>> {
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .get_files(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                path,
+            )
+            .await
+            .map_service_error()?;
+
+        Ok(result
+            .nodes
+            .iter()
+            .map(|node| WorkerFileView::from_node(path, node))
+            .collect())
+    }
+
+    async fn list_worker_files_recursive(
+        &self,
+        component: &Component,
+        worker_name: &WorkerName,
+        path: &str,
+    ) -> anyhow::Result<Vec<WorkerFileView>> {
+        let mut files = Vec::new();
+        let mut dirs = VecDeque::from([path.to_string()]);
+        while let Some(dir) = dirs.pop_front() {
+            for file in self.list_worker_files(component, worker_name, &dir).await? {
+                if file.is_dir() {
+                    dirs.push_back(file.path.clone());
+                }
+                files.push(file);
+            }
+        }
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(files)
+    }
+
+    async fn download_worker_file(
+        &self,
+        component: &Component,
+        worker_name: &WorkerName,
+        file: &WorkerFileView,
+        local_path: PathBuf,
+    ) -> anyhow::Result<WorkerDownloadedFileView> {
+        let clients = self.ctx.golem_clients().await?;
+
+        let content = clients
+            .worker
+            .get_file_content(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &file.path,
+            )
+            .await
+            .map_service_error()?;
+
+        fs::write(&local_path, &content)?;
+
+        Ok(WorkerDownloadedFileView {
+            path: file.path.clone(),
+            local_path,
+            size: content.len() as u64,
+        })
+    }
+}
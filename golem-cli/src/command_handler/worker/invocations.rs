@@ -0,0 +1,226 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::WorkerNameArg;
+use crate::command_handler::worker::WorkerCommandHandler;
+use crate::command_handler::Handlers;
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::text::fmt::{format_worker_name_match, log_error, log_warn};
+use crate::model::worker_invocations::{
+    AwaitedInvocation, WorkerInvocationStatus, WorkerInvocationView, WorkerInvocationsView,
+};
+use crate::model::IdempotencyKey;
+use anyhow::bail;
+use golem_client::model::{InvokeResult, PublicOplogEntry, WorkerStatus};
+use std::time::{Duration, Instant};
+
+const RESULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_invocations(
+        &self,
+        worker_name: WorkerNameArg,
+        cancel_pending: bool,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let oplog = self
+            .worker_oplog(
+                component.versioned_component_id.component_id,
+                &worker_name,
+                None,
+                None,
+            )
+            .await?;
+
+        let view = WorkerInvocationsView {
+            component_name: component.component_name.clone(),
+            worker_name: worker_name.clone(),
+            invocations: WorkerInvocationView::from_oplog(&oplog),
+        };
+        self.ctx.log_handler().log_view(&view);
+
+        if !cancel_pending {
+            return Ok(());
+        }
+
+        let pending = view
+            .pending()
+            .map(|invocation| IdempotencyKey(invocation.idempotency_key.clone()))
+            .collect::<Vec<_>>();
+        if pending.is_empty() {
+            logln("");
+            log_action("Skipping", "cancel, no pending invocations found");
+            return Ok(());
+        }
+
+        logln("");
+        if !self
+            .ctx
+            .interactive_handler()
+            .confirm_cancel_pending_invocations(pending.len())?
+        {
+            bail!(NonSuccessfulExit);
+        }
+
+        log_warn_action(
+            "Canceling",
+            format!(
+                "pending invocations of worker {}",
+                format_worker_name_match(&worker_name_match)
+            ),
+        );
+        let _indent = LogIndent::new();
+        for idempotency_key in pending {
+            let canceled = self
+                .cancel_invocation(&component, &worker_name, &idempotency_key)
+                .await?;
+            if canceled {
+                log_action(
+                    "Canceled",
+                    format!("invocation {}", idempotency_key.0.log_color_highlight()),
+                );
+            } else {
+                log_warn_action(
+                    "Failed",
+                    format!(
+                        "to cancel invocation {}, invocation already started",
+                        idempotency_key.0.log_color_highlight()
+                    ),
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(super) async fn cmd_result(
+        &self,
+        worker_name: WorkerNameArg,
+        idempotency_key: IdempotencyKey,
+        wait: bool,
+        timeout: Option<Duration>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        if wait {
+            log_action(
+                "Waiting",
+                format!(
+                    "for invocation {} of worker {}",
+                    idempotency_key.0.log_color_highlight(),
+                    format_worker_name_match(&worker_name_match)
+                ),
+            );
+        }
+
+        let component_id = component.versioned_component_id.component_id;
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+        let mut oplog = Vec::<(u64, PublicOplogEntry)>::new();
+        let invocation = loop {
+            // Only the entries after the last seen one are fetched while waiting
+            let from_oplog_index = oplog.last().map(|(oplog_index, _)| oplog_index + 1);
+            oplog.extend(
+                self.worker_oplog(component_id, &worker_name, from_oplog_index, None)
+                    .await?,
+            );
+
+            let invocation = WorkerInvocationView::from_oplog(&oplog)
+                .into_iter()
+                .find(|invocation| invocation.idempotency_key == idempotency_key.0);
+            let worker_failed = match &invocation {
+                Some(invocation) if invocation.status == WorkerInvocationStatus::Failed => {
+                    matches!(
+                        self.worker_metadata(component_id, &component.component_name, &worker_name)
+                            .await?
+                            .status,
+                        WorkerStatus::Failed
+                    )
+                }
+                _ => false,
+            };
+
+            match AwaitedInvocation::new(invocation, worker_failed) {
+                AwaitedInvocation::Finished(invocation) => break invocation,
+                AwaitedInvocation::InProgress(status) if !wait => {
+                    log_warn(format!(
+                        "Invocation {} is {}, use {} to wait for the result",
+                        idempotency_key.0.log_color_highlight(),
+                        status.to_string().log_color_highlight(),
+                        "--wait".log_color_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+                AwaitedInvocation::NotFound if !wait => {
+                    log_error(format!(
+                        "Invocation {} not found",
+                        idempotency_key.0.log_color_error_highlight()
+                    ));
+                    bail!(NonSuccessfulExit);
+                }
+                _ => {}
+            }
+
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                log_error(format!(
+                    "Timed out waiting for invocation {}",
+                    idempotency_key.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+            tokio::time::sleep(RESULT_POLL_INTERVAL).await;
+        };
+
+        match invocation.status {
+            WorkerInvocationStatus::Completed => {
+                self.ctx
+                    .log_handler()
+                    .log_view(&InvokeResultView::new_invoke(
+                        idempotency_key,
+                        InvokeResult {
+                            result: invocation.response,
+                        },
+                        &component,
+                        &invocation.function_name,
+                    ));
+                Ok(())
+            }
+            WorkerInvocationStatus::Cancelled => {
+                log_error(format!(
+                    "Invocation {} was cancelled",
+                    idempotency_key.0.log_color_error_highlight()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+            _ => {
+                log_error(format!(
+                    "Invocation {} failed: {}",
+                    idempotency_key.0.log_color_error_highlight(),
+                    invocation.error.unwrap_or_default()
+                ));
+                bail!(NonSuccessfulExit);
+            }
+        }
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::{StreamArgs, WorkerNameArg};
+use crate::command_handler::worker::stream::WorkerConnection;
+use crate::command_handler::worker::stream_output::WorkerStreamOutput;
+use crate::command_handler::worker::WorkerCommandHandler;
+use crate::log::log_action;
+use crate::model::text::fmt::format_worker_name_match;
+use crate::model::worker_logs::{WorkerLogFilter, WorkerLogLevel};
+use crate::model::worker_query::TimeValue;
+use crate::model::{WorkerConnectOptions, WorkerName};
+use chrono::{DateTime, Utc};
+use futures_util::future::Either;
+use futures_util::{future, pin_mut};
+use golem_client::model::PublicOplogEntry;
+use golem_common::model::oplog::LogLevel as OplogLogLevel;
+use golem_common::model::{LogLevel, Timestamp};
+use uuid::Uuid;
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_logs(
+        &self,
+        worker_name: WorkerNameArg,
+        since: Option<TimeValue>,
+        until: Option<TimeValue>,
+        level: Option<WorkerLogLevel>,
+        context: Option<String>,
+        follow: bool,
+        stream_args: StreamArgs,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+        let component_id = component.versioned_component_id.component_id;
+
+        let now = Utc::now();
+        let mut connect_options = WorkerConnectOptions::from(stream_args);
+        connect_options.filter = WorkerLogFilter {
+            since: since.map(|since| since.resolve(now)),
+            until: until.map(|until| until.resolve(now)),
+            min_level: level,
+            context,
+        };
+
+        let mut from_oplog_index = match connect_options.filter.since {
+            Some(since) => {
+                self.first_oplog_index_since(component_id, &worker_name, since)
+                    .await?
+            }
+            None => 1,
+        };
+
+        // The connection is created before reading the oplog, so historical and live entries
+        // go through the same output, which drops the ones seen on both
+        let connection = if follow {
+            Some(
+                WorkerConnection::new(
+                    self.ctx.worker_service_url().clone(),
+                    self.ctx.auth_token().await?,
+                    component_id,
+                    worker_name.0.clone(),
+                    connect_options.clone(),
+                    self.ctx.allow_insecure(),
+                    self.ctx.format(),
+                    None,
+                )
+                .await?,
+            )
+        } else {
+            None
+        };
+        let output = match &connection {
+            Some(connection) => connection.output().clone(),
+            None => WorkerStreamOutput::new(connect_options, self.ctx.format()),
+        };
+
+        from_oplog_index = self
+            .emit_oplog_logs(&output, component_id, &worker_name, from_oplog_index)
+            .await?;
+        output.flush().await;
+
+        if let Some(connection) = connection {
+            log_action(
+                "Following",
+                format!(
+                    "live output of worker {}",
+                    format_worker_name_match(&worker_name_match)
+                ),
+            );
+
+            // Entries logged before the stream got (re)connected are only available from the
+            // oplog, so its tail is read again every time the connection is established
+            let connected = connection.connected().clone();
+            let catch_up = async {
+                loop {
+                    connected.notified().await;
+                    match self
+                        .emit_oplog_logs(&output, component_id, &worker_name, from_oplog_index)
+                        .await
+                    {
+                        Ok(next_oplog_index) => from_oplog_index = next_oplog_index,
+                        Err(err) => break err,
+                    }
+                }
+            };
+            let run = connection.run_forever();
+            pin_mut!(catch_up, run);
+            if let Either::Left((err, _)) = future::select(catch_up, run).await {
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Emits the log entries of the oplog starting from the given index, and returns the index
+    /// following the last read entry
+    async fn emit_oplog_logs(
+        &self,
+        output: &WorkerStreamOutput,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        from_oplog_index: u64,
+    ) -> anyhow::Result<u64> {
+        let mut next_oplog_index = from_oplog_index;
+        for (oplog_index, entry) in self
+            .worker_oplog(component_id, worker_name, Some(from_oplog_index), None)
+            .await?
+        {
+            next_oplog_index = oplog_index + 1;
+            let PublicOplogEntry::Log(params) = entry else {
+                continue;
+            };
+            match params.level {
+                OplogLogLevel::Stdout => output.emit_stdout(params.timestamp, params.message).await,
+                OplogLogLevel::Stderr => output.emit_stderr(params.timestamp, params.message).await,
+                level => {
+                    output
+                        .emit_log(
+                            params.timestamp,
+                            to_log_level(level),
+                            params.context,
+                            params.message,
+                        )
+                        .await
+                }
+            }
+        }
+        Ok(next_oplog_index)
+    }
+
+    /// Finds the first oplog index written at or after the given time by probing single entries,
+    /// so the entries before it do not have to be fetched
+    async fn first_oplog_index_since(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        since: DateTime<Utc>,
+    ) -> anyhow::Result<u64> {
+        // Entries up to lower are older, entries from upper are not
+        let mut lower = 0;
+        let mut upper = self.last_oplog_index(component_id, worker_name).await? + 1;
+        while upper - lower > 1 {
+            let middle = lower + (upper - lower) / 2;
+            let is_older = match self.oplog_entry(component_id, worker_name, middle).await? {
+                Some(entry) => {
+                    (oplog_entry_timestamp(&entry).to_millis() as i64) < since.timestamp_millis()
+                }
+                None => false,
+            };
+            if is_older {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+
+        Ok(upper)
+    }
+}
+
+fn oplog_entry_timestamp(entry: &PublicOplogEntry) -> Timestamp {
+    match entry {
+        PublicOplogEntry::Create(params) => params.timestamp,
+        PublicOplogEntry::ImportedFunctionInvoked(params) => params.timestamp,
+        PublicOplogEntry::ExportedFunctionInvoked(params) => params.timestamp,
+        PublicOplogEntry::ExportedFunctionCompleted(params) => params.timestamp,
+        PublicOplogEntry::Suspend(params) => params.timestamp,
+        PublicOplogEntry::Error(params) => params.timestamp,
+        PublicOplogEntry::NoOp(params) => params.timestamp,
+        PublicOplogEntry::Jump(params) => params.timestamp,
+        PublicOplogEntry::Interrupted(params) => params.timestamp,
+        PublicOplogEntry::Exited(params) => params.timestamp,
+        PublicOplogEntry::ChangeRetryPolicy(params) => params.timestamp,
+        PublicOplogEntry::BeginAtomicRegion(params) => params.timestamp,
+        PublicOplogEntry::EndAtomicRegion(params) => params.timestamp,
+        PublicOplogEntry::BeginRemoteWrite(params) => params.timestamp,
+        PublicOplogEntry::EndRemoteWrite(params) => params.timestamp,
+        PublicOplogEntry::PendingWorkerInvocation(params) => params.timestamp,
+        PublicOplogEntry::PendingUpdate(params) => params.timestamp,
+        PublicOplogEntry::SuccessfulUpdate(params) => params.timestamp,
+        PublicOplogEntry::FailedUpdate(params) => params.timestamp,
+        PublicOplogEntry::GrowMemory(params) => params.timestamp,
+        PublicOplogEntry::CreateResource(params) => params.timestamp,
+        PublicOplogEntry::DropResource(params) => params.timestamp,
+        PublicOplogEntry::DescribeResource(params) => params.timestamp,
+        PublicOplogEntry::Log(params) => params.timestamp,
+        PublicOplogEntry::Restart(params) => params.timestamp,
+        PublicOplogEntry::ActivatePlugin(params) => params.timestamp,
+        PublicOplogEntry::DeactivatePlugin(params) => params.timestamp,
+        PublicOplogEntry::Revert(params) => params.timestamp,
+        PublicOplogEntry::CancelInvocation(params) => params.timestamp,
+        PublicOplogEntry::StartSpan(params) => params.timestamp,
+        PublicOplogEntry::FinishSpan(params) => params.timestamp,
+        PublicOplogEntry::SetSpanAttribute(params) => params.timestamp,
+        PublicOplogEntry::ChangePersistenceLevel(params) => params.timestamp,
+        PublicOplogEntry::CreateAgentInstance(params) => params.timestamp,
+        PublicOplogEntry::DropAgentInstance(params) => params.timestamp,
+    }
+}
+
+fn to_log_level(level: OplogLogLevel) -> LogLevel {
+    match level {
+        OplogLogLevel::Trace => LogLevel::Trace,
+        OplogLogLevel::Debug => LogLevel::Debug,
+        OplogLogLevel::Info | OplogLogLevel::Stdout => LogLevel::Info,
+        OplogLogLevel::Warn => LogLevel::Warn,
+        OplogLogLevel::Error | OplogLogLevel::Stderr => LogLevel::Error,
+        OplogLogLevel::Critical => LogLevel::Critical,
+    }
+}
@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod files;
+mod invocations;
+mod logs;
+mod shell;
+mod snapshot;
 mod stream;
 mod stream_output;
+mod top;
 
 use crate::command::shared_args::{
     NewWorkerArgument, StreamArgs, WorkerFunctionArgument, WorkerFunctionName, WorkerNameArg,
 };
+use crate::command::worker::files::WorkerFilesSubcommand;
 use crate::command::worker::WorkerSubcommand;
 use crate::command_handler::worker::stream::WorkerConnection;
 use crate::command_handler::Handlers;
@@ -25,10 +32,16 @@ use crate::context::Context;
 use crate::error::service::{AnyhowMapServiceError, ServiceError};
 use crate::error::NonSuccessfulExit;
 use crate::fuzzy::{Error, FuzzySearch};
-use crate::log::{log_action, log_error_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::log::{
+    log_action, log_error_action, log_skipping_up_to_date, log_warn_action, logln, LogColorize,
+    LogIndent,
+};
 use crate::model::app::ApplicationComponentSelectMode;
 use crate::model::component::{function_params_types, show_exported_functions, Component};
-use crate::model::deploy::{TryUpdateAllWorkersResult, WorkerUpdateAttempt};
+use crate::model::deploy::{
+    TryUpdateAllWorkersResult, WorkerRolloutConfig, WorkerRolloutFailureAction,
+    WorkerRolloutStrategy, WorkerUpdateAttempt,
+};
 use crate::model::invoke_result_view::InvokeResultView;
 use crate::model::text::fmt::{
     format_export, format_worker_name_match, log_error, log_fuzzy_match, log_text_view, log_warn,
@@ -39,16 +52,20 @@ use crate::model::text::help::{
 };
 use crate::model::text::worker::{WorkerCreateView, WorkerGetView};
 use crate::model::worker::fuzzy_match_function_name;
+use crate::model::worker_query::WorkerQuery;
 use crate::model::{
-    ComponentName, ComponentNameMatchKind, IdempotencyKey, ProjectName, ProjectReference,
+    ComponentName, ComponentNameMatchKind, Format, IdempotencyKey, ProjectName, ProjectReference,
     WorkerMetadata, WorkerMetadataView, WorkerName, WorkerNameMatch, WorkerUpdateMode,
     WorkersMetadataResponseView,
 };
 use anyhow::{anyhow, bail};
+use chrono::Utc;
 use colored::Colorize;
+use futures_util::future::join_all;
 use golem_client::api::WorkerClient;
 use golem_client::model::{
-    ComponentType, InvokeResult, PublicOplogEntry, ScanCursor, UpdateRecord,
+    ComponentType, InvokeResult, PublicOplogEntry, ScanCursor, UpdateRecord, WorkerStatus,
+    WorkersMetadataRequest,
 };
 use golem_client::model::{
     InvokeParameters as InvokeParametersCloud, RevertLastInvocations as RevertLastInvocationsCloud,
@@ -62,7 +79,7 @@ use golem_wasm_ast::analysis::AnalysedType;
 use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
 use golem_wasm_rpc::{parse_value_and_type, ValueAndType};
 use itertools::{EitherOrBoth, Itertools};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::timeout;
@@ -104,6 +121,10 @@ impl WorkerCommandHandler {
                 )
                 .await
             }
+            WorkerSubcommand::Shell {
+                worker_name,
+                stream_args,
+            } => self.cmd_shell(worker_name, stream_args).await,
             WorkerSubcommand::Get { worker_name } => self.cmd_get(worker_name).await,
             WorkerSubcommand::Delete { worker_name } => self.cmd_delete(worker_name).await,
             WorkerSubcommand::List {
@@ -126,6 +147,73 @@ impl WorkerCommandHandler {
                 worker_name,
                 stream_args,
             } => self.cmd_stream(worker_name, stream_args).await,
+            WorkerSubcommand::Logs {
+                worker_name,
+                since,
+                until,
+                level,
+                context,
+                follow,
+                stream_args,
+            } => {
+                self.cmd_logs(
+                    worker_name,
+                    since,
+                    until,
+                    level,
+                    context,
+                    follow,
+                    stream_args,
+                )
+                .await
+            }
+            WorkerSubcommand::Fork {
+                worker_name,
+                target_worker_name,
+                at_oplog_index,
+            } => {
+                self.cmd_fork(worker_name, target_worker_name, at_oplog_index)
+                    .await
+            }
+            WorkerSubcommand::Export {
+                worker_name,
+                at_oplog_index,
+                output,
+            } => self.cmd_export(worker_name, at_oplog_index, output).await,
+            WorkerSubcommand::Import {
+                snapshot,
+                target_worker_name,
+            } => self.cmd_import(snapshot, target_worker_name).await,
+            WorkerSubcommand::Files { subcommand } => match subcommand {
+                WorkerFilesSubcommand::Ls {
+                    worker_name,
+                    path,
+                    recursive,
+                } => self.cmd_files_ls(worker_name, path, recursive).await,
+                WorkerFilesSubcommand::Get {
+                    worker_name,
+                    path,
+                    output,
+                    recursive,
+                } => {
+                    self.cmd_files_get(worker_name, path, output, recursive)
+                        .await
+                }
+            },
+            WorkerSubcommand::Top {
+                component_name,
+                filter: filters,
+                sort_by,
+                refresh_interval,
+            } => {
+                self.cmd_top(
+                    component_name.component_name,
+                    filters,
+                    sort_by,
+                    Duration::from_secs(refresh_interval.max(1)),
+                )
+                .await
+            }
             WorkerSubcommand::Interrupt { worker_name } => self.cmd_interrupt(worker_name).await,
             WorkerSubcommand::Update {
                 worker_name,
@@ -165,6 +253,24 @@ impl WorkerCommandHandler {
                 self.cmd_cancel_invocation(worker_name, idempotency_key)
                     .await
             }
+            WorkerSubcommand::Invocations {
+                worker_name,
+                cancel_pending,
+            } => self.cmd_invocations(worker_name, cancel_pending).await,
+            WorkerSubcommand::Result {
+                worker_name,
+                idempotency_key,
+                wait,
+                timeout,
+            } => {
+                self.cmd_result(
+                    worker_name,
+                    idempotency_key,
+                    wait,
+                    timeout.map(Duration::from_secs),
+                )
+                .await
+            }
         }
     }
 
@@ -278,58 +384,7 @@ impl WorkerCommandHandler {
             )
             .await?;
 
-        let matched_function_name =
-            fuzzy_match_function_name(function_name, component.metadata.exports());
-        let function_name = match matched_function_name {
-            Ok(match_) => {
-                log_fuzzy_match(&match_);
-                match_.option
-            }
-            Err(error) => {
-                let component_functions =
-                    show_exported_functions(component.metadata.exports(), false);
-
-                match error {
-                    Error::Ambiguous {
-                        highlighted_options,
-                        ..
-                    } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) is ambiguous.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        logln("Did you mean one of");
-                        for option in highlighted_options {
-                            logln(format!(" - {}", option.bold()));
-                        }
-                        logln("?");
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
-
-                        bail!(NonSuccessfulExit);
-                    }
-                    Error::NotFound { .. } => {
-                        logln("");
-                        log_error(format!(
-                            "The requested function name ({}) was not found.",
-                            function_name.log_color_error_highlight()
-                        ));
-                        logln("");
-                        log_text_view(&AvailableFunctionNamesHelp {
-                            component_name: worker_name_match.component_name.0,
-                            function_names: component_functions,
-                        });
-
-                        bail!(NonSuccessfulExit);
-                    }
-                }
-            }
-        };
+        let function_name = match_function_name(&component, function_name)?;
 
         if enqueue {
             log_action(
@@ -404,10 +459,24 @@ impl WorkerCommandHandler {
             format!("to worker {}", format_worker_name_match(&worker_name_match)),
         );
 
+        self.stream_worker(
+            component.versioned_component_id.component_id,
+            &worker_name,
+            stream_args,
+        )
+        .await
+    }
+
+    async fn stream_worker(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        stream_args: StreamArgs,
+    ) -> anyhow::Result<()> {
         let connection = WorkerConnection::new(
             self.ctx.worker_service_url().clone(),
             self.ctx.auth_token().await?,
-            component.versioned_component_id.component_id,
+            component_id,
             worker_name.0.clone(),
             stream_args.into(),
             self.ctx.allow_insecure(),
@@ -465,6 +534,7 @@ impl WorkerCommandHandler {
         let batch_size = self.ctx.http_batch_size();
         let mut cursor = Option::<OplogCursor>::None;
         let mut had_entries = false;
+        let mut pages = self.ctx.log_handler().view_pages();
         loop {
             let mut entries = Vec::<(u64, PublicOplogEntry)>::new();
             cursor = {
@@ -494,7 +564,7 @@ impl WorkerCommandHandler {
 
             if !entries.is_empty() {
                 had_entries = true;
-                self.ctx.log_handler().log_view(&entries);
+                pages.log_page(&entries);
             }
 
             if cursor.is_none() {
@@ -592,18 +662,9 @@ impl WorkerCommandHandler {
             ),
         );
 
-        let clients = self.ctx.golem_clients().await?;
-
-        let canceled = clients
-            .worker
-            .cancel_invocation(
-                &component.versioned_component_id.component_id,
-                &worker_name.0,
-                &idempotency_key.0,
-            )
-            .await
-            .map(|result| (result.canceled))
-            .map_service_error()?;
+        let canceled = self
+            .cancel_invocation(&component, &worker_name, &idempotency_key)
+            .await?;
 
         // TODO: json / yaml response?
         if canceled {
@@ -623,6 +684,8 @@ impl WorkerCommandHandler {
         max_count: Option<u64>,
         precise: bool,
     ) -> anyhow::Result<()> {
+        let query = parse_worker_query(&filters)?;
+
         let selected_components = self
             .ctx
             .component_handler()
@@ -644,6 +707,9 @@ impl WorkerCommandHandler {
             bail!(NonSuccessfulExit);
         }
 
+        // Line based formats are streamed page by page, instead of collecting all workers first
+        let stream_pages = matches!(self.ctx.format(), Format::Csv | Format::Ndjson);
+        let mut pages = self.ctx.log_handler().view_pages();
         let mut view = WorkersMetadataResponseView::default();
 
         for component_name in &selected_components.component_names {
@@ -657,12 +723,47 @@ impl WorkerCommandHandler {
                 )
                 .await?
             {
+                Some(component) if stream_pages => {
+                    let mut current_scan_cursor = scan_cursor.clone();
+                    loop {
+                        let (workers, next_scan_cursor) = self
+                            .list_component_workers(
+                                component_name,
+                                component.versioned_component_id.component_id,
+                                query.as_ref(),
+                                current_scan_cursor.as_ref(),
+                                Some(max_count.unwrap_or(self.ctx.http_batch_size())),
+                                precise,
+                            )
+                            .await?;
+
+                        pages.log_page(&WorkersMetadataResponseView {
+                            workers: workers.into_iter().map(WorkerMetadataView::from).collect(),
+                            cursors: BTreeMap::new(),
+                        });
+
+                        match next_scan_cursor {
+                            Some(next_scan_cursor) if max_count.is_some() => {
+                                logln(format!(
+                                    "Cursor for more results for component {}: {}",
+                                    component_name.0.log_color_highlight(),
+                                    scan_cursor_to_string(&next_scan_cursor).log_color_highlight()
+                                ));
+                                break;
+                            }
+                            Some(next_scan_cursor) => {
+                                current_scan_cursor = Some(next_scan_cursor);
+                            }
+                            None => break,
+                        }
+                    }
+                }
                 Some(component) => {
                     let (workers, scan_cursor) = self
                         .list_component_workers(
                             component_name,
                             component.versioned_component_id.component_id,
-                            Some(filters.as_slice()),
+                            query.as_ref(),
                             scan_cursor.as_ref(),
                             max_count,
                             precise,
@@ -687,7 +788,9 @@ impl WorkerCommandHandler {
             }
         }
 
-        self.ctx.log_handler().log_view(&view);
+        if !stream_pages {
+            self.ctx.log_handler().log_view(&view);
+        }
 
         Ok(())
     }
@@ -1024,6 +1127,7 @@ impl WorkerCommandHandler {
         update_mode: WorkerUpdateMode,
         target_version: u64,
         await_update: bool,
+        rollout: &WorkerRolloutConfig,
     ) -> anyhow::Result<TryUpdateAllWorkersResult> {
         let (workers, _) = self
             .list_component_workers(component_name, component_id, None, None, None, false)
@@ -1037,6 +1141,19 @@ impl WorkerCommandHandler {
             return Ok(TryUpdateAllWorkersResult::default());
         }
 
+        if rollout.strategy != WorkerRolloutStrategy::All {
+            return self
+                .rollout_component_workers(
+                    component_name,
+                    component_id,
+                    &workers,
+                    update_mode,
+                    target_version,
+                    rollout,
+                )
+                .await;
+        }
+
         log_action(
             "Updating",
             format!(
@@ -1096,6 +1213,260 @@ impl WorkerCommandHandler {
         Ok(update_results)
     }
 
+    // Updates the workers in batches, every batch is updated concurrently and awaited. After each
+    // batch the failure rate is checked, and if it exceeds the threshold, the rollout is paused
+    // or aborted.
+    async fn rollout_component_workers(
+        &self,
+        component_name: &ComponentName,
+        component_id: Uuid,
+        workers: &[WorkerMetadata],
+        update_mode: WorkerUpdateMode,
+        target_version: u64,
+        rollout: &WorkerRolloutConfig,
+    ) -> anyhow::Result<TryUpdateAllWorkersResult> {
+        let batches = rollout.batches(workers.len());
+
+        log_action(
+            "Updating",
+            format!(
+                "all workers ({}) for component {} to version {} using {} rollout in {} batch(es)",
+                workers.len().to_string().log_color_highlight(),
+                component_name.0.blue().bold(),
+                target_version.to_string().log_color_highlight(),
+                rollout.strategy.to_string().log_color_highlight(),
+                batches.len().to_string().log_color_highlight(),
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let attempt = |worker: &WorkerMetadata, error: Option<String>| WorkerUpdateAttempt {
+            component_name: component_name.clone(),
+            target_version,
+            worker_name: worker.worker_id.worker_name.as_str().into(),
+            error,
+        };
+
+        let mut update_results = TryUpdateAllWorkersResult::default();
+        let mut processed = 0;
+        let mut remaining = workers;
+        for (batch_idx, batch_size) in batches.iter().enumerate() {
+            let (batch, rest) = remaining.split_at(*batch_size);
+            remaining = rest;
+
+            log_action(
+                "Updating",
+                format!(
+                    "{} {}/{} ({} worker(s))",
+                    if batch_idx == 0 && rollout.strategy == WorkerRolloutStrategy::Canary {
+                        "canary batch"
+                    } else {
+                        "batch"
+                    },
+                    (batch_idx + 1).to_string().log_color_highlight(),
+                    batches.len().to_string().log_color_highlight(),
+                    batch.len().to_string().log_color_highlight(),
+                ),
+            );
+            let batch_results = {
+                let _indent = LogIndent::new();
+                join_all(batch.iter().map(|worker| {
+                    self.update_and_check_worker(
+                        component_name,
+                        worker,
+                        update_mode,
+                        target_version,
+                    )
+                }))
+                .await
+            };
+
+            for (worker, result) in batch.iter().zip(batch_results) {
+                processed += 1;
+                match result {
+                    Ok(()) => update_results.triggered.push(attempt(worker, None)),
+                    Err(error) => update_results
+                        .failed
+                        .push(attempt(worker, Some(error.to_string()))),
+                }
+            }
+
+            if rollout.is_failure_threshold_exceeded(update_results.failed.len(), processed) {
+                log_warn(format!(
+                    "{} of {} updated worker(s) failed for component {}, the allowed failure rate is {}%",
+                    update_results.failed.len(),
+                    processed,
+                    component_name.0.log_color_highlight(),
+                    rollout.max_failure_percent
+                ));
+                self.log_component_worker_versions(component_name, component_id)
+                    .await?;
+
+                if remaining.is_empty() {
+                    break;
+                }
+
+                let proceed = match rollout.on_failure {
+                    WorkerRolloutFailureAction::Abort => false,
+                    WorkerRolloutFailureAction::Pause => self
+                        .ctx
+                        .interactive_handler()
+                        .confirm_continue_worker_rollout(component_name, remaining.len())?,
+                };
+
+                if !proceed {
+                    log_warn_action(
+                        "Aborting",
+                        format!(
+                            "rollout for component {}, skipping {} worker(s)",
+                            component_name.0.log_color_highlight(),
+                            remaining.len().to_string().log_color_highlight()
+                        ),
+                    );
+                    update_results
+                        .skipped
+                        .extend(remaining.iter().map(|worker| attempt(worker, None)));
+                    break;
+                }
+            }
+        }
+
+        Ok(update_results)
+    }
+
+    async fn update_and_check_worker(
+        &self,
+        component_name: &ComponentName,
+        worker: &WorkerMetadata,
+        update_mode: WorkerUpdateMode,
+        target_version: u64,
+    ) -> anyhow::Result<()> {
+        let component_id = worker.worker_id.component_id.0;
+        let worker_name = &worker.worker_id.worker_name;
+
+        self.update_worker(
+            component_name,
+            component_id,
+            worker_name,
+            update_mode,
+            target_version,
+            true,
+        )
+        .await?;
+
+        let metadata = self
+            .ctx
+            .golem_clients()
+            .await?
+            .worker
+            .get_worker_metadata(&component_id, worker_name)
+            .await
+            .map_service_error()?;
+
+        if matches!(metadata.status, WorkerStatus::Failed) {
+            bail!(
+                "Worker {} failed after the update: {}",
+                worker_name,
+                metadata.last_error.unwrap_or("unknown reason".to_string())
+            );
+        }
+
+        Ok(())
+    }
+
+    async fn log_component_worker_versions(
+        &self,
+        component_name: &ComponentName,
+        component_id: Uuid,
+    ) -> anyhow::Result<()> {
+        let (workers, _) = self
+            .list_component_workers(component_name, component_id, None, None, None, true)
+            .await?;
+
+        logln("Workers by component version:");
+        let _indent = LogIndent::new();
+        for (version, workers) in &workers
+            .iter()
+            .sorted_by_key(|worker| worker.component_version)
+            .chunk_by(|worker| worker.component_version)
+        {
+            logln(format!(
+                "{}: {}",
+                version.to_string().log_color_highlight(),
+                workers
+                    .map(|worker| worker.worker_id.worker_name.as_str())
+                    .join(", ")
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Updates workers which are already running on a newer version than the target one,
+    // used for rolling back failed deployments
+    pub async fn rollback_component_workers(
+        &self,
+        component_name: &ComponentName,
+        component_id: Uuid,
+        update_mode: WorkerUpdateMode,
+        target_version: u64,
+    ) -> anyhow::Result<TryUpdateAllWorkersResult> {
+        let (workers, _) = self
+            .list_component_workers(component_name, component_id, None, None, None, true)
+            .await?;
+
+        let workers = workers
+            .into_iter()
+            .filter(|worker| worker.component_version > target_version)
+            .collect::<Vec<_>>();
+
+        if workers.is_empty() {
+            log_skipping_up_to_date(format!(
+                "rolling back workers for component {}",
+                component_name.0.log_color_highlight()
+            ));
+            return Ok(TryUpdateAllWorkersResult::default());
+        }
+
+        log_warn_action(
+            "Rolling back",
+            format!(
+                "workers ({}) for component {} to version {}",
+                workers.len().to_string().log_color_highlight(),
+                component_name.0.blue().bold(),
+                target_version.to_string().log_color_highlight()
+            ),
+        );
+        let _indent = LogIndent::new();
+
+        let mut update_results = TryUpdateAllWorkersResult::default();
+        for worker in &workers {
+            let attempt = |error: Option<String>| WorkerUpdateAttempt {
+                component_name: component_name.clone(),
+                target_version,
+                worker_name: worker.worker_id.worker_name.as_str().into(),
+                error,
+            };
+
+            match self
+                .update_worker(
+                    component_name,
+                    worker.worker_id.component_id.0,
+                    &worker.worker_id.worker_name,
+                    update_mode,
+                    target_version,
+                    false,
+                )
+                .await
+            {
+                Ok(_) => update_results.triggered.push(attempt(None)),
+                Err(error) => update_results.failed.push(attempt(Some(error.to_string()))),
+            }
+        }
+
+        Ok(update_results)
+    }
+
     async fn update_worker(
         &self,
         component_name: &ComponentName,
@@ -1139,7 +1510,10 @@ impl WorkerCommandHandler {
 
         match result {
             Ok(_) => {
-                log_action("Triggered update", "");
+                log_action(
+                    "Triggered update",
+                    format!("for worker {}", worker_name.log_color_highlight()),
+                );
 
                 if await_update {
                     self.await_update_result(&component_id, worker_name, target_version)
@@ -1149,7 +1523,13 @@ impl WorkerCommandHandler {
                 Ok(())
             }
             Err(error) => {
-                log_error_action("Failed", "to trigger update for worker, error:");
+                log_error_action(
+                    "Failed",
+                    format!(
+                        "to trigger update for worker {}, error:",
+                        worker_name.log_color_highlight()
+                    ),
+                );
                 let _indent = LogIndent::new();
                 logln(format!("{error}"));
                 Err(anyhow!(error))
@@ -1169,79 +1549,82 @@ impl WorkerCommandHandler {
                 .worker
                 .get_worker_metadata(component_id, worker_name)
                 .await?;
+
+            let mut pending = false;
+            let mut latest_success = None;
+            let mut latest_failure = None;
             for update_record in metadata.updates {
-                let mut latest_success = None;
-                let mut latest_failure = None;
-                let mut pending_count = 0;
                 match update_record {
                     UpdateRecord::PendingUpdate(details)
                         if details.target_version == target_version =>
                     {
-                        pending_count += 1;
+                        pending = true;
                     }
                     UpdateRecord::SuccessfulUpdate(details)
                         if details.target_version == target_version =>
                     {
-                        match latest_success {
-                            None => latest_success = Some(details),
-                            Some(previous_success)
-                                if previous_success.timestamp < details.timestamp =>
-                            {
-                                latest_success = Some(details);
-                            }
-                            _ => {}
-                        }
+                        latest_success = latest_success.max(Some(details.timestamp));
                     }
                     UpdateRecord::FailedUpdate(details)
                         if details.target_version == target_version =>
                     {
-                        match latest_failure {
-                            None => latest_failure = Some(details),
-                            Some(previous_failure)
-                                if previous_failure.timestamp < details.timestamp =>
-                            {
-                                latest_failure = Some(details);
-                            }
-                            _ => {}
-                        }
+                        latest_failure =
+                            latest_failure.max(Some((details.timestamp, details.details)));
                     }
                     _ => {}
                 }
+            }
 
-                if pending_count > 0 {
-                    log_action("Worker update", "is still pending");
-                    tokio::time::sleep(Duration::from_secs(2)).await;
-                } else if let Some(success) = latest_success {
+            // A failed update can be retried, so only the latest outcome is considered
+            let failure = match (latest_success, latest_failure) {
+                (Some(success), Some(failure)) if success < failure.0 => Some(failure),
+                (Some(success), _) => {
                     log_action(
                         "Worker update",
                         format!(
-                            "to version {} succeeded at {}",
-                            success.target_version.to_string().log_color_highlight(),
-                            success.timestamp.to_string().log_color_highlight()
+                            "of {} to version {} succeeded at {}",
+                            worker_name.log_color_highlight(),
+                            target_version.to_string().log_color_highlight(),
+                            success.to_string().log_color_highlight()
                         ),
                     );
                     return Ok(());
-                } else if let Some(failure) = latest_failure {
-                    let error = failure.details.unwrap_or("unknown reason".to_string());
-                    log_error_action(
-                        "Worker update",
-                        format!(
-                            "to version {} succeeded at {}: {}",
-                            failure.target_version.to_string().log_color_highlight(),
-                            failure.timestamp.to_string().log_color_highlight(),
-                            error
-                        ),
-                    );
-                    return Err(anyhow!(error));
-                } else {
-                    log_error_action(
-                        "Worker update",
-                        "is not pending anymore, but no outcome has been found",
-                    );
-                    return Err(anyhow ! (
-                "Unexpected worker state: update is not pending anymore, but no outcome has been found"
-                ));
                 }
+                (None, failure) => failure,
+            };
+
+            if pending {
+                log_action(
+                    "Worker update",
+                    format!("of {} is still pending", worker_name.log_color_highlight()),
+                );
+                tokio::time::sleep(Duration::from_secs(2)).await;
+            } else if let Some((timestamp, details)) = failure {
+                let error = details.unwrap_or("unknown reason".to_string());
+                log_error_action(
+                    "Worker update",
+                    format!(
+                        "of {} to version {} failed at {}: {}",
+                        worker_name.log_color_highlight(),
+                        target_version.to_string().log_color_highlight(),
+                        timestamp.to_string().log_color_highlight(),
+                        error
+                    ),
+                );
+                return Err(anyhow!(error));
+            } else if metadata.component_version == target_version {
+                return Ok(());
+            } else {
+                log_error_action(
+                    "Worker update",
+                    format!(
+                        "of {} is not pending anymore, but no outcome has been found",
+                        worker_name.log_color_highlight()
+                    ),
+                );
+                bail!(
+                    "Unexpected worker state: update is not pending anymore, but no outcome has been found"
+                );
             }
         }
     }
@@ -1342,7 +1725,7 @@ impl WorkerCommandHandler {
         &self,
         component_name: &ComponentName,
         component_id: Uuid,
-        filters: Option<&[String]>,
+        query: Option<&WorkerQuery>,
         start_scan_cursor: Option<&ScanCursor>,
         max_count: Option<u64>,
         precise: bool,
@@ -1351,18 +1734,31 @@ impl WorkerCommandHandler {
         let mut workers = Vec::<WorkerMetadata>::new();
         let mut final_result_cursor = Option::<ScanCursor>::None;
 
-        let start_scan_cursor = start_scan_cursor.map(scan_cursor_to_string);
-        let mut current_scan_cursor = start_scan_cursor.clone();
+        let now = Utc::now();
+        let filter = query.and_then(|query| query.to_worker_filter(now));
+        let client_side_query = query.filter(|query| !query.is_exact());
+
+        // NOTE: with client side filtering pages can come back with fewer matching workers than
+        //       requested, so pages are fetched until max_count is reached, only requesting the
+        //       remaining count to not skip workers
+        let mut current_scan_cursor = start_scan_cursor.cloned();
         loop {
+            let count = match max_count {
+                Some(max_count) => max_count.saturating_sub(workers.len() as u64),
+                None => self.ctx.http_batch_size(),
+            };
+
             let result_cursor = {
                 let results = clients
                     .worker
-                    .get_workers_metadata(
+                    .find_workers_metadata(
                         &component_id,
-                        filters,
-                        current_scan_cursor.as_deref(),
-                        max_count.or(Some(self.ctx.http_batch_size())),
-                        Some(precise),
+                        &WorkersMetadataRequest {
+                            filter: filter.clone(),
+                            cursor: current_scan_cursor.clone(),
+                            count: Some(count),
+                            precise: Some(precise),
+                        },
                     )
                     .await
                     .map_service_error()?;
@@ -1371,7 +1767,10 @@ impl WorkerCommandHandler {
                     results
                         .workers
                         .into_iter()
-                        .map(|meta| WorkerMetadata::from(component_name.clone(), meta)),
+                        .map(|meta| WorkerMetadata::from(component_name.clone(), meta))
+                        .filter(|worker| {
+                            client_side_query.is_none_or(|query| query.matches(worker, now))
+                        }),
                 );
 
                 results.cursor
@@ -1379,11 +1778,11 @@ impl WorkerCommandHandler {
 
             match result_cursor {
                 Some(next_cursor) => {
-                    if max_count.is_none() {
-                        current_scan_cursor = Some(scan_cursor_to_string(&next_cursor));
-                    } else {
+                    if max_count.is_some_and(|max_count| workers.len() as u64 >= max_count) {
                         final_result_cursor = Some(next_cursor);
                         break;
+                    } else {
+                        current_scan_cursor = Some(next_cursor);
                     }
                 }
                 None => {
@@ -1432,6 +1831,26 @@ impl WorkerCommandHandler {
         Ok((component, worker_name.clone()))
     }
 
+    async fn cancel_invocation(
+        &self,
+        component: &Component,
+        worker_name: &WorkerName,
+        idempotency_key: &IdempotencyKey,
+    ) -> anyhow::Result<bool> {
+        let clients = self.ctx.golem_clients().await?;
+
+        clients
+            .worker
+            .cancel_invocation(
+                &component.versioned_component_id.component_id,
+                &worker_name.0,
+                &idempotency_key.0,
+            )
+            .await
+            .map(|result| result.canceled)
+            .map_service_error()
+    }
+
     async fn resume_worker(
         &self,
         component: &Component,
@@ -1719,6 +2138,62 @@ impl WorkerCommandHandler {
     }
 }
 
+/// Fuzzy matches the requested function name against the exports of the component,
+/// logging the available function names if there is no unambiguous match
+fn match_function_name(component: &Component, function_name: &str) -> anyhow::Result<String> {
+    let matched_function_name =
+        fuzzy_match_function_name(function_name, component.metadata.exports());
+    match matched_function_name {
+        Ok(match_) => {
+            log_fuzzy_match(&match_);
+            Ok(match_.option)
+        }
+        Err(error) => {
+            let component_functions = show_exported_functions(component.metadata.exports(), false);
+
+            match error {
+                Error::Ambiguous {
+                    highlighted_options,
+                    ..
+                } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) is ambiguous.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    logln("Did you mean one of");
+                    for option in highlighted_options {
+                        logln(format!(" - {}", option.bold()));
+                    }
+                    logln("?");
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component.component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+                Error::NotFound { .. } => {
+                    logln("");
+                    log_error(format!(
+                        "The requested function name ({}) was not found.",
+                        function_name.log_color_error_highlight()
+                    ));
+                    logln("");
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component.component_name.0.clone(),
+                        function_names: component_functions,
+                    });
+
+                    bail!(NonSuccessfulExit);
+                }
+            }
+        }
+    }
+}
+
 fn wave_args_to_invoke_args(
     component: &Component,
     function_name: &str,
@@ -1817,6 +2292,19 @@ pub fn lenient_parse_type_annotated_value(
     parse_value_and_type(analysed_type, input)
 }
 
+fn parse_worker_query(filters: &[String]) -> anyhow::Result<Option<WorkerQuery>> {
+    match WorkerQuery::parse_all(filters) {
+        Ok(query) => Ok(query),
+        Err(error) => {
+            log_error("Invalid worker filter");
+            logln("");
+            logln(error.to_string());
+            logln("");
+            bail!(NonSuccessfulExit)
+        }
+    }
+}
+
 fn scan_cursor_to_string(cursor: &ScanCursor) -> String {
     format!("{}/{}", cursor.layer, cursor.cursor)
 }
@@ -0,0 +1,215 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::{StreamArgs, WorkerNameArg};
+use crate::command_handler::worker::stream::WorkerConnection;
+use crate::command_handler::worker::{
+    match_function_name, wave_args_to_invoke_args, WorkerCommandHandler,
+};
+use crate::error::NonSuccessfulExit;
+use crate::fs;
+use crate::fs::PathExtra;
+use crate::log::{log_action, logln, LogColorize};
+use crate::model::component::{show_exported_functions, Component};
+use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::text::fmt::{format_export, format_worker_name_match, log_error, log_text_view};
+use crate::model::text::help::{AvailableFunctionNamesHelp, WorkerShellHelp};
+use crate::model::worker_shell::{complete_function_name, WorkerShellCommand};
+use crate::model::{IdempotencyKey, WorkerName};
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::FileHistory;
+use rustyline::validate::Validator;
+use rustyline::{Editor, Helper};
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_shell(
+        &self,
+        worker_name: WorkerNameArg,
+        stream_args: StreamArgs,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+
+        let history_file = self
+            .ctx
+            .worker_shell_history_file(&component.component_name, &worker_name)
+            .await?;
+        fs::create_dir_all(PathExtra::new(&history_file).parent()?)?;
+
+        let mut editor = Editor::<WorkerShellHelper, FileHistory>::new()?;
+        editor.set_helper(Some(WorkerShellHelper {
+            function_names: show_exported_functions(component.metadata.exports(), false),
+        }));
+        // The history file is missing for the first session of the worker
+        let _ = editor.load_history(&history_file);
+
+        log_action(
+            "Connecting",
+            format!("to worker {}", format_worker_name_match(&worker_name_match)),
+        );
+
+        // The connection retries until the worker exists, so the worker's output is streamed
+        // even if it is created by the first invocation of the shell
+        let connection = WorkerConnection::new(
+            self.ctx.worker_service_url().clone(),
+            self.ctx.auth_token().await?,
+            component.versioned_component_id.component_id,
+            worker_name.0.clone(),
+            stream_args.into(),
+            self.ctx.allow_insecure(),
+            self.ctx.format(),
+            None,
+        )
+        .await?;
+        let connection_handle = tokio::task::spawn(connection.run_forever());
+
+        logln("");
+        log_text_view(&WorkerShellHelp);
+        logln("");
+
+        let prompt = format!("{}> ", worker_name.0);
+        loop {
+            let line = match tokio::task::block_in_place(|| editor.readline(&prompt)) {
+                Ok(line) => line,
+                Err(ReadlineError::Interrupted) => continue,
+                Err(ReadlineError::Eof) => break,
+                Err(err) => {
+                    connection_handle.abort();
+                    return Err(err.into());
+                }
+            };
+
+            if !line.trim().is_empty() {
+                let _ = editor.add_history_entry(line.as_str());
+            }
+
+            match WorkerShellCommand::parse(&line) {
+                Ok(WorkerShellCommand::Empty) => {}
+                Ok(WorkerShellCommand::Help) => log_text_view(&WorkerShellHelp),
+                Ok(WorkerShellCommand::Functions) => {
+                    log_text_view(&AvailableFunctionNamesHelp {
+                        component_name: component.component_name.0.clone(),
+                        function_names: show_exported_functions(component.metadata.exports(), true),
+                    });
+                }
+                Ok(WorkerShellCommand::Exit) => break,
+                Ok(WorkerShellCommand::Invoke {
+                    function_name,
+                    arguments,
+                }) => {
+                    if let Err(err) = self
+                        .shell_invoke(&component, &worker_name, &function_name, arguments)
+                        .await
+                    {
+                        if err.downcast_ref::<NonSuccessfulExit>().is_none() {
+                            log_error(format!("{err:#}"));
+                        }
+                    }
+                }
+                Err(err) => log_error(err),
+            }
+        }
+
+        connection_handle.abort();
+
+        if let Err(err) = editor.save_history(&history_file) {
+            log_error(format!(
+                "Failed to save worker shell history to {}: {err}",
+                history_file.display().to_string().log_color_highlight()
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn shell_invoke(
+        &self,
+        component: &Component,
+        worker_name: &WorkerName,
+        function_name: &str,
+        arguments: Vec<String>,
+    ) -> anyhow::Result<()> {
+        let function_name = match_function_name(component, function_name)?;
+        let arguments = wave_args_to_invoke_args(component, &function_name, arguments)?;
+        let idempotency_key = IdempotencyKey::new();
+
+        log_action(
+            "Invoking",
+            format!(
+                "{} using idempotency key {}",
+                format_export(&function_name),
+                idempotency_key.0.log_color_highlight()
+            ),
+        );
+
+        let result = self
+            .invoke_worker(
+                component,
+                Some(worker_name),
+                &function_name,
+                arguments,
+                idempotency_key.clone(),
+                false,
+                None,
+            )
+            .await?;
+
+        if let Some(result) = result {
+            self.ctx
+                .log_handler()
+                .log_view(&InvokeResultView::new_invoke(
+                    idempotency_key,
+                    result,
+                    component,
+                    &function_name,
+                ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Completes exported function names at the start of shell lines
+struct WorkerShellHelper {
+    function_names: Vec<String>,
+}
+
+impl Completer for WorkerShellHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete_function_name(line, pos, &self.function_names))
+    }
+}
+
+impl Hinter for WorkerShellHelper {
+    type Hint = String;
+}
+
+impl Highlighter for WorkerShellHelper {}
+
+impl Validator for WorkerShellHelper {}
+
+impl Helper for WorkerShellHelper {}
@@ -0,0 +1,455 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::WorkerNameArg;
+use crate::command_handler::worker::WorkerCommandHandler;
+use crate::command_handler::Handlers;
+use crate::error::service::AnyhowMapServiceError;
+use crate::error::NonSuccessfulExit;
+use crate::fs;
+use crate::log::{log_action, log_error_action, log_warn_action, logln, LogColorize, LogIndent};
+use crate::model::text::fmt::{format_worker_name_match, log_error};
+use crate::model::text::worker::WorkerGetView;
+use crate::model::worker_snapshot::{WorkerSnapshot, WorkerSnapshotOplogEntry};
+use crate::model::{ComponentVersionSelection, IdempotencyKey, WorkerName, WorkerUpdateMode};
+use anyhow::{anyhow, bail, Context};
+use golem_client::api::WorkerClient;
+use golem_client::model::{ForkWorkerRequest, PublicOplogEntry, WorkerId};
+use golem_common::model::public_oplog::OplogCursor;
+use golem_common::model::ComponentId;
+use golem_wasm_rpc::json::OptionallyValueAndTypeJson;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_fork(
+        &self,
+        worker_name: WorkerNameArg,
+        target_worker_name: String,
+        at_oplog_index: Option<u64>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+        let component_id = component.versioned_component_id.component_id;
+
+        let oplog_index = match at_oplog_index {
+            Some(oplog_index) => oplog_index,
+            None => self.last_oplog_index(component_id, &worker_name).await?,
+        };
+
+        log_action(
+            "Forking",
+            format!(
+                "worker {} into {} at oplog index {}",
+                format_worker_name_match(&worker_name_match),
+                target_worker_name.log_color_highlight(),
+                oplog_index.to_string().log_color_highlight()
+            ),
+        );
+
+        let clients = self.ctx.golem_clients().await?;
+
+        let result = clients
+            .worker
+            .fork_worker(
+                &component_id,
+                &worker_name.0,
+                &ForkWorkerRequest {
+                    target_worker_id: WorkerId {
+                        component_id: ComponentId(component_id),
+                        worker_name: target_worker_name.clone(),
+                    },
+                    oplog_index_cutoff: oplog_index,
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_service_error();
+
+        if let Err(error) = result {
+            log_error_action("Failed", "to fork worker, error:");
+            {
+                let _indent = LogIndent::new();
+                logln(format!("{error}"));
+            }
+            logln("");
+            logln(format!(
+                "If the server does not support forking, use {} and {} instead.",
+                "worker export".log_color_highlight(),
+                "worker import".log_color_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        }
+
+        log_action(
+            "Forked",
+            format!("worker {}", target_worker_name.log_color_highlight()),
+        );
+
+        let metadata = self
+            .worker_metadata(
+                component_id,
+                &component.component_name,
+                &WorkerName(target_worker_name),
+            )
+            .await?;
+        self.ctx
+            .log_handler()
+            .log_view(&WorkerGetView::from_metadata(metadata, false));
+
+        Ok(())
+    }
+
+    pub(super) async fn cmd_export(
+        &self,
+        worker_name: WorkerNameArg,
+        at_oplog_index: Option<u64>,
+        output: Option<PathBuf>,
+    ) -> anyhow::Result<()> {
+        self.ctx.silence_app_context_init().await;
+        let worker_name_match = self.match_worker_name(worker_name.worker_name).await?;
+        let (component, worker_name) = self
+            .component_by_worker_name_match(&worker_name_match)
+            .await?;
+        let component_id = component.versioned_component_id.component_id;
+
+        log_action(
+            "Exporting",
+            format!("worker {}", format_worker_name_match(&worker_name_match)),
+        );
+
+        let metadata = self
+            .worker_metadata(component_id, &component.component_name, &worker_name)
+            .await?;
+        let oplog = self
+            .worker_oplog(component_id, &worker_name, None, at_oplog_index)
+            .await?;
+        let last_oplog_index = oplog
+            .last()
+            .map(|(oplog_index, _)| *oplog_index)
+            .unwrap_or_default();
+
+        let snapshot = WorkerSnapshot {
+            component_name: component.component_name.clone(),
+            component_version: metadata.component_version,
+            worker_name: worker_name.clone(),
+            args: metadata.args.clone(),
+            env: metadata.env.clone(),
+            oplog_index: at_oplog_index
+                .map(|oplog_index| oplog_index.min(last_oplog_index))
+                .unwrap_or(last_oplog_index),
+            oplog: oplog
+                .into_iter()
+                .map(|(oplog_index, entry)| WorkerSnapshotOplogEntry { oplog_index, entry })
+                .collect(),
+        };
+
+        let output =
+            output.unwrap_or_else(|| PathBuf::from(format!("{}.snapshot.json", worker_name.0)));
+        fs::write_str(&output, serde_json::to_string_pretty(&snapshot)?)?;
+
+        log_action(
+            "Exported",
+            format!(
+                "worker {} at oplog index {} to {}",
+                format_worker_name_match(&worker_name_match),
+                snapshot.oplog_index.to_string().log_color_highlight(),
+                output.display().to_string().log_color_highlight()
+            ),
+        );
+
+        self.ctx
+            .log_handler()
+            .log_view(&WorkerGetView::from_metadata(metadata, false));
+
+        Ok(())
+    }
+
+    pub(super) async fn cmd_import(
+        &self,
+        snapshot_path: PathBuf,
+        target_worker_name: Option<String>,
+    ) -> anyhow::Result<()> {
+        let snapshot: WorkerSnapshot = serde_json::from_str(&fs::read_to_string(&snapshot_path)?)
+            .with_context(|| {
+            anyhow!(
+                "Failed to parse worker snapshot {}",
+                snapshot_path.display().to_string().log_color_highlight()
+            )
+        })?;
+        let worker_name = target_worker_name
+            .map(WorkerName)
+            .unwrap_or_else(|| snapshot.worker_name.clone());
+
+        // NOTE: the recorded invocations are only guaranteed to be valid for the component version
+        //       they were recorded with, so other versions are not used as a fallback
+        let Some(component) = self
+            .ctx
+            .component_handler()
+            .component(
+                None,
+                (&snapshot.component_name).into(),
+                Some(ComponentVersionSelection::ByExplicitVersion(
+                    snapshot.component_version,
+                )),
+            )
+            .await?
+        else {
+            log_error(format!(
+                "Component {} version {} not found, the worker snapshot can only be imported using the component version it was exported from",
+                snapshot.component_name.0.log_color_error_highlight(),
+                snapshot
+                    .component_version
+                    .to_string()
+                    .log_color_error_highlight()
+            ));
+            bail!(NonSuccessfulExit);
+        };
+        let component_id = component.versioned_component_id.component_id;
+
+        log_action(
+            "Creating",
+            format!(
+                "worker {}/{}",
+                component.component_name.0.log_color_highlight(),
+                worker_name.0.log_color_highlight()
+            ),
+        );
+        self.new_worker(
+            component_id,
+            worker_name.0.clone(),
+            snapshot.args.clone(),
+            snapshot.env.clone(),
+        )
+        .await?;
+
+        // New workers are created using the latest component version, so they are updated to the
+        // version of the snapshot before replaying the invocations
+        let metadata = self
+            .worker_metadata(component_id, &component.component_name, &worker_name)
+            .await?;
+        if metadata.component_version != snapshot.component_version {
+            log_action(
+                "Updating",
+                format!(
+                    "worker {} to the exported component version {}",
+                    worker_name.0.log_color_highlight(),
+                    snapshot.component_version.to_string().log_color_highlight()
+                ),
+            );
+            let _indent = LogIndent::new();
+            self.update_worker(
+                &component.component_name,
+                component_id,
+                &worker_name.0,
+                WorkerUpdateMode::Automatic,
+                snapshot.component_version,
+                true,
+            )
+            .await?;
+        }
+
+        let invocations = snapshot.invocations().collect::<Vec<_>>();
+        log_action(
+            "Replaying",
+            format!(
+                "{} invocation(s) up to oplog index {}",
+                invocations.len().to_string().log_color_highlight(),
+                snapshot.oplog_index.to_string().log_color_highlight()
+            ),
+        );
+        {
+            let _indent = LogIndent::new();
+            for invocation in invocations {
+                log_action(
+                    "Invoking",
+                    format!(
+                        "{} using idempotency key {}",
+                        invocation.function_name.log_color_highlight(),
+                        invocation.idempotency_key.to_string().log_color_highlight()
+                    ),
+                );
+
+                let arguments = invocation
+                    .request
+                    .iter()
+                    .cloned()
+                    .map(|value| value.try_into())
+                    .collect::<Result<Vec<OptionallyValueAndTypeJson>, _>>()
+                    .map_err(|err| anyhow!("Failed to convert invocation parameters: {err}"))?;
+
+                self.invoke_worker(
+                    &component,
+                    Some(&worker_name),
+                    &invocation.function_name,
+                    arguments,
+                    IdempotencyKey(invocation.idempotency_key.to_string()),
+                    false,
+                    None,
+                )
+                .await?;
+            }
+        }
+
+        let metadata = self
+            .worker_metadata(component_id, &component.component_name, &worker_name)
+            .await?;
+        if metadata.component_version != snapshot.component_version {
+            log_warn_action(
+                "Imported",
+                format!(
+                    "worker is running on component version {}, the snapshot was exported from version {}",
+                    metadata.component_version.to_string().log_color_highlight(),
+                    snapshot.component_version.to_string().log_color_highlight()
+                ),
+            );
+        } else {
+            log_action(
+                "Imported",
+                format!("worker {}", worker_name.0.log_color_highlight()),
+            );
+        }
+
+        self.ctx
+            .log_handler()
+            .log_view(&WorkerGetView::from_metadata(metadata, false));
+
+        Ok(())
+    }
+
+    /// Finds the last oplog index of a worker by probing single entries, so the whole oplog does
+    /// not have to be fetched
+    pub(super) async fn last_oplog_index(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+    ) -> anyhow::Result<u64> {
+        // Oplog indexes start from 1, so 0 is used as the lower bound
+        let mut lower = 0;
+        let mut upper = 1;
+        while self
+            .has_oplog_entry(component_id, worker_name, upper)
+            .await?
+        {
+            lower = upper;
+            upper *= 2;
+        }
+
+        while upper - lower > 1 {
+            let middle = lower + (upper - lower) / 2;
+            if self
+                .has_oplog_entry(component_id, worker_name, middle)
+                .await?
+            {
+                lower = middle;
+            } else {
+                upper = middle;
+            }
+        }
+
+        Ok(lower)
+    }
+
+    async fn has_oplog_entry(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        oplog_index: u64,
+    ) -> anyhow::Result<bool> {
+        Ok(self
+            .oplog_entry(component_id, worker_name, oplog_index)
+            .await?
+            .is_some())
+    }
+
+    pub(super) async fn oplog_entry(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        oplog_index: u64,
+    ) -> anyhow::Result<Option<PublicOplogEntry>> {
+        let result = self
+            .ctx
+            .golem_clients()
+            .await?
+            .worker
+            .get_oplog(
+                &component_id,
+                &worker_name.0,
+                Some(oplog_index),
+                1,
+                None,
+                None,
+            )
+            .await
+            .map_service_error()?;
+
+        Ok(result.entries.into_iter().next().map(|entry| entry.entry))
+    }
+
+    /// Collects the oplog of a worker starting from, and up to and including the given indexes
+    pub(super) async fn worker_oplog(
+        &self,
+        component_id: Uuid,
+        worker_name: &WorkerName,
+        from_oplog_index: Option<u64>,
+        up_to_oplog_index: Option<u64>,
+    ) -> anyhow::Result<Vec<(u64, PublicOplogEntry)>> {
+        let clients = self.ctx.golem_clients().await?;
+        let batch_size = self.ctx.http_batch_size();
+
+        let mut entries = Vec::<(u64, PublicOplogEntry)>::new();
+        let mut cursor = Option::<OplogCursor>::None;
+        loop {
+            let result = clients
+                .worker
+                .get_oplog(
+                    &component_id,
+                    &worker_name.0,
+                    from_oplog_index,
+                    batch_size,
+                    cursor.as_ref(),
+                    None,
+                )
+                .await
+                .map_service_error()?;
+
+            entries.extend(
+                result
+                    .entries
+                    .into_iter()
+                    .map(|entry| (entry.oplog_index, entry.entry)),
+            );
+            cursor = result.next;
+
+            let reached_index = up_to_oplog_index.is_some_and(|up_to_oplog_index| {
+                entries
+                    .last()
+                    .is_some_and(|(oplog_index, _)| *oplog_index >= up_to_oplog_index)
+            });
+            if cursor.is_none() || reached_index {
+                break;
+            }
+        }
+
+        if let Some(up_to_oplog_index) = up_to_oplog_index {
+            entries.retain(|(oplog_index, _)| *oplog_index <= up_to_oplog_index);
+        }
+
+        Ok(entries)
+    }
+}
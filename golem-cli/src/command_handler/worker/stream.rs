@@ -26,7 +26,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::net::TcpStream;
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 use tokio::{task, time};
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::handshake::client::Request;
@@ -45,6 +45,7 @@ pub struct WorkerConnection {
     idempotency_key: Option<IdempotencyKey>,
     last_seen_idempotency_key: Arc<Mutex<Option<IdempotencyKey>>>,
     goal_reached: Arc<AtomicBool>,
+    connected: Arc<Notify>,
 }
 
 impl WorkerConnection {
@@ -78,9 +79,22 @@ impl WorkerConnection {
             idempotency_key,
             last_seen_idempotency_key,
             goal_reached,
+            connected: Arc::new(Notify::new()),
         })
     }
 
+    /// Output of the connection, entries emitted through it are deduplicated against
+    /// the streamed ones
+    pub fn output(&self) -> &WorkerStreamOutput {
+        &self.output
+    }
+
+    /// Notified every time the stream is (re)connected, entries logged while it was not connected
+    /// are only available from the oplog
+    pub fn connected(&self) -> &Arc<Notify> {
+        &self.connected
+    }
+
     /// Creates a new worker connection and every time the connection is dropped tries to
     /// reconnect. If there was an idempotency_key goal and it has been reached, the loop
     /// exits.
@@ -111,6 +125,7 @@ impl WorkerConnection {
             }
             _ => anyhow!("Websocket connect failed, error: {}", e),
         })?;
+        self.connected.notify_one();
 
         let (write, read) = ws_stream.split();
 
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::model::records::CsvRecords;
 use crate::model::{Format, WorkerConnectOptions};
 use colored::Colorize;
 use golem_common::model::{IdempotencyKey, LogLevel, Timestamp};
@@ -29,6 +30,7 @@ pub struct WorkerStreamOutput {
     state: Arc<Mutex<WorkerStreamOutputState>>,
     options: WorkerConnectOptions,
     format: Format,
+    csv: Arc<std::sync::Mutex<CsvRecords>>,
 }
 
 struct WorkerStreamOutputState {
@@ -40,30 +42,70 @@ struct WorkerStreamOutputState {
     pub last_timestamp_hashes: HashSet<u64>,
 }
 
+impl WorkerStreamOutputState {
+    fn new() -> Self {
+        WorkerStreamOutputState {
+            last_stdout_timestamp: Timestamp::now_utc(),
+            stdout: String::new(),
+            last_stderr_timestamp: Timestamp::now_utc(),
+            stderr: String::new(),
+            last_timestamp: Timestamp::from_str("2000-01-01T00:00:00Z").unwrap(),
+            last_timestamp_hashes: HashSet::new(),
+        }
+    }
+
+    /// Entries are emitted in timestamp order, so an entry is only new if it is not older than
+    /// the last one, and was not seen yet with the same timestamp
+    fn check_already_seen(&mut self, timestamp: Timestamp, message: &str) -> bool {
+        let mut hasher = DefaultHasher::new();
+        message.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        match self.last_timestamp.cmp(&timestamp) {
+            Ordering::Less => {
+                // definitely new
+                self.last_timestamp = timestamp;
+                self.last_timestamp_hashes.clear();
+                self.last_timestamp_hashes.insert(hash);
+                false
+            }
+            Ordering::Equal => {
+                if self.last_timestamp_hashes.contains(&hash) {
+                    // old
+                    true
+                } else {
+                    // new
+                    self.last_timestamp_hashes.insert(hash);
+                    false
+                }
+            }
+            Ordering::Greater => {
+                // definitely old
+                true
+            }
+        }
+    }
+}
+
 impl WorkerStreamOutput {
     pub fn new(options: WorkerConnectOptions, format: Format) -> Self {
         WorkerStreamOutput {
-            state: Arc::new(Mutex::new(WorkerStreamOutputState {
-                last_stdout_timestamp: Timestamp::now_utc(),
-                stdout: String::new(),
-                last_stderr_timestamp: Timestamp::now_utc(),
-                stderr: String::new(),
-                last_timestamp: Timestamp::from_str("2000-01-01T00:00:00Z").unwrap(),
-                last_timestamp_hashes: HashSet::new(),
-            })),
+            state: Arc::new(Mutex::new(WorkerStreamOutputState::new())),
             options,
             format,
+            csv: Arc::new(std::sync::Mutex::new(CsvRecords::default())),
         }
     }
 
     pub async fn emit_stdout(&self, timestamp: Timestamp, message: String) {
+        if !self.options.filter.matches(timestamp, LogLevel::Info, "") {
+            return;
+        }
+
         let mut state = self.state.lock().await;
         state.last_stdout_timestamp = timestamp;
 
-        if !self
-            .check_already_seen(&mut state, timestamp, &message)
-            .await
-        {
+        if !state.check_already_seen(timestamp, &message) {
             let lines = message.lines().collect::<Vec<_>>();
             for (idx, line) in lines.iter().enumerate() {
                 if idx == (lines.len() - 1) {
@@ -87,13 +129,14 @@ impl WorkerStreamOutput {
     }
 
     pub async fn emit_stderr(&self, timestamp: Timestamp, message: String) {
+        if !self.options.filter.matches(timestamp, LogLevel::Error, "") {
+            return;
+        }
+
         let mut state = self.state.lock().await;
         state.last_stderr_timestamp = timestamp;
 
-        if !self
-            .check_already_seen(&mut state, timestamp, &message)
-            .await
-        {
+        if !state.check_already_seen(timestamp, &message) {
             let lines = message.lines().collect::<Vec<_>>();
             for (idx, line) in lines.iter().enumerate() {
                 if idx == (lines.len() - 1) {
@@ -123,12 +166,13 @@ impl WorkerStreamOutput {
         context: String,
         message: String,
     ) {
+        if !self.options.filter.matches(timestamp, level, &context) {
+            return;
+        }
+
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(&mut state, timestamp, &message)
-            .await
-        {
+        if !state.check_already_seen(timestamp, &message) {
             let level_str = match level {
                 LogLevel::Trace => "TRACE",
                 LogLevel::Debug => "DEBUG",
@@ -139,8 +183,11 @@ impl WorkerStreamOutput {
             };
 
             match self.format {
-                Format::Json => self.json(level_str, &context, &message),
-                Format::Yaml => self.yaml(level_str, &context, &message),
+                Format::Json | Format::Ndjson => {
+                    self.json(timestamp, level_str, &context, &message)
+                }
+                Format::Yaml => self.yaml(timestamp, level_str, &context, &message),
+                Format::Csv => self.csv(timestamp, level_str, &context, &message),
                 Format::Text => {
                     let prefix = self.prefix(timestamp, level_str);
                     self.colored(level, &format!("{prefix}[{context}] {message}"));
@@ -152,10 +199,7 @@ impl WorkerStreamOutput {
     pub async fn emit_stream_closed(&self, timestamp: Timestamp) {
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(&mut state, timestamp, "Stream closed")
-            .await
-        {
+        if !state.check_already_seen(timestamp, "Stream closed") {
             let prefix = self.prefix(timestamp, "STREAM");
             self.colored(LogLevel::Debug, &format!("{prefix}Stream closed"));
         }
@@ -164,10 +208,7 @@ impl WorkerStreamOutput {
     pub async fn emit_stream_error(&self, timestamp: Timestamp, error: tungstenite::error::Error) {
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(&mut state, timestamp, "Stream error")
-            .await
-        {
+        if !state.check_already_seen(timestamp, "Stream error") {
             let prefix = self.prefix(timestamp, "STREAM");
             self.colored(
                 LogLevel::Warn,
@@ -184,14 +225,10 @@ impl WorkerStreamOutput {
     ) {
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(
-                &mut state,
-                timestamp,
-                &format!("{function_name} {idempotency_key} started"),
-            )
-            .await
-        {
+        if !state.check_already_seen(
+            timestamp,
+            &format!("{function_name} {idempotency_key} started"),
+        ) {
             let prefix = self.prefix(timestamp, "INVOKE");
             self.colored(
                 LogLevel::Trace,
@@ -208,14 +245,10 @@ impl WorkerStreamOutput {
     ) {
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(
-                &mut state,
-                timestamp,
-                &format!("{function_name} {idempotency_key} finished"),
-            )
-            .await
-        {
+        if !state.check_already_seen(
+            timestamp,
+            &format!("{function_name} {idempotency_key} finished"),
+        ) {
             let prefix = self.prefix(timestamp, "INVOKE");
             self.colored(
                 LogLevel::Trace,
@@ -227,14 +260,10 @@ impl WorkerStreamOutput {
     pub async fn emit_missed_messages(&self, timestamp: Timestamp, number_of_missed_messages: u64) {
         let mut state = self.state.lock().await;
 
-        if !self
-            .check_already_seen(
-                &mut state,
-                timestamp,
-                &format!("{number_of_missed_messages} messages missed"),
-            )
-            .await
-        {
+        if !state.check_already_seen(
+            timestamp,
+            &format!("{number_of_missed_messages} messages missed"),
+        ) {
             let prefix = self.prefix(timestamp, "STREAM");
             self.colored(
                 LogLevel::Warn,
@@ -255,45 +284,11 @@ impl WorkerStreamOutput {
         }
     }
 
-    async fn check_already_seen(
-        &self,
-        state: &mut WorkerStreamOutputState,
-        timestamp: Timestamp,
-        message: &str,
-    ) -> bool {
-        let mut hasher = DefaultHasher::new();
-        message.hash(&mut hasher);
-        let hash = hasher.finish();
-
-        match state.last_timestamp.cmp(&timestamp) {
-            Ordering::Less => {
-                // definitely new
-                state.last_timestamp = timestamp;
-                state.last_timestamp_hashes.clear();
-                state.last_timestamp_hashes.insert(hash);
-                false
-            }
-            Ordering::Equal => {
-                if state.last_timestamp_hashes.contains(&hash) {
-                    // old
-                    true
-                } else {
-                    // new
-                    state.last_timestamp_hashes.insert(hash);
-                    false
-                }
-            }
-            Ordering::Greater => {
-                // definitely old
-                true
-            }
-        }
-    }
-
     fn print_stdout(&self, timestamp: Timestamp, message: &str) {
         match self.format {
-            Format::Json => self.json("STDOUT", "", message),
-            Format::Yaml => self.yaml("STDOUT", "", message),
+            Format::Json | Format::Ndjson => self.json(timestamp, "STDOUT", "", message),
+            Format::Yaml => self.yaml(timestamp, "STDOUT", "", message),
+            Format::Csv => self.csv(timestamp, "STDOUT", "", message),
             Format::Text => {
                 let prefix = self.prefix(timestamp, "STDOUT");
                 self.colored(LogLevel::Info, &format!("{prefix}{message}"));
@@ -303,8 +298,9 @@ impl WorkerStreamOutput {
 
     fn print_stderr(&self, timestamp: Timestamp, message: &str) {
         match self.format {
-            Format::Json => self.json("STDERR", "", message),
-            Format::Yaml => self.yaml("STDERR", "", message),
+            Format::Json | Format::Ndjson => self.json(timestamp, "STDERR", "", message),
+            Format::Yaml => self.yaml(timestamp, "STDERR", "", message),
+            Format::Csv => self.csv(timestamp, "STDERR", "", message),
             Format::Text => {
                 let prefix = self.prefix(timestamp, "STDERR");
                 self.colored(LogLevel::Error, &format!("{prefix}{message}"));
@@ -312,19 +308,32 @@ impl WorkerStreamOutput {
         }
     }
 
-    fn json(&self, level_or_source: &str, context: &str, message: &str) {
-        let json = self.json_value(level_or_source, context, message);
+    fn json(&self, timestamp: Timestamp, level_or_source: &str, context: &str, message: &str) {
+        let json = self.json_value(timestamp, level_or_source, context, message);
         println!("{json}");
     }
 
-    fn yaml(&self, level_or_source: &str, context: &str, message: &str) {
-        let json = self.json_value(level_or_source, context, message);
+    fn yaml(&self, timestamp: Timestamp, level_or_source: &str, context: &str, message: &str) {
+        let json = self.json_value(timestamp, level_or_source, context, message);
         println!("{}", serde_yaml::to_string(&json).unwrap());
     }
 
-    fn json_value(&self, level_or_source: &str, context: &str, message: &str) -> serde_json::Value {
+    fn csv(&self, timestamp: Timestamp, level_or_source: &str, context: &str, message: &str) {
+        let json = self.json_value(timestamp, level_or_source, context, message);
+        for line in self.csv.lock().unwrap().lines(&[json]) {
+            println!("{line}");
+        }
+    }
+
+    fn json_value(
+        &self,
+        timestamp: Timestamp,
+        level_or_source: &str,
+        context: &str,
+        message: &str,
+    ) -> serde_json::Value {
         serde_json::json!({
-            "timestamp": Timestamp::now_utc(),
+            "timestamp": timestamp,
             "level": level_or_source,
             "context": context,
             "message": message,
@@ -364,3 +373,32 @@ impl WorkerStreamOutput {
         result
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::command_handler::worker::stream_output::WorkerStreamOutputState;
+    use assert2::check;
+    use golem_common::model::Timestamp;
+    use std::str::FromStr;
+    use test_r::test;
+
+    #[test]
+    fn already_seen_entries_across_history_and_live_output() {
+        let at = |value: &str| Timestamp::from_str(value).unwrap();
+        let mut state = WorkerStreamOutputState::new();
+
+        // entries read from the oplog
+        check!(!state.check_already_seen(at("2025-01-01T00:00:01Z"), "first"));
+        check!(!state.check_already_seen(at("2025-01-01T00:00:02Z"), "second"));
+        check!(!state.check_already_seen(at("2025-01-01T00:00:02Z"), "third"));
+
+        // the live stream replays some of them after connecting
+        check!(state.check_already_seen(at("2025-01-01T00:00:01Z"), "first"));
+        check!(state.check_already_seen(at("2025-01-01T00:00:02Z"), "third"));
+
+        // entries not in the oplog yet are new, even with the last seen timestamp
+        check!(!state.check_already_seen(at("2025-01-01T00:00:02Z"), "fourth"));
+        check!(!state.check_already_seen(at("2025-01-01T00:00:03Z"), "fifth"));
+        check!(state.check_already_seen(at("2025-01-01T00:00:03Z"), "fifth"));
+    }
+}
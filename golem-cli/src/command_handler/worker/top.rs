@@ -0,0 +1,277 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command::shared_args::StreamArgs;
+use crate::command_handler::worker::{parse_worker_query, WorkerCommandHandler};
+use crate::command_handler::Handlers;
+use crate::error::NonSuccessfulExit;
+use crate::log::{log_action, LogColorize};
+use crate::model::component::Component;
+use crate::model::text::fmt::{log_error, log_warn};
+use crate::model::worker_query::WorkerQuery;
+use crate::model::worker_top::{WorkerTop, WorkerTopRow, WorkerTopSortBy};
+use crate::model::{ComponentName, WorkerName};
+use anyhow::bail;
+use crossterm::event::{Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, event, execute, queue, style, terminal};
+use std::io::{stdout, IsTerminal, Write};
+use std::time::{Duration, Instant};
+use tokio::task;
+
+const EVENT_POLL_TIMEOUT: Duration = Duration::from_millis(100);
+
+enum WorkerTopAction {
+    Quit,
+    Stream(WorkerTopRow),
+}
+
+impl WorkerCommandHandler {
+    pub(super) async fn cmd_top(
+        &self,
+        component_name: Option<ComponentName>,
+        filters: Vec<String>,
+        sort_by: WorkerTopSortBy,
+        refresh_interval: Duration,
+    ) -> anyhow::Result<()> {
+        let query = parse_worker_query(&filters)?;
+
+        if !stdout().is_terminal() {
+            log_error("Worker top requires an interactive terminal, use 'worker list' instead");
+            bail!(NonSuccessfulExit);
+        }
+
+        let selected_components = self
+            .ctx
+            .component_handler()
+            .must_select_components_by_app_dir_or_name(component_name.as_ref())
+            .await?;
+
+        let mut components = Vec::<Component>::new();
+        for component_name in &selected_components.component_names {
+            match self
+                .ctx
+                .component_handler()
+                .component(
+                    selected_components.project.as_ref(),
+                    component_name.into(),
+                    None,
+                )
+                .await?
+            {
+                Some(component) => components.push(component),
+                None => {
+                    log_warn(format!(
+                        "Component not found: {}",
+                        component_name.0.log_color_error_highlight()
+                    ));
+                }
+            }
+        }
+
+        if components.is_empty() {
+            log_error("No deployed components found");
+            bail!(NonSuccessfulExit);
+        }
+
+        let title = match components.as_slice() {
+            [component] => component.component_name.0.clone(),
+            components => format!("{} components", components.len()),
+        };
+        let mut top = WorkerTop::new(title, sort_by);
+
+        let action = {
+            let _terminal = TerminalGuard::enter()?;
+            self.run_top(&mut top, &components, query.as_ref(), refresh_interval)
+                .await?
+        };
+
+        match action {
+            WorkerTopAction::Quit => Ok(()),
+            WorkerTopAction::Stream(row) => {
+                log_action(
+                    "Connecting",
+                    format!(
+                        "to worker {}/{}",
+                        row.component_name.0.log_color_highlight(),
+                        row.worker_name.log_color_highlight()
+                    ),
+                );
+                self.stream_worker(
+                    row.component_id,
+                    &WorkerName(row.worker_name),
+                    StreamArgs {
+                        stream_no_log_level: false,
+                        stream_no_timestamp: false,
+                    },
+                )
+                .await
+            }
+        }
+    }
+
+    async fn run_top(
+        &self,
+        top: &mut WorkerTop,
+        components: &[Component],
+        query: Option<&WorkerQuery>,
+        refresh_interval: Duration,
+    ) -> anyhow::Result<WorkerTopAction> {
+        let mut next_refresh = Instant::now();
+        let mut redraw = true;
+        loop {
+            if Instant::now() >= next_refresh {
+                match self.top_rows(components, query).await {
+                    Ok(rows) => top.set_rows(rows),
+                    Err(err) => top.message = Some(format!("Failed to refresh workers: {err}")),
+                }
+                next_refresh = Instant::now() + refresh_interval;
+                redraw = true;
+            }
+
+            if redraw {
+                draw(top)?;
+                redraw = false;
+            }
+
+            let key = match task::spawn_blocking(poll_event).await?? {
+                Some(Event::Key(key)) if key.kind == KeyEventKind::Press => key,
+                Some(Event::Resize(_, _)) => {
+                    redraw = true;
+                    continue;
+                }
+                _ => continue,
+            };
+            redraw = true;
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(WorkerTopAction::Quit),
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return Ok(WorkerTopAction::Quit)
+                }
+                KeyCode::Up | KeyCode::Char('k') => top.select_previous(),
+                KeyCode::Down | KeyCode::Char('j') => top.select_next(),
+                KeyCode::Char('s') => top.next_sort(),
+                KeyCode::Char('o') => top.toggle_order(),
+                KeyCode::Char(key @ ('i' | 'r')) => {
+                    let Some(row) = top.selected().cloned() else {
+                        continue;
+                    };
+                    let Some(component) = components.iter().find(|component| {
+                        component.versioned_component_id.component_id == row.component_id
+                    }) else {
+                        continue;
+                    };
+                    let worker_name = WorkerName(row.worker_name.clone());
+                    let (action, result) = if key == 'i' {
+                        (
+                            "interrupt",
+                            self.interrupt_worker(component, &worker_name, false).await,
+                        )
+                    } else {
+                        ("resume", self.resume_worker(component, &worker_name).await)
+                    };
+                    top.message = Some(match result {
+                        Ok(()) => format!("Requested {action} for worker {}", row.worker_name),
+                        Err(err) => {
+                            format!("Failed to {action} worker {}: {err}", row.worker_name)
+                        }
+                    });
+                    next_refresh = Instant::now();
+                }
+                KeyCode::Enter => {
+                    if let Some(row) = top.selected() {
+                        return Ok(WorkerTopAction::Stream(row.clone()));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    async fn top_rows(
+        &self,
+        components: &[Component],
+        query: Option<&WorkerQuery>,
+    ) -> anyhow::Result<Vec<WorkerTopRow>> {
+        let mut rows = Vec::new();
+        for component in components {
+            let (workers, _) = self
+                .list_component_workers(
+                    &component.component_name,
+                    component.versioned_component_id.component_id,
+                    query,
+                    None,
+                    None,
+                    true,
+                )
+                .await?;
+            rows.extend(workers.iter().map(WorkerTopRow::from));
+        }
+        Ok(rows)
+    }
+}
+
+/// Waits for a terminal event for a short while, crossterm blocks while polling, so it is
+/// called outside of the async runtime's worker threads
+fn poll_event() -> std::io::Result<Option<Event>> {
+    if event::poll(EVENT_POLL_TIMEOUT)? {
+        event::read().map(Some)
+    } else {
+        Ok(None)
+    }
+}
+
+// NOTE: lines are overwritten in place instead of clearing the whole screen first, to avoid flickering
+fn draw(top: &WorkerTop) -> anyhow::Result<()> {
+    let (width, height) = terminal::size()?;
+    let lines = top.render(width as usize, height as usize);
+    let line_count = lines.len() as u16;
+    let mut stdout = stdout();
+    for (idx, line) in lines.into_iter().enumerate() {
+        queue!(
+            stdout,
+            cursor::MoveTo(0, idx as u16),
+            style::Print(line),
+            terminal::Clear(terminal::ClearType::UntilNewLine)
+        )?;
+    }
+    if line_count < height {
+        queue!(
+            stdout,
+            cursor::MoveTo(0, line_count),
+            terminal::Clear(terminal::ClearType::FromCursorDown)
+        )?;
+    }
+    stdout.flush()?;
+    Ok(())
+}
+
+/// Switches the terminal to raw mode and the alternate screen, restoring it when dropped
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> anyhow::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let guard = Self;
+        execute!(stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
@@ -18,7 +18,7 @@ use chrono::{DateTime, Utc};
 use golem_client::model::TokenSecret;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::fmt::{Display, Formatter};
 use std::fs::{create_dir_all, File, OpenOptions};
@@ -124,6 +124,8 @@ pub struct Profile {
     pub custom_worker_url: Option<Url>,
     #[serde(skip_serializing_if = "std::ops::Not::not", default)]
     pub allow_insecure: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub project: Option<String>,
     #[serde(default)]
     pub config: ProfileConfig,
     pub auth: AuthenticationConfig,
@@ -137,6 +139,7 @@ impl Profile {
             custom_worker_url: None,
             custom_cloud_url: None,
             allow_insecure: false,
+            project: None,
             config: ProfileConfig::default(),
             auth: AuthenticationConfig::Static(StaticAuthenticationConfig {
                 secret: AuthSecret(LOCAL_WELL_KNOWN_TOKEN),
@@ -303,6 +306,34 @@ impl Config {
         config.profiles.remove(name);
         config.store_file(config_dir)
     }
+
+    pub fn rename_profile(
+        name: &ProfileName,
+        new_name: ProfileName,
+        config_dir: &Path,
+    ) -> anyhow::Result<()> {
+        let mut config = Self::from_dir(config_dir)?;
+
+        let Some(profile) = config.profiles.remove(name) else {
+            bail!("No profile {} in configuration", name.0);
+        };
+        if config.profiles.contains_key(&new_name) {
+            bail!("Profile {} already exists", new_name.0);
+        }
+
+        if config.default_profile.as_ref() == Some(name) {
+            config.default_profile = Some(new_name.clone());
+        }
+        config.profiles.insert(new_name, profile);
+
+        config.store_file(config_dir)
+    }
+}
+
+/// Portable set of profiles, used by profile export and import
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ProfilesExport {
+    pub profiles: BTreeMap<ProfileName, Profile>,
 }
 
 #[derive(Debug, Clone)]
@@ -421,11 +452,15 @@ impl HttpClientConfig {
 pub enum AuthenticationConfig {
     OAuth2(OAuth2AuthenticationConfig),
     Static(StaticAuthenticationConfig),
+    Stored(StoredAuthenticationConfig),
 }
 
 impl AuthenticationConfig {
     pub fn empty_oauth2() -> Self {
-        Self::OAuth2(OAuth2AuthenticationConfig { data: None })
+        Self::OAuth2(OAuth2AuthenticationConfig {
+            data: None,
+            flow: OAuth2Flow::default(),
+        })
     }
 
     pub fn static_token(token: Uuid) -> Self {
@@ -441,10 +476,49 @@ impl Default for AuthenticationConfig {
     }
 }
 
+impl From<ResolvedAuthenticationConfig> for AuthenticationConfig {
+    fn from(value: ResolvedAuthenticationConfig) -> Self {
+        match value {
+            ResolvedAuthenticationConfig::OAuth2(inner) => Self::OAuth2(inner),
+            ResolvedAuthenticationConfig::Static(inner) => Self::Static(inner),
+        }
+    }
+}
+
+/// Inline authentication config, stored credentials are resolved when loading the profile,
+/// see crate::credentials
+#[derive(Debug, Clone)]
+pub enum ResolvedAuthenticationConfig {
+    OAuth2(OAuth2AuthenticationConfig),
+    Static(StaticAuthenticationConfig),
+}
+
+impl ResolvedAuthenticationConfig {
+    /// Static tokens are always usable, OAuth2 profiles need a login first
+    pub fn is_logged_in(&self) -> bool {
+        match self {
+            Self::OAuth2(inner) => inner.data.is_some(),
+            Self::Static(_) => true,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OAuth2AuthenticationConfig {
     pub data: Option<OAuth2AuthenticationData>,
+    #[serde(default)]
+    pub flow: OAuth2Flow,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OAuth2Flow {
+    /// Browser based web flow, the CLI polls for the result
+    #[default]
+    Web,
+    /// Device code flow, the user code can be entered on any device with a browser
+    Device,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -463,6 +537,35 @@ pub struct StaticAuthenticationConfig {
     pub secret: AuthSecret,
 }
 
+// NOTE: the referenced secret is an inline (OAuth2 or Static) AuthenticationConfig, which is
+//       resolved when the profile is loaded, see crate::credentials
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoredAuthenticationConfig {
+    pub store: CredentialStoreConfig,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CredentialStoreConfig {
+    EncryptedFile(EncryptedFileCredentialStoreConfig),
+    Helper(HelperCredentialStoreConfig),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EncryptedFileCredentialStoreConfig {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub identity_file: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HelperCredentialStoreConfig {
+    pub command: String,
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize)]
 pub struct AuthSecret(pub Uuid);
 
@@ -483,3 +586,54 @@ impl From<AuthSecret> for TokenSecret {
         Self { value: value.0 }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::config::{AuthenticationConfig, Config, Profile, ProfileName, ProfilesExport};
+    use assert2::{check, let_assert};
+    use test_r::test;
+    use uuid::Uuid;
+
+    #[test]
+    fn rename_profile() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let config_dir = config_dir.path();
+        let staging = ProfileName::from("staging");
+        let renamed = ProfileName::from("stage");
+
+        Config::set_profile(staging.clone(), Profile::default(), config_dir).unwrap();
+        Config::set_active_profile_name(staging.clone(), config_dir).unwrap();
+
+        Config::rename_profile(&staging, renamed.clone(), config_dir).unwrap();
+
+        let config = Config::from_dir(config_dir).unwrap();
+        check!(!config.profiles.contains_key(&staging));
+        check!(config.profiles.contains_key(&renamed));
+        check!(config.default_profile_name() == renamed);
+
+        check!(Config::rename_profile(&staging, renamed.clone(), config_dir).is_err());
+        check!(Config::rename_profile(&renamed, ProfileName::local(), config_dir).is_err());
+    }
+
+    #[test]
+    fn profiles_export_round_trip() {
+        let token = Uuid::new_v4();
+        let mut export = ProfilesExport::default();
+        export.profiles.insert(
+            ProfileName::from("ci"),
+            Profile {
+                project: Some("my-project".to_string()),
+                auth: AuthenticationConfig::static_token(token),
+                ..Profile::default()
+            },
+        );
+
+        let json = serde_json::to_string_pretty(&export).unwrap();
+        let imported: ProfilesExport = serde_json::from_str(&json).unwrap();
+
+        let_assert!(Some(profile) = imported.profiles.get(&ProfileName::from("ci")));
+        check!(profile.project.as_deref() == Some("my-project"));
+        let_assert!(AuthenticationConfig::Static(auth) = &profile.auth);
+        check!(auth.secret.0 == token);
+    }
+}
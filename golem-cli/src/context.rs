@@ -14,17 +14,19 @@
 
 use crate::app::context::ApplicationContext;
 use crate::auth::{Auth, Authentication};
-use crate::command::shared_args::UpdateOrRedeployArgs;
+use crate::command::shared_args::{UpdateOrRedeployArgs, WorkerRolloutArgs};
 use crate::command::GolemCliGlobalFlags;
 use crate::command_handler::interactive::InteractiveHandler;
-use crate::config::AuthenticationConfig;
+use crate::config::ResolvedAuthenticationConfig;
 use crate::config::{ClientConfig, Config, HttpClientConfig, NamedProfile, Profile, ProfileName};
+use crate::credentials::resolve_authentication_config;
 use crate::error::{ContextInitHintError, HintError, NonSuccessfulExit};
 use crate::log::{log_action, set_log_output, LogColorize, LogOutput, Output};
 use crate::model::app::{AppBuildStep, ApplicationSourceMode};
 use crate::model::app::{ApplicationConfig, BuildProfileName as AppBuildProfileName};
 use crate::model::text::fmt::log_error;
-use crate::model::{app_raw, Format, ProjectReference};
+use crate::model::worker_shell::history_file_name;
+use crate::model::{app_raw, ComponentName, Format, ProjectReference, WorkerName};
 use crate::model::{AccountDetails, AccountId, PluginReference};
 use crate::wasm_rpc_stubgen::stub::RustDependencyOverride;
 use anyhow::{anyhow, bail, Context as AnyhowContext};
@@ -47,6 +49,7 @@ use golem_client::api::TokenClientLive as TokenClientCloud;
 use golem_client::api::WorkerClientLive as WorkerClientCloud;
 use golem_client::api::{AccountClient, AccountSummaryClientLive as AccountSummaryClientCloud};
 use golem_client::api::{AccountClientLive as AccountClientCloud, LoginClientLive};
+use golem_client::model::Token;
 use golem_client::{Context as ContextCloud, Security};
 use golem_rib_repl::ReplComponentDependencies;
 use golem_templates::model::{ComposableAppGroupName, GuestLanguage};
@@ -70,6 +73,7 @@ pub struct Context {
     update_or_redeploy: UpdateOrRedeployArgs,
     profile_name: ProfileName,
     profile: Profile,
+    auth_config: ResolvedAuthenticationConfig,
     available_profile_names: BTreeSet<ProfileName>,
     app_context_config: ApplicationContextConfig,
     http_batch_size: u64,
@@ -127,11 +131,12 @@ impl Context {
         let app_source_mode = preloaded_app.source_mode;
         let manifest_profiles = preloaded_app.profiles.unwrap_or_default();
 
-        let (available_profile_names, profile, manifest_profile) = load_merged_profiles(
-            &config_dir,
-            app_context_config.requested_profile_name.as_ref(),
-            manifest_profiles,
-        )?;
+        let (available_profile_names, profile, manifest_profile, auth_config) =
+            load_merged_profiles(
+                &config_dir,
+                app_context_config.requested_profile_name.as_ref(),
+                manifest_profiles,
+            )?;
 
         debug!(profile_name=%profile.name, manifest_profile=?manifest_profile, "Loaded profiles");
 
@@ -159,15 +164,23 @@ impl Context {
             if manifest_profile.redeploy_all == Some(true) {
                 update_or_redeploy.redeploy_all = true;
             }
+
+            if let Some(worker_rollout) = &manifest_profile.worker_rollout {
+                update_or_redeploy.rollout = WorkerRolloutArgs::from_manifest(worker_rollout);
+            }
         }
 
-        let project = match manifest_profile.as_ref().and_then(|m| m.project.as_ref()) {
+        let project = match manifest_profile
+            .as_ref()
+            .and_then(|m| m.project.as_ref())
+            .or(profile.profile.project.as_ref())
+        {
             Some(project) => Some(
                 ProjectReference::from_str(project.as_str())
                     .map_err(|err| anyhow!("{}", err))
                     .with_context(|| {
                         anyhow!(
-                            "Failed to parse project for manifest profile {}",
+                            "Failed to parse project for profile {}",
                             profile.name.0.log_color_highlight()
                         )
                     })?,
@@ -180,6 +193,8 @@ impl Context {
         let log_output = log_output_for_help.unwrap_or(match format {
             Format::Json => Output::Stderr,
             Format::Yaml => Output::Stderr,
+            Format::Csv => Output::Stderr,
+            Format::Ndjson => Output::Stderr,
             Format::Text => Output::Stdout,
         });
 
@@ -211,6 +226,7 @@ impl Context {
             update_or_redeploy,
             profile_name: profile.name,
             profile: profile.profile,
+            auth_config,
             available_profile_names,
             app_context_config,
             http_batch_size: http_batch_size.unwrap_or(50),
@@ -248,6 +264,24 @@ impl Context {
         Ok(history_file)
     }
 
+    pub async fn worker_shell_history_file(
+        &self,
+        component_name: &ComponentName,
+        worker_name: &WorkerName,
+    ) -> anyhow::Result<PathBuf> {
+        let app_ctx = self.app_context_lock().await;
+        let history_dir = match app_ctx.opt()? {
+            Some(app_ctx) => app_ctx.application.worker_shell_history_dir(),
+            None => self.config_dir.join("worker-shell-history"),
+        };
+        let history_file = history_dir.join(history_file_name(component_name, worker_name));
+        debug!(
+            history_file = %history_file.display(),
+            "Selected worker shell history file"
+        );
+        Ok(history_file)
+    }
+
     pub fn format(&self) -> Format {
         self.format
     }
@@ -296,7 +330,7 @@ impl Context {
                     self.client_config.clone(),
                     self.auth_token_override,
                     &self.profile_name,
-                    &self.profile.auth,
+                    &self.auth_config,
                     self.config_dir(),
                 )
                 .await?;
@@ -310,6 +344,47 @@ impl Context {
             .await
     }
 
+    // NOTE: used for cross-profile operations, the clients are not cached, and the auth token
+    //       override is only applied for the selected profile
+    pub async fn golem_clients_for_profile(
+        &self,
+        profile_name: &ProfileName,
+    ) -> anyhow::Result<GolemClients> {
+        let Some(profile) = Config::get_profile(self.config_dir(), profile_name)? else {
+            bail!(
+                "Profile {} not found",
+                profile_name.0.log_color_error_highlight()
+            );
+        };
+
+        let auth_config = if profile_name == &self.profile_name {
+            self.auth_config.clone()
+        } else {
+            resolve_profile_authentication_config(self.config_dir(), &profile)?
+        };
+
+        GolemClients::new(
+            ClientConfig::from(&profile.profile),
+            (profile_name == &self.profile_name)
+                .then_some(self.auth_token_override)
+                .flatten(),
+            profile_name,
+            &auth_config,
+            self.config_dir(),
+        )
+        .await
+    }
+
+    /// Returns whether a non-default project is selected for the profile
+    pub fn profile_has_project(&self, profile_name: &ProfileName) -> anyhow::Result<bool> {
+        if profile_name == &self.profile_name {
+            return Ok(self.project.is_some());
+        }
+
+        Ok(Config::get_profile(self.config_dir(), profile_name)?
+            .is_some_and(|profile| profile.profile.project.is_some()))
+    }
+
     #[cfg(feature = "server-commands")]
     async fn start_local_server_if_needed(&self, clients: &GolemClients) -> anyhow::Result<()> {
         if !self.profile_name.is_builtin_local() {
@@ -527,7 +602,7 @@ impl GolemClients {
         config: ClientConfig,
         token_override: Option<Uuid>,
         profile_name: &ProfileName,
-        auth_config: &AuthenticationConfig,
+        auth_config: &ResolvedAuthenticationConfig,
         config_dir: &Path,
     ) -> anyhow::Result<Self> {
         let healthcheck_http_client = new_reqwest_client(&config.health_check_http_client_config)?;
@@ -535,15 +610,7 @@ impl GolemClients {
         let service_http_client = new_reqwest_client(&config.service_http_client_config)?;
         let invoke_http_client = new_reqwest_client(&config.invoke_http_client_config)?;
 
-        let auth = Auth::new(LoginClientLive {
-            context: ContextCloud {
-                client: service_http_client.clone(),
-                base_url: config.cloud_url.clone(),
-                security_token: Security::Empty,
-            },
-        });
-
-        let authentication = auth
+        let authentication = new_auth(&config)?
             .authenticate(token_override, auth_config, config_dir, profile_name)
             .await?;
 
@@ -654,6 +721,10 @@ impl GolemClients {
     pub fn auth_token(&self) -> String {
         self.authentication.0.secret.value.to_string()
     }
+
+    pub fn token(&self) -> &Token {
+        &self.authentication.0.data
+    }
 }
 
 struct ApplicationContextConfig {
@@ -829,6 +900,16 @@ impl Default for RibReplState {
     }
 }
 
+pub fn new_auth(config: &ClientConfig) -> anyhow::Result<Auth> {
+    Ok(Auth::new(LoginClientLive {
+        context: ContextCloud {
+            client: new_reqwest_client(&config.service_http_client_config)?,
+            base_url: config.cloud_url.clone(),
+            security_token: Security::Empty,
+        },
+    }))
+}
+
 fn new_reqwest_client(config: &HttpClientConfig) -> anyhow::Result<reqwest::Client> {
     let mut builder = reqwest::Client::builder();
 
@@ -851,7 +932,7 @@ fn new_reqwest_client(config: &HttpClientConfig) -> anyhow::Result<reqwest::Clie
 
 /// Finds the requested or the default profile in the global CLI config
 /// and in the application manifest. The global config gets overrides applied from
-/// the manifest profile.
+/// the manifest profile, and its stored credentials are resolved.
 ///
 /// NOTE: Both of the profiles are returned, because currently the set of properties
 ///       are different. Eventually they should converge, but that will need breaking
@@ -864,6 +945,7 @@ fn load_merged_profiles(
     BTreeSet<ProfileName>,
     NamedProfile,
     Option<app_raw::Profile>,
+    ResolvedAuthenticationConfig,
 )> {
     let mut available_profile_names = BTreeSet::new();
 
@@ -971,7 +1053,26 @@ fn load_merged_profiles(
         }
     };
 
-    Ok((available_profile_names, profile, manifest_profile))
+    let auth_config = resolve_profile_authentication_config(config_dir, &profile)?;
+
+    Ok((
+        available_profile_names,
+        profile,
+        manifest_profile,
+        auth_config,
+    ))
+}
+
+fn resolve_profile_authentication_config(
+    config_dir: &Path,
+    profile: &NamedProfile,
+) -> anyhow::Result<ResolvedAuthenticationConfig> {
+    resolve_authentication_config(config_dir, &profile.profile.auth).with_context(|| {
+        anyhow!(
+            "Failed to resolve credentials for profile {}",
+            profile.name.0.log_color_highlight()
+        )
+    })
 }
 
 pub async fn check_http_response_success(
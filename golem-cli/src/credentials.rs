@@ -0,0 +1,607 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::command_handler::interactive::InteractiveHandler;
+use crate::config::{
+    AuthenticationConfig, CredentialStoreConfig, EncryptedFileCredentialStoreConfig,
+    HelperCredentialStoreConfig, ResolvedAuthenticationConfig, StoredAuthenticationConfig,
+};
+use crate::fs;
+use crate::log::LogColorize;
+use age::secrecy::SecretString;
+use anyhow::{anyhow, bail, Context};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::str::FromStr;
+use std::sync::Mutex;
+use tracing::debug;
+
+pub const CREDENTIAL_STORE_PASSPHRASE_ENV_VAR: &str = "GOLEM_CREDENTIAL_STORE_PASSPHRASE";
+
+// NOTE: resolved credentials and passphrases are cached for the whole process, so one command
+//       calls the stores (and prompts for passphrases) at most once per stored credential
+static RESOLVED_CREDENTIALS: Mutex<BTreeMap<String, ResolvedAuthenticationConfig>> =
+    Mutex::new(BTreeMap::new());
+static PASSPHRASES: Mutex<BTreeMap<PathBuf, String>> = Mutex::new(BTreeMap::new());
+
+// NOTE: stores hold inline authentication configs (OAuth2 or Static), profiles only keep
+//       a StoredAuthenticationConfig reference to them
+pub trait CredentialStore {
+    fn get(&self, key: &str) -> anyhow::Result<Option<AuthenticationConfig>>;
+    fn store(&self, key: &str, auth: &AuthenticationConfig) -> anyhow::Result<()>;
+    fn erase(&self, key: &str) -> anyhow::Result<()>;
+}
+
+pub fn credential_store(
+    config_dir: &Path,
+    config: &CredentialStoreConfig,
+) -> Box<dyn CredentialStore> {
+    match config {
+        CredentialStoreConfig::EncryptedFile(config) => {
+            Box::new(EncryptedFileCredentialStore::new(config_dir, config))
+        }
+        CredentialStoreConfig::Helper(config) => Box::new(HelperCredentialStore::new(config)),
+    }
+}
+
+/// Returns the inline authentication config, stored references are resolved using their stores
+pub fn resolve_authentication_config(
+    config_dir: &Path,
+    auth: &AuthenticationConfig,
+) -> anyhow::Result<ResolvedAuthenticationConfig> {
+    match auth {
+        AuthenticationConfig::OAuth2(inner) => {
+            Ok(ResolvedAuthenticationConfig::OAuth2(inner.clone()))
+        }
+        AuthenticationConfig::Static(inner) => {
+            Ok(ResolvedAuthenticationConfig::Static(inner.clone()))
+        }
+        AuthenticationConfig::Stored(stored) => {
+            let cache_key = resolved_credentials_cache_key(config_dir, stored)?;
+            if let Some(resolved) = RESOLVED_CREDENTIALS.lock().unwrap().get(&cache_key) {
+                return Ok(resolved.clone());
+            }
+
+            debug!(key = stored.key, "Resolving stored credentials");
+            let resolved = match credential_store(config_dir, &stored.store).get(&stored.key)? {
+                Some(AuthenticationConfig::OAuth2(inner)) => {
+                    ResolvedAuthenticationConfig::OAuth2(inner)
+                }
+                Some(AuthenticationConfig::Static(inner)) => {
+                    ResolvedAuthenticationConfig::Static(inner)
+                }
+                Some(AuthenticationConfig::Stored(_)) => bail!(
+                    "Stored credentials {} must not reference other stored credentials",
+                    stored.key.log_color_error_highlight()
+                ),
+                None => bail!(
+                    "Stored credentials {} not found",
+                    stored.key.log_color_error_highlight()
+                ),
+            };
+
+            RESOLVED_CREDENTIALS
+                .lock()
+                .unwrap()
+                .insert(cache_key, resolved.clone());
+            Ok(resolved)
+        }
+    }
+}
+
+pub fn store_authentication_config(
+    config_dir: &Path,
+    stored: &StoredAuthenticationConfig,
+    auth: &AuthenticationConfig,
+) -> anyhow::Result<()> {
+    let resolved = match auth {
+        AuthenticationConfig::OAuth2(inner) => ResolvedAuthenticationConfig::OAuth2(inner.clone()),
+        AuthenticationConfig::Static(inner) => ResolvedAuthenticationConfig::Static(inner.clone()),
+        AuthenticationConfig::Stored(_) => bail!("Only inline credentials can be stored"),
+    };
+    credential_store(config_dir, &stored.store).store(&stored.key, auth)?;
+    RESOLVED_CREDENTIALS.lock().unwrap().insert(
+        resolved_credentials_cache_key(config_dir, stored)?,
+        resolved,
+    );
+    Ok(())
+}
+
+pub fn erase_authentication_config(
+    config_dir: &Path,
+    stored: &StoredAuthenticationConfig,
+) -> anyhow::Result<()> {
+    credential_store(config_dir, &stored.store).erase(&stored.key)?;
+    RESOLVED_CREDENTIALS
+        .lock()
+        .unwrap()
+        .remove(&resolved_credentials_cache_key(config_dir, stored)?);
+    Ok(())
+}
+
+/// Moves the stored credentials to a new key in the same store, returns the new reference
+pub fn move_authentication_config(
+    config_dir: &Path,
+    stored: &StoredAuthenticationConfig,
+    new_key: String,
+) -> anyhow::Result<StoredAuthenticationConfig> {
+    let auth =
+        resolve_authentication_config(config_dir, &AuthenticationConfig::Stored(stored.clone()))?;
+    let moved = StoredAuthenticationConfig {
+        store: stored.store.clone(),
+        key: new_key,
+    };
+    store_authentication_config(config_dir, &moved, &auth.into())?;
+    erase_authentication_config(config_dir, stored)?;
+    Ok(moved)
+}
+
+fn resolved_credentials_cache_key(
+    config_dir: &Path,
+    stored: &StoredAuthenticationConfig,
+) -> anyhow::Result<String> {
+    Ok(format!(
+        "{}\n{}\n{}",
+        config_dir.display(),
+        serde_json::to_string(&stored.store)?,
+        stored.key
+    ))
+}
+
+/// Stores all credentials in a single age encrypted JSON file in the config dir, using either
+/// an age identity file or a passphrase
+pub struct EncryptedFileCredentialStore {
+    path: PathBuf,
+    identity_file: Option<PathBuf>,
+}
+
+impl EncryptedFileCredentialStore {
+    pub fn new(config_dir: &Path, config: &EncryptedFileCredentialStoreConfig) -> Self {
+        Self {
+            path: config_dir.join("credentials.age"),
+            identity_file: config.identity_file.clone(),
+        }
+    }
+
+    fn load(&self) -> anyhow::Result<BTreeMap<String, AuthenticationConfig>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+
+        let encrypted = fs::read(&self.path)?;
+        let decrypted = match &self.identity_file {
+            Some(identity_file) => age::decrypt(&self.identity(identity_file)?, &encrypted),
+            None => age::decrypt(&age::scrypt::Identity::new(self.passphrase()?), &encrypted),
+        }
+        .map_err(|err| {
+            anyhow!(
+                "Failed to decrypt credential store {}: {err}",
+                self.path.display().to_string().log_color_error_highlight()
+            )
+        })?;
+
+        serde_json::from_slice(&decrypted).with_context(|| {
+            anyhow!(
+                "Failed to deserialize credential store {}",
+                self.path.display().to_string().log_color_error_highlight()
+            )
+        })
+    }
+
+    fn save(&self, credentials: &BTreeMap<String, AuthenticationConfig>) -> anyhow::Result<()> {
+        let plaintext = serde_json::to_vec(credentials)?;
+        let encrypted = match &self.identity_file {
+            Some(identity_file) => {
+                age::encrypt(&self.identity(identity_file)?.to_public(), &plaintext)
+            }
+            None => age::encrypt(&age::scrypt::Recipient::new(self.passphrase()?), &plaintext),
+        }
+        .map_err(|err| anyhow!("Failed to encrypt credential store: {err}"))?;
+
+        fs::write(&self.path, encrypted)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))
+                .with_context(|| {
+                    anyhow!(
+                        "Failed to set permissions for credential store {}",
+                        self.path.display()
+                    )
+                })?;
+        }
+
+        Ok(())
+    }
+
+    fn passphrase(&self) -> anyhow::Result<SecretString> {
+        let mut passphrases = PASSPHRASES.lock().unwrap();
+        let passphrase = match passphrases.get(&self.path) {
+            Some(passphrase) => passphrase.clone(),
+            None => {
+                let passphrase = match std::env::var(CREDENTIAL_STORE_PASSPHRASE_ENV_VAR) {
+                    Ok(passphrase) => passphrase,
+                    Err(_) => InteractiveHandler::prompt_credential_store_passphrase(
+                        CREDENTIAL_STORE_PASSPHRASE_ENV_VAR,
+                        !self.path.exists(),
+                    )?,
+                };
+                passphrases.insert(self.path.clone(), passphrase.clone());
+                passphrase
+            }
+        };
+        Ok(SecretString::from(passphrase))
+    }
+
+    fn identity(&self, identity_file: &Path) -> anyhow::Result<age::x25519::Identity> {
+        let identity = fs::read_to_string(identity_file)?;
+        let identity = identity
+            .lines()
+            .map(|line| line.trim())
+            .find(|line| !line.is_empty() && !line.starts_with('#'))
+            .ok_or_else(|| {
+                anyhow!(
+                    "No age identity found in {}",
+                    identity_file
+                        .display()
+                        .to_string()
+                        .log_color_error_highlight()
+                )
+            })?;
+        age::x25519::Identity::from_str(identity).map_err(|err| {
+            anyhow!(
+                "Failed to parse age identity in {}: {err}",
+                identity_file
+                    .display()
+                    .to_string()
+                    .log_color_error_highlight()
+            )
+        })
+    }
+}
+
+impl CredentialStore for EncryptedFileCredentialStore {
+    fn get(&self, key: &str) -> anyhow::Result<Option<AuthenticationConfig>> {
+        Ok(self.load()?.remove(key))
+    }
+
+    fn store(&self, key: &str, auth: &AuthenticationConfig) -> anyhow::Result<()> {
+        let mut credentials = self.load()?;
+        credentials.insert(key.to_string(), auth.clone());
+        self.save(&credentials)
+    }
+
+    fn erase(&self, key: &str) -> anyhow::Result<()> {
+        let mut credentials = self.load()?;
+        if credentials.remove(key).is_some() {
+            self.save(&credentials)?;
+        }
+        Ok(())
+    }
+}
+
+/// Delegates storing the credentials to an external command, similar to git credential helpers.
+///
+/// The helper is called with the action (get, store or erase) as its last argument, and receives
+/// `key=<key>` (and `secret=<json>` for store) lines on its standard input. For get, the helper
+/// has to print the stored secret JSON to its standard output, or nothing if the key is unknown.
+pub struct HelperCredentialStore {
+    command: String,
+}
+
+impl HelperCredentialStore {
+    pub fn new(config: &HelperCredentialStoreConfig) -> Self {
+        Self {
+            command: config.command.clone(),
+        }
+    }
+
+    fn call(&self, action: &str, input: &str) -> anyhow::Result<String> {
+        let args = shlex::split(&self.command).ok_or_else(|| {
+            anyhow!(
+                "Failed to parse credential helper command: {}",
+                self.command.log_color_error_highlight()
+            )
+        })?;
+        let Some((program, args)) = args.split_first() else {
+            bail!("Empty credential helper command");
+        };
+
+        debug!(command = self.command, action, "Calling credential helper");
+
+        let mut child = Command::new(program)
+            .args(args)
+            .arg(action)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| {
+                anyhow!(
+                    "Failed to start credential helper: {}",
+                    self.command.log_color_error_highlight()
+                )
+            })?;
+
+        child
+            .stdin
+            .take()
+            .expect("Missing credential helper stdin")
+            .write_all(input.as_bytes())
+            .context("Failed to write to credential helper")?;
+
+        let output = child
+            .wait_with_output()
+            .context("Failed to wait for credential helper")?;
+
+        if !output.status.success() {
+            bail!(
+                "Credential helper {} failed for action {}: {}",
+                self.command.log_color_error_highlight(),
+                action.log_color_highlight(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        String::from_utf8(output.stdout).context("Credential helper output is not valid UTF-8")
+    }
+}
+
+impl CredentialStore for HelperCredentialStore {
+    fn get(&self, key: &str) -> anyhow::Result<Option<AuthenticationConfig>> {
+        let output = self.call("get", &format!("key={key}\n"))?;
+        let output = output.trim();
+        if output.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(serde_json::from_str(output).context(
+            "Failed to deserialize credential helper output",
+        )?))
+    }
+
+    fn store(&self, key: &str, auth: &AuthenticationConfig) -> anyhow::Result<()> {
+        self.call(
+            "store",
+            &format!("key={key}\nsecret={}\n", serde_json::to_string(auth)?),
+        )?;
+        Ok(())
+    }
+
+    fn erase(&self, key: &str) -> anyhow::Result<()> {
+        self.call("erase", &format!("key={key}\n"))?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::config::{
+        AuthenticationConfig, CredentialStoreConfig, EncryptedFileCredentialStoreConfig,
+        HelperCredentialStoreConfig, ResolvedAuthenticationConfig, StoredAuthenticationConfig,
+    };
+    use crate::credentials::{
+        credential_store, erase_authentication_config, move_authentication_config,
+        resolve_authentication_config, store_authentication_config,
+    };
+    use age::secrecy::ExposeSecret;
+    use assert2::{check, let_assert};
+    use std::path::Path;
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn round_trip(config_dir: &Path, store: CredentialStoreConfig) {
+        let token = Uuid::new_v4();
+        let credential_store = credential_store(config_dir, &store);
+        let stored = StoredAuthenticationConfig {
+            store,
+            key: "profile-key".to_string(),
+        };
+        let stored_auth = AuthenticationConfig::Stored(stored.clone());
+
+        check!(credential_store.get("profile-key").unwrap().is_none());
+        check!(resolve_authentication_config(config_dir, &stored_auth).is_err());
+
+        store_authentication_config(
+            config_dir,
+            &stored,
+            &AuthenticationConfig::static_token(token),
+        )
+        .unwrap();
+        let_assert!(
+            Ok(Some(AuthenticationConfig::Static(inner))) = credential_store.get("profile-key")
+        );
+        check!(inner.secret.0 == token);
+        let_assert!(
+            Ok(ResolvedAuthenticationConfig::Static(resolved)) =
+                resolve_authentication_config(config_dir, &stored_auth)
+        );
+        check!(resolved.secret.0 == token);
+
+        check!(store_authentication_config(config_dir, &stored, &stored_auth).is_err());
+
+        erase_authentication_config(config_dir, &stored).unwrap();
+        check!(credential_store.get("profile-key").unwrap().is_none());
+        check!(resolve_authentication_config(config_dir, &stored_auth).is_err());
+    }
+
+    #[test]
+    fn encrypted_file_store_round_trip() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let identity_file = config_dir.path().join("identity.txt");
+        std::fs::write(
+            &identity_file,
+            format!(
+                "# test identity\n{}\n",
+                identity.to_string().expose_secret()
+            ),
+        )
+        .unwrap();
+
+        round_trip(
+            config_dir.path(),
+            CredentialStoreConfig::EncryptedFile(EncryptedFileCredentialStoreConfig {
+                identity_file: Some(identity_file),
+            }),
+        );
+    }
+
+    #[test]
+    fn encrypted_file_store_is_encrypted() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let identity_file = config_dir.path().join("identity.txt");
+        std::fs::write(&identity_file, identity.to_string().expose_secret()).unwrap();
+
+        let token = Uuid::new_v4();
+        store_authentication_config(
+            config_dir.path(),
+            &StoredAuthenticationConfig {
+                store: CredentialStoreConfig::EncryptedFile(EncryptedFileCredentialStoreConfig {
+                    identity_file: Some(identity_file),
+                }),
+                key: "profile-key".to_string(),
+            },
+            &AuthenticationConfig::static_token(token),
+        )
+        .unwrap();
+
+        let content = std::fs::read(config_dir.path().join("credentials.age")).unwrap();
+        let content = String::from_utf8_lossy(&content);
+        check!(!content.contains("profile-key"));
+        check!(!content.contains(&token.to_string()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn helper_store_round_trip() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let helper_dir = config_dir.path().join("helper");
+        std::fs::create_dir(&helper_dir).unwrap();
+        let helper = config_dir.path().join("helper.sh");
+        std::fs::write(
+            &helper,
+            r#"dir="$1"
+input=$(cat)
+key=$(printf '%s\n' "$input" | sed -n 's/^key=//p')
+case "$2" in
+  get) if [ -f "$dir/$key" ]; then cat "$dir/$key"; fi ;;
+  store) printf '%s\n' "$input" | sed -n 's/^secret=//p' > "$dir/$key" ;;
+  erase) rm -f "$dir/$key" ;;
+  *) exit 1 ;;
+esac
+"#,
+        )
+        .unwrap();
+
+        round_trip(
+            config_dir.path(),
+            CredentialStoreConfig::Helper(HelperCredentialStoreConfig {
+                command: format!("sh {} {}", helper.display(), helper_dir.display()),
+            }),
+        );
+    }
+
+    #[test]
+    fn move_stored_credentials() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let identity = age::x25519::Identity::generate();
+        let identity_file = config_dir.path().join("identity.txt");
+        std::fs::write(&identity_file, identity.to_string().expose_secret()).unwrap();
+        let store = CredentialStoreConfig::EncryptedFile(EncryptedFileCredentialStoreConfig {
+            identity_file: Some(identity_file),
+        });
+        let credential_store = credential_store(config_dir.path(), &store);
+
+        let token = Uuid::new_v4();
+        let stored = StoredAuthenticationConfig {
+            store,
+            key: "old-profile".to_string(),
+        };
+        store_authentication_config(
+            config_dir.path(),
+            &stored,
+            &AuthenticationConfig::static_token(token),
+        )
+        .unwrap();
+
+        let moved =
+            move_authentication_config(config_dir.path(), &stored, "new-profile".to_string())
+                .unwrap();
+        check!(moved.key == "new-profile");
+        check!(credential_store.get("old-profile").unwrap().is_none());
+        let_assert!(
+            Ok(Some(AuthenticationConfig::Static(inner))) = credential_store.get("new-profile")
+        );
+        check!(inner.secret.0 == token);
+        check!(resolve_authentication_config(
+            config_dir.path(),
+            &AuthenticationConfig::Stored(stored)
+        )
+        .is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resolved_credentials_are_cached() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let calls = config_dir.path().join("calls.txt");
+        let token = Uuid::new_v4();
+        let stored_auth = AuthenticationConfig::Stored(StoredAuthenticationConfig {
+            store: CredentialStoreConfig::Helper(HelperCredentialStoreConfig {
+                command: format!(
+                    "sh -c 'echo $1 >> {}; echo {}' helper",
+                    calls.display(),
+                    serde_json::to_string(
+                        &serde_json::to_string(&AuthenticationConfig::static_token(token)).unwrap()
+                    )
+                    .unwrap()
+                ),
+            }),
+            key: "profile-key".to_string(),
+        });
+
+        for _ in 0..3 {
+            let_assert!(
+                Ok(ResolvedAuthenticationConfig::Static(resolved)) =
+                    resolve_authentication_config(config_dir.path(), &stored_auth)
+            );
+            check!(resolved.secret.0 == token);
+        }
+        check!(std::fs::read_to_string(&calls).unwrap() == "get\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn helper_store_failure() {
+        let config_dir = tempfile::tempdir().unwrap();
+        let stored = StoredAuthenticationConfig {
+            store: CredentialStoreConfig::Helper(HelperCredentialStoreConfig {
+                command: "sh -c 'echo broken >&2; exit 1' helper".to_string(),
+            }),
+            key: "profile-key".to_string(),
+        };
+
+        let_assert!(
+            Err(err) = resolve_authentication_config(
+                config_dir.path(),
+                &AuthenticationConfig::Stored(stored)
+            )
+        );
+        check!(err.to_string().contains("broken"));
+    }
+}
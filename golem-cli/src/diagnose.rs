@@ -19,6 +19,7 @@ use colored::Colorize;
 use golem_templates::model::GuestLanguage;
 use indoc::indoc;
 use regex::Regex;
+use serde::Serialize;
 use std::cmp::max;
 use std::collections::HashSet;
 use std::fmt::Display;
@@ -861,6 +862,43 @@ pub fn diagnose(dir: &Path, language: Option<GuestLanguage>) {
     }
 }
 
+/// Language and build tool versions detected for a component, recorded in the producers
+/// section of the linked WASM
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DetectedBuildTools {
+    pub language: Option<String>,
+    pub sdk: Vec<(String, String)>,
+    pub processed_by: Vec<(String, String)>,
+}
+
+pub fn detect_build_tools(dir: &Path) -> DetectedBuildTools {
+    let Some(selected_language) = SelectedLanguage::from_env(dir) else {
+        return DetectedBuildTools::default();
+    };
+
+    let mut detected = DetectedBuildTools {
+        language: Some(selected_language.language.to_string()),
+        ..DetectedBuildTools::default()
+    };
+
+    for tool in selected_language.language.tools() {
+        let Ok(version) = tool.get_version(&selected_language.project_dir) else {
+            continue;
+        };
+        let tool_version = (tool.metadata().short_name.to_string(), version);
+        match tool {
+            Tool::GolemSdkGo | Tool::GolemSdkRust | Tool::GolemSdkTypeScript => {
+                detected.sdk.push(tool_version)
+            }
+            // NOTE: targets are not versioned tools
+            Tool::RustTargetWasm32WasiP1 => {}
+            _ => detected.processed_by.push(tool_version),
+        }
+    }
+
+    detected
+}
+
 fn report_tools(all_tools: Vec<DetectedTool>) {
     let (name_padding, version_padding) = {
         let mut name_padding = 0;
@@ -24,12 +24,14 @@ pub mod command;
 pub mod command_handler;
 pub mod config;
 pub mod context;
+pub mod credentials;
 pub mod diagnose;
 pub mod error;
 pub mod fs;
 pub mod fuzzy;
 pub mod log;
 pub mod model;
+pub mod oci;
 pub mod validation;
 pub mod wasm_rpc_stubgen;
 
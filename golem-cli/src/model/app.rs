@@ -4,13 +4,16 @@ use crate::log::LogColorize;
 use crate::model::app::app_builder::{build_application, build_profiles};
 use crate::model::app_raw;
 use crate::model::component::AppComponentType;
+use crate::model::content_hash::ContentHash;
 use crate::model::template::Template;
+use crate::oci::OciReference;
 use crate::validation::{ValidatedResult, ValidationBuilder};
 use crate::wasm_rpc_stubgen::naming;
 use crate::wasm_rpc_stubgen::naming::wit::package_dep_dir_name_from_parser;
 use crate::wasm_rpc_stubgen::stub::RustDependencyOverride;
 use anyhow::anyhow;
 use golem_common::model::{ComponentFilePathWithPermissions, ComponentFilePermissions};
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
@@ -464,7 +467,23 @@ impl Display for DependencyType {
 pub enum BinaryComponentSource {
     AppComponent { name: AppComponentName },
     LocalFile { path: PathBuf },
-    Url { url: Url },
+    Url { url: Url, hash: Option<ContentHash> },
+    Oci { reference: OciReference },
+}
+
+impl BinaryComponentSource {
+    pub fn from_url(url: &str) -> Result<Self, String> {
+        if OciReference::is_oci_url(url) {
+            Ok(BinaryComponentSource::Oci {
+                reference: OciReference::from_str(url)?,
+            })
+        } else {
+            Ok(BinaryComponentSource::Url {
+                url: Url::from_str(url).map_err(|err| format!("Invalid URL {url}: {err}"))?,
+                hash: None,
+            })
+        }
+    }
 }
 
 impl Display for BinaryComponentSource {
@@ -472,8 +491,133 @@ impl Display for BinaryComponentSource {
         match self {
             BinaryComponentSource::AppComponent { name } => write!(f, "{name}"),
             BinaryComponentSource::LocalFile { path } => write!(f, "{}", path.display()),
-            BinaryComponentSource::Url { url } => write!(f, "{url}"),
+            BinaryComponentSource::Url { url, .. } => write!(f, "{url}"),
+            BinaryComponentSource::Oci { reference } => write!(f, "{reference}"),
+        }
+    }
+}
+
+/// Default registry namespace for packages in the wasi namespace, matching the wkg defaults
+pub const DEFAULT_WASI_WIT_REGISTRY: &str = "ghcr.io/webassembly";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RemoteWitDepSource {
+    Registry {
+        reference: OciReference,
+    },
+    Git {
+        url: String,
+        rev: Option<String>,
+        path: PathBuf,
+    },
+}
+
+impl Display for RemoteWitDepSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RemoteWitDepSource::Registry { reference } => write!(f, "{reference}"),
+            RemoteWitDepSource::Git { url, rev, path } => {
+                write!(
+                    f,
+                    "git+{url}#{}:{}",
+                    rev.as_deref().unwrap_or("HEAD"),
+                    path.display()
+                )
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RemoteWitDep {
+    pub package: wit_parser::PackageName,
+    pub source: RemoteWitDepSource,
+}
+
+impl RemoteWitDep {
+    pub fn from_raw(raw: app_raw::RemoteWitDep) -> Result<Self, String> {
+        let package = parse_wit_package_name(&raw.package)?;
+
+        let source = match (raw.registry, raw.git) {
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "Only one of the {}/{} fields can be specified",
+                    "registry".log_color_error_highlight(),
+                    "git".log_color_error_highlight()
+                ))
+            }
+            (registry, None) => {
+                if raw.rev.is_some() || raw.path.is_some() {
+                    return Err(format!(
+                        "The {}/{} fields can only be used together with the {} field",
+                        "rev".log_color_error_highlight(),
+                        "path".log_color_error_highlight(),
+                        "git".log_color_error_highlight()
+                    ));
+                }
+                let Some(version) = &package.version else {
+                    return Err(format!(
+                        "Missing version for registry package {}, expected <namespace>:<name>@<version>",
+                        raw.package.log_color_error_highlight()
+                    ));
+                };
+                let registry = match registry {
+                    Some(registry) => registry,
+                    None if package.namespace == "wasi" => DEFAULT_WASI_WIT_REGISTRY.to_string(),
+                    None => {
+                        return Err(format!(
+                            "Missing {} or {} field for package {}",
+                            "registry".log_color_error_highlight(),
+                            "git".log_color_error_highlight(),
+                            raw.package.log_color_highlight()
+                        ))
+                    }
+                };
+                RemoteWitDepSource::Registry {
+                    reference: OciReference::from_str(&format!(
+                        "{}/{}/{}:{}",
+                        registry.trim_end_matches('/'),
+                        package.namespace,
+                        package.name,
+                        version
+                    ))?,
+                }
+            }
+            (None, Some(url)) => RemoteWitDepSource::Git {
+                url,
+                rev: raw.rev,
+                path: PathBuf::from(raw.path.unwrap_or_else(|| naming::wit::WIT_DIR.to_string())),
+            },
+        };
+
+        Ok(Self { package, source })
+    }
+}
+
+/// Parses WIT package names in <namespace>:<name>[@<version>] form
+pub fn parse_wit_package_name(package: &str) -> Result<wit_parser::PackageName, String> {
+    let (name, version) = match package.split_once('@') {
+        Some((name, version)) => (
+            name,
+            Some(
+                semver::Version::parse(version)
+                    .map_err(|err| format!("Invalid version in package {package}: {err}"))?,
+            ),
+        ),
+        None => (package, None),
+    };
+
+    match name.split_once(':') {
+        Some((namespace, name)) if !namespace.is_empty() && !name.is_empty() => {
+            Ok(wit_parser::PackageName {
+                namespace: namespace.to_string(),
+                name: name.to_string(),
+                version,
+            })
         }
+        _ => Err(format!(
+            "Invalid package name {package}, expected <namespace>:<name>[@<version>]"
+        )),
     }
 }
 
@@ -481,6 +625,45 @@ impl Display for BinaryComponentSource {
 pub struct DependentComponent {
     pub source: BinaryComponentSource,
     pub dep_type: DependencyType,
+    pub plug_selection: PlugSelection,
+}
+
+/// Selects the imports of a component which are plugged by a linked dependency
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PlugSelection {
+    /// Interfaces to plug by matching names, all matching interfaces are plugged if empty.
+    /// Interfaces matched without selection are plugged from the last dependency exporting them
+    pub interfaces: BTreeSet<String>,
+    /// Imports plugged by differently named exports
+    pub mappings: BTreeMap<String, String>,
+}
+
+impl PlugSelection {
+    pub fn is_default(&self) -> bool {
+        self.interfaces.is_empty() && self.mappings.is_empty()
+    }
+
+    /// Matches interface names with or without version, e.g. ns:pkg/iface matches ns:pkg/iface@1.0.0
+    pub fn interface_matches(selector: &str, name: &str) -> bool {
+        name == selector
+            || name
+                .split_once('@')
+                .is_some_and(|(name_without_version, _)| name_without_version == selector)
+    }
+}
+
+impl Display for PlugSelection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "interfaces: [{}], mappings: [{}]",
+            self.interfaces.iter().join(", "),
+            self.mappings
+                .iter()
+                .map(|(import, export)| format!("{import} <- {export}"))
+                .join(", ")
+        )
+    }
 }
 
 impl DependentComponent {
@@ -529,6 +712,7 @@ pub struct Application {
     all_sources: BTreeSet<PathBuf>,
     temp_dir: Option<WithSource<String>>,
     wit_deps: WithSource<Vec<String>>,
+    remote_wit_deps: WithSource<Vec<RemoteWitDep>>,
     components: BTreeMap<AppComponentName, Component>,
     dependencies: BTreeMap<AppComponentName, BTreeSet<DependentComponent>>,
     dependency_sources: BTreeMap<AppComponentName, BTreeMap<AppComponentName, PathBuf>>,
@@ -540,6 +724,7 @@ pub struct Application {
         ProfileName,
         BTreeMap<HttpApiDeploymentSite, WithSource<app_raw::HttpApiDeployment>>,
     >,
+    metadata: app_raw::ComponentMetadata,
 }
 
 impl Application {
@@ -583,12 +768,31 @@ impl Application {
     }
 
     pub fn wit_deps(&self) -> Vec<PathBuf> {
-        self.wit_deps
+        let mut wit_deps: Vec<PathBuf> = self
+            .wit_deps
             .value
             .iter()
             .cloned()
             .map(|path| self.wit_deps.source.join(path))
-            .collect()
+            .collect();
+
+        if !self.remote_wit_deps.value.is_empty() {
+            let vendored_deps_dir = self.remote_wit_deps_wit_dir().join(naming::wit::DEPS_DIR);
+            if !wit_deps.contains(&vendored_deps_dir) {
+                wit_deps.push(vendored_deps_dir);
+            }
+        }
+
+        wit_deps
+    }
+
+    pub fn remote_wit_deps(&self) -> &[RemoteWitDep] {
+        &self.remote_wit_deps.value
+    }
+
+    /// WIT dir where the remote WIT dependencies are vendored into, under its deps dir
+    pub fn remote_wit_deps_wit_dir(&self) -> PathBuf {
+        self.remote_wit_deps.source.join(naming::wit::WIT_DIR)
     }
 
     pub fn all_dependencies(&self) -> BTreeSet<DependentComponent> {
@@ -668,6 +872,10 @@ impl Application {
         self.temp_dir().join(".rib_repl_history")
     }
 
+    pub fn worker_shell_history_dir(&self) -> PathBuf {
+        self.temp_dir().join("worker-shell-history")
+    }
+
     fn component(&self, component_name: &AppComponentName) -> &Component {
         self.components
             .get(component_name)
@@ -779,6 +987,17 @@ impl Application {
         }
     }
 
+    pub fn component_metadata(
+        &self,
+        component_name: &AppComponentName,
+        profile: Option<&BuildProfileName>,
+    ) -> app_raw::ComponentMetadata {
+        let properties = self.component_properties(component_name, profile);
+        self.metadata
+            .clone()
+            .merge_with_overrides(properties.metadata.clone())
+    }
+
     pub fn component_name_as_safe_path_elem(&self, component_name: &AppComponentName) -> String {
         component_name.as_str().replace(":", "_")
     }
@@ -980,6 +1199,7 @@ pub struct ComponentProperties {
     pub files: Vec<InitialComponentFile>,
     pub plugins: Vec<PluginInstallation>,
     pub env: HashMap<String, String>,
+    pub metadata: app_raw::ComponentMetadata,
 }
 
 impl ComponentProperties {
@@ -1003,6 +1223,7 @@ impl ComponentProperties {
             files,
             plugins,
             env: Self::validate_and_normalize_env(validation, raw.env),
+            metadata: raw.metadata.unwrap_or_default(),
         })
     }
 
@@ -1083,6 +1304,10 @@ impl ComponentProperties {
                 .extend(Self::validate_and_normalize_env(validation, overrides.env));
         }
 
+        if let Some(metadata) = overrides.metadata {
+            self.metadata = self.metadata.merge_with_overrides(metadata);
+        }
+
         Ok((!any_errors).then_some(self))
     }
 
@@ -1136,6 +1361,7 @@ impl ComponentProperties {
 pub struct InitialComponentFile {
     pub source: InitialComponentFileSource,
     pub target: ComponentFilePathWithPermissions,
+    pub hash: Option<ContentHash>,
 }
 
 impl InitialComponentFile {
@@ -1152,6 +1378,30 @@ impl InitialComponentFile {
             })
             .ok()?;
 
+        let hash = match &file.hash {
+            Some(hash) => {
+                if !source.is_remote() {
+                    validation.push_context("source path", file.source_path.to_string());
+                    validation.add_error(format!(
+                        "The {} field can only be used for http(s) source paths",
+                        "hash".log_color_error_highlight()
+                    ));
+                    validation.pop_context();
+                    return None;
+                }
+                Some(
+                    ContentHash::from_str(hash)
+                        .map_err(|err| {
+                            validation.push_context("source path", file.source_path.to_string());
+                            validation.add_error(err);
+                            validation.pop_context();
+                        })
+                        .ok()?,
+                )
+            }
+            None => None,
+        };
+
         Some(InitialComponentFile {
             source,
             target: ComponentFilePathWithPermissions {
@@ -1160,6 +1410,7 @@ impl InitialComponentFile {
                     .permissions
                     .unwrap_or(ComponentFilePermissions::ReadOnly),
             },
+            hash,
         })
     }
 
@@ -1208,7 +1459,7 @@ impl InitialComponentFileSource {
         })?;
 
         let source_path_scheme = url.scheme();
-        let supported_schemes = ["http", "https", "file", ""];
+        let supported_schemes = ["http", "https", "file", "oci", ""];
         if !supported_schemes.contains(&source_path_scheme) {
             return Err(format!(
                 "Unsupported source path scheme: {}, supported schemes {}:",
@@ -1223,6 +1474,17 @@ impl InitialComponentFileSource {
         &self.0
     }
 
+    pub fn is_remote(&self) -> bool {
+        matches!(self.0.scheme(), "http" | "https")
+    }
+
+    /// Returns the OCI reference for oci:// sources
+    pub fn oci_reference(&self) -> Option<OciReference> {
+        (self.0.scheme() == "oci")
+            .then(|| OciReference::from_str(self.0.as_str()).ok())
+            .flatten()
+    }
+
     pub fn into_url(self) -> Url {
         self.0
     }
@@ -1277,6 +1539,7 @@ mod app_builder {
         HttpApiDeploymentSite, ResolvedComponentProperties, TemplateName, WithSource,
     };
     use crate::model::app_raw;
+    use crate::model::content_hash::ContentHash;
     use crate::model::deploy_diff::api_definition::normalize_http_api_binding_path;
     use crate::model::text::fmt::format_rib_source_for_error;
     use crate::validation::{ValidatedResult, ValidationBuilder};
@@ -1291,7 +1554,6 @@ mod app_builder {
     use std::fmt::Debug;
     use std::path::{Path, PathBuf};
     use std::str::FromStr;
-    use url::Url;
 
     // Load full manifest EXCEPT profiles
     pub fn build_application(
@@ -1313,6 +1575,8 @@ mod app_builder {
         Include,
         TempDir,
         WitDeps,
+        RemoteWitDeps,
+        Metadata,
         CustomCommand(String),
         Template(TemplateName),
         Dependency((AppComponentName, DependentComponent)),
@@ -1337,6 +1601,8 @@ mod app_builder {
                 UniqueSourceCheckedEntityKey::Include => property,
                 UniqueSourceCheckedEntityKey::TempDir => property,
                 UniqueSourceCheckedEntityKey::WitDeps => property,
+                UniqueSourceCheckedEntityKey::RemoteWitDeps => property,
+                UniqueSourceCheckedEntityKey::Metadata => property,
                 UniqueSourceCheckedEntityKey::CustomCommand(_) => "Custom command",
                 UniqueSourceCheckedEntityKey::Template(_) => "Template",
                 UniqueSourceCheckedEntityKey::Dependency(_) => "Dependency",
@@ -1361,6 +1627,12 @@ mod app_builder {
                 UniqueSourceCheckedEntityKey::WitDeps => {
                     "witDeps".log_color_highlight().to_string()
                 }
+                UniqueSourceCheckedEntityKey::RemoteWitDeps => {
+                    "remoteWitDeps".log_color_highlight().to_string()
+                }
+                UniqueSourceCheckedEntityKey::Metadata => {
+                    "metadata".log_color_highlight().to_string()
+                }
                 UniqueSourceCheckedEntityKey::CustomCommand(command_name) => {
                     command_name.log_color_highlight().to_string()
                 }
@@ -1419,6 +1691,7 @@ mod app_builder {
         include: Vec<String>,
         temp_dir: Option<WithSource<String>>,
         wit_deps: WithSource<Vec<String>>,
+        remote_wit_deps: WithSource<Vec<RemoteWitDep>>,
         templates: HashMap<TemplateName, app_raw::ComponentTemplate>,
         dependencies: BTreeMap<AppComponentName, BTreeSet<DependentComponent>>,
         custom_commands: HashMap<String, WithSource<Vec<app_raw::ExternalCommand>>>,
@@ -1438,6 +1711,8 @@ mod app_builder {
 
         profiles: BTreeMap<ProfileName, app_raw::Profile>,
 
+        metadata: app_raw::ComponentMetadata,
+
         all_sources: BTreeSet<PathBuf>,
         entity_sources: HashMap<UniqueSourceCheckedEntityKey, Vec<PathBuf>>,
     }
@@ -1493,6 +1768,7 @@ mod app_builder {
                 all_sources: builder.all_sources,
                 temp_dir: builder.temp_dir,
                 wit_deps: builder.wit_deps,
+                remote_wit_deps: builder.remote_wit_deps,
                 components: builder.resolved_components,
                 dependencies: builder.dependencies,
                 dependency_sources,
@@ -1501,6 +1777,7 @@ mod app_builder {
                 clean: builder.clean,
                 http_api_definitions: builder.http_api_definitions,
                 http_api_deployments: builder.http_api_deployments,
+                metadata: builder.metadata,
             })
         }
 
@@ -1564,6 +1841,29 @@ mod app_builder {
                             WithSource::new(app_source_dir.to_path_buf(), app.application.wit_deps);
                     }
 
+                    if !app.application.remote_wit_deps.is_empty()
+                        && self.add_entity_source(
+                            UniqueSourceCheckedEntityKey::RemoteWitDeps,
+                            &app.source,
+                        )
+                    {
+                        self.remote_wit_deps = WithSource::new(
+                            app_source_dir.to_path_buf(),
+                            Self::resolve_remote_wit_deps(
+                                validation,
+                                app.application.remote_wit_deps,
+                            ),
+                        );
+                    }
+
+                    if let Some(metadata) = app.application.metadata {
+                        if self
+                            .add_entity_source(UniqueSourceCheckedEntityKey::Metadata, &app.source)
+                        {
+                            self.metadata = metadata;
+                        }
+                    }
+
                     for (template_name, template) in app.application.templates {
                         self.add_and_resolve_raw_template(
                             validation,
@@ -1830,6 +2130,14 @@ mod app_builder {
                     let dep_type = DependencyType::from_str(&dependency.type_);
                     if let Ok(dep_type) = dep_type {
                         let binary_component_source = match (dependency.target, dependency.path, dependency.url) {
+                            (_, _, None) if dependency.hash.is_some() => {
+                                validation.add_error(format!(
+                                    "The {} field can only be used together with the {} field for component dependency",
+                                    "hash".log_color_error_highlight(),
+                                    "url".log_color_error_highlight()
+                                ));
+                                None
+                            }
                             (Some(target_name), None, None) => {
                                 Some(BinaryComponentSource::AppComponent {
                                     name: target_name.into(),
@@ -1839,14 +2147,34 @@ mod app_builder {
                                 Some(BinaryComponentSource::LocalFile { path: Path::new(&path).to_path_buf() })
                             }
                             (None, None, Some(url)) => {
-                                match Url::from_str(&url) {
-                                    Ok(url) => {
-                                        Some(BinaryComponentSource::Url { url })
+                                match BinaryComponentSource::from_url(&url) {
+                                    Ok(BinaryComponentSource::Url { url, .. }) if dependency.hash.is_some() => {
+                                        match ContentHash::from_str(dependency.hash.as_deref().unwrap_or_default()) {
+                                            Ok(hash) => Some(BinaryComponentSource::Url { url, hash: Some(hash) }),
+                                            Err(err) => {
+                                                validation.add_error(format!(
+                                                    "Invalid hash for component dependency: {}, {}",
+                                                    url.as_str().log_color_highlight(),
+                                                    err
+                                                ));
+                                                None
+                                            }
+                                        }
                                     }
-                                    Err(_) => {
+                                    Ok(source) if dependency.hash.is_some() => {
                                         validation.add_error(format!(
-                                            "Invalid URL for component dependency: {}",
-                                            url.log_color_highlight()
+                                            "The {} field is not supported for component dependency {}, use a digest reference instead",
+                                            "hash".log_color_error_highlight(),
+                                            source.to_string().log_color_highlight()
+                                        ));
+                                        None
+                                    }
+                                    Ok(source) => Some(source),
+                                    Err(err) => {
+                                        validation.add_error(format!(
+                                            "Invalid URL for component dependency: {}, {}",
+                                            url.log_color_highlight(),
+                                            err
                                         ));
                                         None
                                     }
@@ -1872,10 +2200,27 @@ mod app_builder {
                             }
                         };
 
+                        let plug_selection = PlugSelection {
+                            interfaces: dependency.interfaces.into_iter().collect(),
+                            mappings: dependency.mappings,
+                        };
+                        if !plug_selection.is_default()
+                            && dep_type == DependencyType::DynamicWasmRpc
+                        {
+                            validation.add_error(format!(
+                                "The {}/{} fields can only be used for {}/{} component dependencies",
+                                "interfaces".log_color_error_highlight(),
+                                "mappings".log_color_error_highlight(),
+                                DependencyType::WASM.log_color_highlight(),
+                                DependencyType::STATIC_WASM_RPC.log_color_highlight()
+                            ));
+                        }
+
                         if let Some(binary_component_source) = binary_component_source {
                             let dependent_component = DependentComponent {
                                 source: binary_component_source,
                                 dep_type,
+                                plug_selection,
                             };
 
                             let unique_key = UniqueSourceCheckedEntityKey::Dependency((
@@ -1899,6 +2244,35 @@ mod app_builder {
             });
         }
 
+        fn resolve_remote_wit_deps(
+            validation: &mut ValidationBuilder,
+            remote_wit_deps: Vec<app_raw::RemoteWitDep>,
+        ) -> Vec<RemoteWitDep> {
+            let mut resolved = Vec::<RemoteWitDep>::new();
+            for remote_wit_dep in remote_wit_deps {
+                let package = remote_wit_dep.package.clone();
+                validation.with_context(vec![("remote WIT dependency", package)], |validation| {
+                    match RemoteWitDep::from_raw(remote_wit_dep) {
+                        Ok(dep) => {
+                            if resolved.iter().any(|other| {
+                                other.package.namespace == dep.package.namespace
+                                    && other.package.name == dep.package.name
+                            }) {
+                                validation.add_error(format!(
+                                    "Package {} is defined multiple times",
+                                    dep.package.to_string().log_color_error_highlight()
+                                ));
+                            } else {
+                                resolved.push(dep);
+                            }
+                        }
+                        Err(err) => validation.add_error(err),
+                    }
+                });
+            }
+            resolved
+        }
+
         fn validate_unique_sources(&mut self, validation: &mut ValidationBuilder) {
             let entity_sources = std::mem::take(&mut self.entity_sources);
             entity_sources
@@ -1929,6 +2303,7 @@ mod app_builder {
                             !std::fs::exists(path).unwrap_or(false)
                         }
                         BinaryComponentSource::Url { .. } => false,
+                        BinaryComponentSource::Oci { .. } => false,
                     };
                     let invalid_target_source = match (&target.dep_type, &target.source) {
                         (
@@ -2409,6 +2784,19 @@ mod app_builder {
                     ));
                 }
             }
+
+            for command in &properties.build {
+                if let app_raw::BuildCommand::ComposeWac(command) = command {
+                    if command.compose_wac.is_some() == command.compose_wac_inline.is_some() {
+                        validation.add_error(format!(
+                            "Exactly one of the {}/{} fields must be specified for WAC composition {}",
+                            "composeWac".log_color_error_highlight(),
+                            "composeWacInline".log_color_error_highlight(),
+                            command.to.log_color_highlight()
+                        ));
+                    }
+                }
+            }
         }
 
         fn validate_http_api_definitions(&self, validation: &mut ValidationBuilder) {
@@ -2729,7 +3117,10 @@ mod app_builder {
 
 #[cfg(test)]
 mod test {
-    use crate::model::app::{AppComponentName, Application, BuildProfileName};
+    use crate::model::app::{
+        AppComponentName, Application, BuildProfileName, InitialComponentFileSource,
+        RemoteWitDepSource,
+    };
     use crate::model::app_raw;
     use crate::model::component::AppComponentType;
     use assert2::{assert, check};
@@ -2824,4 +3215,223 @@ mod test {
         check!(release_props.component_wasm == "comp-a-component-wasm");
         check!(release_custom_props.component_wasm == "release-comp-a-component-wasm");
     }
+
+    #[test]
+    fn remote_wit_deps() {
+        let manifest = indoc! {"
+            remoteWitDeps:
+            - package: wasi:http@0.2.3
+            - package: example:api
+              git: https://github.com/example/api.git
+              rev: v1.0.0
+        "};
+
+        let app = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source/golem.yaml".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        );
+
+        let (app, _warns, errors) = app.into_product();
+        assert!(errors.is_empty(), "\n{}", errors.join("\n\n"));
+        let app = app.unwrap();
+
+        let deps = app.remote_wit_deps();
+        check!(deps.len() == 2);
+        check!(deps[0].package.to_string() == "wasi:http@0.2.3");
+        check!(deps[0].source.to_string() == "oci://ghcr.io/webassembly/wasi/http:0.2.3");
+        check!(deps[1].package.version.is_none());
+        check!(
+            deps[1].source
+                == RemoteWitDepSource::Git {
+                    url: "https://github.com/example/api.git".to_string(),
+                    rev: Some("v1.0.0".to_string()),
+                    path: "wit".into(),
+                }
+        );
+        check!(app.wit_deps() == vec![std::path::PathBuf::from("dummy-source/wit/deps")]);
+    }
+
+    #[test]
+    fn remote_wit_deps_invalid() {
+        let manifest = indoc! {"
+            remoteWitDeps:
+            - package: example:api
+            - package: wasi:http
+            - package: wasi:io@0.2.3
+              registry: ghcr.io/webassembly
+              git: https://github.com/example/api.git
+        "};
+
+        let app = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source/golem.yaml".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        );
+
+        let (_app, _warns, errors) = app.into_product();
+        check!(errors.len() == 3, "\n{}", errors.join("\n\n"));
+    }
+
+    #[test]
+    fn dependency_plug_selection() {
+        let manifest = indoc! {"
+            components:
+              app:main: {}
+              app:lib-a:
+                componentType: library
+              app:lib-b:
+                componentType: library
+            dependencies:
+              app:main:
+              - type: wasm
+                target: app:lib-a
+                interfaces:
+                - test:lib/api
+              - type: wasm
+                target: app:lib-b
+                mappings:
+                  test:lib/logging@0.1.0: test:lib-b/logging@0.1.0
+        "};
+
+        let (app, _warns, errors) = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        )
+        .into_product();
+        assert!(errors.is_empty(), "\n{}", errors.join("\n\n"));
+        let app = app.unwrap();
+
+        let deps = app
+            .component_dependencies(&AppComponentName::from("app:main"))
+            .iter()
+            .collect::<Vec<_>>();
+        check!(deps.len() == 2);
+        check!(deps[0].dep_type == DependencyType::Wasm);
+        check!(deps[0].plug_selection.interfaces.contains("test:lib/api"));
+        check!(deps[0].plug_selection.mappings.is_empty());
+        check!(deps[1].plug_selection.interfaces.is_empty());
+        check!(
+            deps[1]
+                .plug_selection
+                .mappings
+                .get("test:lib/logging@0.1.0")
+                == Some(&"test:lib-b/logging@0.1.0".to_string())
+        );
+
+        check!(PlugSelection::interface_matches(
+            "test:lib/api",
+            "test:lib/api@0.1.0"
+        ));
+        check!(!PlugSelection::interface_matches(
+            "test:lib/api",
+            "test:lib/api2@0.1.0"
+        ));
+    }
+
+    #[test]
+    fn dependency_plug_selection_for_dynamic_rpc() {
+        let manifest = indoc! {"
+            components:
+              app:main: {}
+              app:other: {}
+            dependencies:
+              app:main:
+              - type: wasm-rpc
+                target: app:other
+                interfaces:
+                - test:other/api
+        "};
+
+        let (_app, _warns, errors) = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        )
+        .into_product();
+        check!(errors.len() == 1, "\n{}", errors.join("\n\n"));
+    }
+
+    #[test]
+    fn initial_component_file_source_oci_reference() {
+        let source = InitialComponentFileSource::new(
+            "oci://ghcr.io/golemcloud/assets:1.0.0",
+            std::path::Path::new("."),
+        )
+        .unwrap();
+        assert!(!source.is_remote());
+        let reference = source.oci_reference().unwrap();
+        // NOTE: the lock file key of the file has to match the key used by 'app deps update'
+        check!(reference.to_string() == source.as_url().as_str());
+        check!(reference.tag.as_deref() == Some("1.0.0"));
+
+        let source = InitialComponentFileSource::new(
+            "https://example.com/assets.txt",
+            std::path::Path::new("."),
+        )
+        .unwrap();
+        check!(source.is_remote());
+        check!(source.oci_reference().is_none());
+    }
+
+    #[test]
+    fn compose_wac_requires_exactly_one_source() {
+        let manifest = indoc! {"
+            components:
+              app:both:
+                sourceWit: wit
+                generatedWit: wit-generated
+                componentWasm: component.wasm
+                build:
+                - composeWac: composition.wac
+                  composeWacInline: package app:composed;
+                  to: composed.wasm
+              app:none:
+                sourceWit: wit
+                generatedWit: wit-generated
+                componentWasm: component.wasm
+                build:
+                - to: composed.wasm
+                  packages: {}
+              app:file:
+                sourceWit: wit
+                generatedWit: wit-generated
+                componentWasm: component.wasm
+                build:
+                - composeWac: composition.wac
+                  to: composed.wasm
+        "};
+
+        let (_app, _warns, errors) = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        )
+        .into_product();
+
+        let errors = errors.join("\n");
+        check!(errors.contains("app:both"), "{errors}");
+        check!(errors.contains("app:none"), "{errors}");
+        check!(!errors.contains("app:file"), "{errors}");
+        check!(
+            errors.matches("Exactly one of the").count() == 2,
+            "{errors}"
+        );
+    }
 }
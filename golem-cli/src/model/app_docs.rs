@@ -0,0 +1,1185 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::app::{AppComponentName, Application};
+use crate::model::app_raw::HttpApiDefinitionBindingType;
+use crate::model::component::AppComponentType;
+use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
+use golem_common::model::agent::{AgentType, DataSchema, ElementSchema, NamedElementSchemas};
+use golem_wasm_ast::analysis::AnalysedType;
+use itertools::Itertools;
+use std::fmt::Write;
+use wit_parser::{
+    Docs, Function, Handle, InterfaceId, PackageId, Resolve, Type, TypeDefKind, TypeId, WorldItem,
+};
+
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppDocsFormat {
+    /// Static HTML site
+    #[default]
+    Html,
+    /// Markdown files
+    Markdown,
+}
+
+impl AppDocsFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AppDocsFormat::Html => "html",
+            AppDocsFormat::Markdown => "md",
+        }
+    }
+}
+
+/// HTTP API definition route bound to a component
+#[derive(Clone, Debug)]
+pub struct HttpApiRouteDocs {
+    pub api: String,
+    pub method: String,
+    pub path: String,
+    pub binding_type: String,
+    /// Rib response mapping of the route, used for finding the called functions
+    pub response: Option<String>,
+}
+
+impl HttpApiRouteDocs {
+    /// Returns the routes of all the HTTP API definitions bound to the component
+    pub fn for_component(app: &Application, component_name: &AppComponentName) -> Vec<Self> {
+        app.http_api_definitions()
+            .iter()
+            .flat_map(|(api_definition_name, api_definition)| {
+                api_definition
+                    .value
+                    .routes
+                    .iter()
+                    .filter(|route| {
+                        route.binding.component_name.as_deref() == Some(component_name.as_str())
+                    })
+                    .map(|route| Self {
+                        api: format!("{}@{}", api_definition_name, api_definition.value.version),
+                        method: route.method.to_uppercase(),
+                        path: route.path.clone(),
+                        binding_type: match route.binding.type_.unwrap_or_default() {
+                            HttpApiDefinitionBindingType::Default => "default",
+                            HttpApiDefinitionBindingType::CorsPreflight => "cors-preflight",
+                            HttpApiDefinitionBindingType::FileServer => "file-server",
+                            HttpApiDefinitionBindingType::HttpHandler => "http-handler",
+                        }
+                        .to_string(),
+                        response: route.binding.response.clone(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Returns true if the response mapping calls the function of the interface, either as a fully
+    /// qualified call (`api.{add-item}(..)`), or if `unqualified` is set, as a method call
+    /// (`worker.add-item(..)`)
+    pub fn calls_function(
+        &self,
+        interface_name: Option<&str>,
+        function_name: &str,
+        unqualified: bool,
+    ) -> bool {
+        let Some(response) = &self.response else {
+            return false;
+        };
+
+        response.match_indices(function_name).any(|(idx, _)| {
+            let before = &response[..idx];
+            let after = response[idx + function_name.len()..].chars().next();
+            if before
+                .chars()
+                .next_back()
+                .is_some_and(is_rib_identifier_char)
+                || !matches!(after, Some('(') | Some('}'))
+            {
+                return false;
+            }
+
+            match before.strip_suffix(".{") {
+                Some(before) => {
+                    let qualifier = before
+                        .rsplit(|c: char| !is_rib_qualifier_char(c))
+                        .next()
+                        .unwrap_or_default();
+                    interface_name.is_some_and(|interface_name| {
+                        interface_qualifier_matches(interface_name, qualifier)
+                    })
+                }
+                None => unqualified,
+            }
+        })
+    }
+}
+
+impl std::fmt::Display for HttpApiRouteDocs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} ({})", self.method, self.path, self.api)
+    }
+}
+
+fn is_rib_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '-' || c == '_'
+}
+
+fn is_rib_qualifier_char(c: char) -> bool {
+    is_rib_identifier_char(c) || matches!(c, ':' | '/' | '@' | '.')
+}
+
+/// Matches interface names with or without namespace and version, e.g. ns:pkg/api@1.0.0 is
+/// matched by ns:pkg/api@1.0.0, ns:pkg/api and api
+fn interface_qualifier_matches(interface_name: &str, qualifier: &str) -> bool {
+    let without_version = |name: &str| name.split_once('@').map_or(name, |(name, _)| name);
+    let interface_name = without_version(interface_name);
+    let qualifier = without_version(qualifier);
+
+    interface_name == qualifier
+        || interface_name
+            .rsplit_once('/')
+            .is_some_and(|(_, name)| name == qualifier)
+}
+
+#[derive(Clone, Debug)]
+pub struct ItemDocs {
+    pub name: String,
+    pub type_: Option<String>,
+    pub docs: Option<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct TypeDocs {
+    pub name: String,
+    pub kind: String,
+    /// Referenced type for aliases and other non-structured types
+    pub definition: Option<String>,
+    pub docs: Option<String>,
+    /// Fields, cases or flags
+    pub items: Vec<ItemDocs>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FunctionDocs {
+    pub name: String,
+    pub signature: String,
+    pub docs: Option<String>,
+    pub routes: Vec<String>,
+    item_name: String,
+}
+
+#[derive(Clone, Debug)]
+pub struct InterfaceDocs {
+    pub name: String,
+    pub docs: Option<String>,
+    pub types: Vec<TypeDocs>,
+    pub functions: Vec<FunctionDocs>,
+}
+
+impl InterfaceDocs {
+    pub fn new(resolve: &Resolve, name: String, interface_id: InterfaceId) -> Self {
+        let interface = &resolve.interfaces[interface_id];
+        Self {
+            name,
+            docs: docs(&interface.docs),
+            types: interface
+                .types
+                .iter()
+                .map(|(name, type_id)| type_docs(resolve, name, *type_id))
+                .collect(),
+            functions: interface
+                .functions
+                .values()
+                .map(|function| function_docs(resolve, function))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AgentFunctionDocs {
+    pub name: String,
+    pub signature: String,
+    pub description: String,
+    pub prompt_hint: Option<String>,
+    pub routes: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct AgentTypeDocs {
+    pub name: String,
+    pub description: String,
+    pub constructor: AgentFunctionDocs,
+    pub methods: Vec<AgentFunctionDocs>,
+}
+
+impl AgentTypeDocs {
+    pub fn new(agent_type: &AgentType) -> Self {
+        let constructor_name = agent_type
+            .constructor
+            .name
+            .clone()
+            .unwrap_or_else(|| agent_type.type_name.clone());
+        Self {
+            name: agent_type.type_name.clone(),
+            description: agent_type.description.clone(),
+            constructor: AgentFunctionDocs {
+                signature: format!(
+                    "{}{}",
+                    constructor_name,
+                    data_schema_ref(&agent_type.constructor.input_schema)
+                ),
+                name: constructor_name,
+                description: agent_type.constructor.description.clone(),
+                prompt_hint: agent_type.constructor.prompt_hint.clone(),
+                routes: vec![],
+            },
+            methods: agent_type
+                .methods
+                .iter()
+                .map(|method| AgentFunctionDocs {
+                    name: method.name.clone(),
+                    signature: format!(
+                        "{}{} -> {}",
+                        method.name,
+                        data_schema_ref(&method.input_schema),
+                        data_schema_ref(&method.output_schema)
+                    ),
+                    description: method.description.clone(),
+                    prompt_hint: method.prompt_hint.clone(),
+                    routes: vec![],
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Documentation of an application component, based on its resolved WIT
+#[derive(Clone, Debug)]
+pub struct ComponentDocs {
+    pub name: AppComponentName,
+    pub component_type: AppComponentType,
+    pub package: String,
+    pub exports: Vec<InterfaceDocs>,
+    pub agent_types: Vec<AgentTypeDocs>,
+    /// Interfaces of the generated wasm-rpc client of the component
+    pub client_interfaces: Vec<InterfaceDocs>,
+    pub routes: Vec<HttpApiRouteDocs>,
+}
+
+impl ComponentDocs {
+    pub fn new(
+        name: AppComponentName,
+        component_type: AppComponentType,
+        wit: &ResolvedWitDir,
+        client_package: Option<(&Resolve, PackageId)>,
+        agent_types: &[AgentType],
+        routes: Vec<HttpApiRouteDocs>,
+    ) -> anyhow::Result<Self> {
+        let main_package = wit.main_package()?;
+
+        let mut exports = Vec::<InterfaceDocs>::new();
+        for world_id in main_package.worlds.values() {
+            let world = &wit.resolve.worlds[*world_id];
+            let mut world_functions = Vec::new();
+            for (key, item) in &world.exports {
+                match item {
+                    WorldItem::Interface { id, .. } => {
+                        let name = wit.resolve.name_world_key(key);
+                        if !exports.iter().any(|export| export.name == name) {
+                            exports.push(InterfaceDocs::new(&wit.resolve, name, *id));
+                        }
+                    }
+                    WorldItem::Function(function) => {
+                        world_functions.push(function_docs(&wit.resolve, function))
+                    }
+                    WorldItem::Type(_) => {}
+                }
+            }
+            if !world_functions.is_empty() {
+                exports.push(InterfaceDocs {
+                    name: world.name.clone(),
+                    docs: docs(&world.docs),
+                    types: vec![],
+                    functions: world_functions,
+                });
+            }
+        }
+
+        let client_interfaces = client_package
+            .map(|(resolve, package_id)| {
+                resolve.packages[package_id]
+                    .interfaces
+                    .values()
+                    .map(|interface_id| {
+                        InterfaceDocs::new(
+                            resolve,
+                            resolve.id_of(*interface_id).unwrap_or_default(),
+                            *interface_id,
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let mut docs = Self {
+            name,
+            component_type,
+            package: main_package.name.to_string(),
+            exports,
+            agent_types: agent_types.iter().map(AgentTypeDocs::new).collect(),
+            client_interfaces,
+            routes,
+        };
+        docs.bind_routes();
+
+        Ok(docs)
+    }
+
+    fn bind_routes(&mut self) {
+        let routes = &self.routes;
+        let bound_routes = |interface_name: Option<&str>,
+                            function_name: &str,
+                            unqualified: bool| {
+            routes
+                .iter()
+                .filter(|route| route.calls_function(interface_name, function_name, unqualified))
+                .map(|route| route.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        // Unqualified calls are only bound to functions with unique names, as they are ambiguous
+        // otherwise
+        let function_name_counts = self
+            .exports
+            .iter()
+            .flat_map(|interface| interface.functions.iter())
+            .counts_by(|function| function.item_name.clone());
+
+        for interface in &mut self.exports {
+            for function in &mut interface.functions {
+                function.routes = bound_routes(
+                    Some(interface.name.as_str()),
+                    &function.item_name,
+                    function_name_counts[&function.item_name] == 1,
+                );
+            }
+        }
+        for agent_type in &mut self.agent_types {
+            agent_type.constructor.routes = bound_routes(None, &agent_type.constructor.name, true);
+            for method in &mut agent_type.methods {
+                method.routes = bound_routes(None, &method.name, true);
+            }
+        }
+    }
+
+    pub fn file_name(&self, format: AppDocsFormat) -> String {
+        format!(
+            "{}.{}",
+            self.name.as_str().replace([':', '/', '\\'], "-"),
+            format.extension()
+        )
+    }
+
+    pub fn render(&self, format: AppDocsFormat) -> String {
+        let mut out = DocsRenderer::new(format, self.name.as_str());
+
+        out.heading(1, self.name.as_str());
+        out.list(&[
+            format!("Component type: {}", self.component_type),
+            format!("WIT package: {}", out.inline_code(&self.package)),
+        ]);
+
+        if !self.agent_types.is_empty() {
+            out.heading(2, "Agent types");
+            for agent_type in &self.agent_types {
+                out.heading(3, &agent_type.name);
+                out.text(&agent_type.description);
+                out.heading(4, "Constructor");
+                out.agent_function(&agent_type.constructor);
+                if !agent_type.methods.is_empty() {
+                    out.heading(4, "Methods");
+                    for method in &agent_type.methods {
+                        out.agent_function(method);
+                    }
+                }
+            }
+        }
+
+        if !self.exports.is_empty() {
+            out.heading(2, "Exported interfaces");
+            for interface in &self.exports {
+                out.interface(interface);
+            }
+        }
+
+        if !self.client_interfaces.is_empty() {
+            out.heading(2, "WASM RPC client interfaces");
+            for interface in &self.client_interfaces {
+                out.interface(interface);
+            }
+        }
+
+        if !self.routes.is_empty() {
+            out.heading(2, "HTTP API routes");
+            let items = self
+                .routes
+                .iter()
+                .map(|route| {
+                    format!(
+                        "{} {} ({}, binding: {})",
+                        out.inline_code(&route.method),
+                        out.inline_code(&route.path),
+                        out.escape(&route.api),
+                        out.escape(&route.binding_type)
+                    )
+                })
+                .collect::<Vec<_>>();
+            out.list(&items);
+        }
+
+        out.finish()
+    }
+}
+
+/// Renders the index page of the generated docs
+pub fn render_docs_index(
+    format: AppDocsFormat,
+    title: &str,
+    components: &[ComponentDocs],
+) -> String {
+    let mut out = DocsRenderer::new(format, title);
+
+    out.heading(1, title);
+    let items = components
+        .iter()
+        .map(|component| {
+            format!(
+                "{} ({}, {} exported interface(s), {} agent type(s), {} HTTP API route(s))",
+                out.link(component.name.as_str(), &component.file_name(format)),
+                component.component_type,
+                component.exports.len(),
+                component.agent_types.len(),
+                component.routes.len()
+            )
+        })
+        .collect::<Vec<_>>();
+    out.list(&items);
+
+    out.finish()
+}
+
+struct DocsRenderer {
+    format: AppDocsFormat,
+    out: String,
+}
+
+impl DocsRenderer {
+    fn new(format: AppDocsFormat, title: &str) -> Self {
+        let mut renderer = Self {
+            format,
+            out: String::new(),
+        };
+        if format == AppDocsFormat::Html {
+            let _ = writeln!(
+                renderer.out,
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n\
+                 body {{ font-family: sans-serif; max-width: 960px; margin: 2em auto; padding: 0 1em; }}\n\
+                 pre {{ background: #f4f4f4; padding: 0.5em; overflow-x: auto; }}\n\
+                 .docs {{ white-space: pre-wrap; }}\n\
+                 </style>\n</head>\n<body>",
+                renderer.escape(title)
+            );
+        }
+        renderer
+    }
+
+    fn finish(mut self) -> String {
+        if self.format == AppDocsFormat::Html {
+            self.out.push_str("</body>\n</html>\n");
+        }
+        self.out
+    }
+
+    fn escape(&self, text: &str) -> String {
+        match self.format {
+            AppDocsFormat::Html => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;"),
+            AppDocsFormat::Markdown => {
+                let mut escaped = String::with_capacity(text.len());
+                for c in text.chars() {
+                    if matches!(
+                        c,
+                        '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '!'
+                    ) {
+                        escaped.push('\\');
+                    }
+                    escaped.push(c);
+                }
+                escaped
+            }
+        }
+    }
+
+    fn inline_code(&self, code: &str) -> String {
+        match self.format {
+            AppDocsFormat::Html => format!("<code>{}</code>", self.escape(code)),
+            AppDocsFormat::Markdown => {
+                // Code spans cannot contain escapes, so the delimiter has to be longer than any
+                // backtick run in the code
+                let delimiter = "`".repeat(longest_backtick_run(code) + 1);
+                if code.starts_with('`') || code.ends_with('`') {
+                    format!("{delimiter} {code} {delimiter}")
+                } else {
+                    format!("{delimiter}{code}{delimiter}")
+                }
+            }
+        }
+    }
+
+    fn link(&self, text: &str, target: &str) -> String {
+        match self.format {
+            AppDocsFormat::Html => format!(
+                "<a href=\"{}\">{}</a>",
+                self.escape(target),
+                self.escape(text)
+            ),
+            AppDocsFormat::Markdown => {
+                format!(
+                    "[{}](<{}>)",
+                    self.escape(text),
+                    target.replace(['<', '>'], "")
+                )
+            }
+        }
+    }
+
+    fn heading(&mut self, level: usize, text: &str) {
+        let _ = match self.format {
+            AppDocsFormat::Html => writeln!(self.out, "<h{level}>{}</h{level}>", self.escape(text)),
+            AppDocsFormat::Markdown => writeln!(
+                self.out,
+                "{} {}\n",
+                "#".repeat(level),
+                self.escape(&text.replace('\n', " "))
+            ),
+        };
+    }
+
+    fn text(&mut self, text: &str) {
+        if text.trim().is_empty() {
+            return;
+        }
+        let _ = match self.format {
+            AppDocsFormat::Html => {
+                writeln!(
+                    self.out,
+                    "<p class=\"docs\">{}</p>",
+                    self.escape(text.trim())
+                )
+            }
+            AppDocsFormat::Markdown => writeln!(self.out, "{}\n", self.escape(text.trim())),
+        };
+    }
+
+    fn code_block(&mut self, code: &str) {
+        let _ = match self.format {
+            AppDocsFormat::Html => {
+                writeln!(self.out, "<pre><code>{}</code></pre>", self.escape(code))
+            }
+            AppDocsFormat::Markdown => {
+                let fence = "`".repeat((longest_backtick_run(code) + 1).max(3));
+                writeln!(self.out, "{fence}wit\n{code}\n{fence}\n")
+            }
+        };
+    }
+
+    /// Renders a list, the items are expected to be already escaped
+    fn list(&mut self, items: &[String]) {
+        if items.is_empty() {
+            return;
+        }
+        let _ = match self.format {
+            AppDocsFormat::Html => writeln!(
+                self.out,
+                "<ul>\n{}\n</ul>",
+                items
+                    .iter()
+                    .map(|item| format!("<li>{item}</li>"))
+                    .join("\n")
+            ),
+            AppDocsFormat::Markdown => writeln!(
+                self.out,
+                "{}\n",
+                items.iter().map(|item| format!("- {item}")).join("\n")
+            ),
+        };
+    }
+
+    fn routes(&mut self, routes: &[String]) {
+        if routes.is_empty() {
+            return;
+        }
+        let items = routes
+            .iter()
+            .map(|route| format!("HTTP route: {}", self.inline_code(route)))
+            .collect::<Vec<_>>();
+        self.list(&items);
+    }
+
+    fn interface(&mut self, interface: &InterfaceDocs) {
+        self.heading(3, &interface.name);
+        if let Some(docs) = &interface.docs {
+            self.text(docs);
+        }
+
+        if !interface.types.is_empty() {
+            self.heading(4, "Types");
+            for type_docs in &interface.types {
+                self.heading(5, &format!("{} {}", type_docs.kind, type_docs.name));
+                if let Some(definition) = &type_docs.definition {
+                    self.code_block(&format!("type {} = {}", type_docs.name, definition));
+                }
+                if let Some(docs) = &type_docs.docs {
+                    self.text(docs);
+                }
+                let items = type_docs
+                    .items
+                    .iter()
+                    .map(|item| {
+                        let mut line = match &item.type_ {
+                            Some(type_) => self.inline_code(&format!("{}: {}", item.name, type_)),
+                            None => self.inline_code(&item.name),
+                        };
+                        if let Some(docs) = &item.docs {
+                            line.push_str(" - ");
+                            line.push_str(&self.escape(docs.trim()));
+                        }
+                        line
+                    })
+                    .collect::<Vec<_>>();
+                self.list(&items);
+            }
+        }
+
+        if !interface.functions.is_empty() {
+            self.heading(4, "Functions");
+            for function in &interface.functions {
+                self.heading(5, &function.name);
+                self.code_block(&function.signature);
+                if let Some(docs) = &function.docs {
+                    self.text(docs);
+                }
+                self.routes(&function.routes);
+            }
+        }
+    }
+
+    fn agent_function(&mut self, function: &AgentFunctionDocs) {
+        self.code_block(&function.signature);
+        self.text(&function.description);
+        if let Some(prompt_hint) = &function.prompt_hint {
+            let prompt_hint = format!("Prompt hint: {}", self.escape(prompt_hint));
+            self.list(&[prompt_hint]);
+        }
+        self.routes(&function.routes);
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`')
+        .map(|run| run.len())
+        .max()
+        .unwrap_or_default()
+}
+
+fn docs(docs: &Docs) -> Option<String> {
+    docs.contents
+        .as_ref()
+        .map(|contents| contents.trim().to_string())
+        .filter(|contents| !contents.is_empty())
+}
+
+fn function_docs(resolve: &Resolve, function: &Function) -> FunctionDocs {
+    // Resource functions are named like "[method]cart.add-item" or "[constructor]cart"
+    let (is_constructor, name) = match function.name.split_once(']') {
+        Some((kind, name)) if kind.ends_with("constructor") => {
+            (true, format!("{name}.constructor"))
+        }
+        Some((_, name)) => (false, name.to_string()),
+        None => (false, function.name.clone()),
+    };
+
+    let params = function
+        .params
+        .iter()
+        .filter(|(name, _)| name != "self")
+        .map(|(name, typ)| format!("{}: {}", name, type_ref(resolve, typ)))
+        .join(", ");
+
+    let signature = if is_constructor {
+        format!("constructor({params})")
+    } else {
+        match &function.result {
+            Some(result) => format!(
+                "{}: func({}) -> {}",
+                function.item_name(),
+                params,
+                type_ref(resolve, result)
+            ),
+            None => format!("{}: func({})", function.item_name(), params),
+        }
+    };
+
+    FunctionDocs {
+        name,
+        signature,
+        docs: docs(&function.docs),
+        routes: vec![],
+        item_name: function.item_name().to_string(),
+    }
+}
+
+fn type_docs(resolve: &Resolve, name: &str, type_id: TypeId) -> TypeDocs {
+    let type_def = &resolve.types[type_id];
+
+    let (definition, items) = match &type_def.kind {
+        TypeDefKind::Record(record) => (
+            None,
+            record
+                .fields
+                .iter()
+                .map(|field| ItemDocs {
+                    name: field.name.clone(),
+                    type_: Some(type_ref(resolve, &field.ty)),
+                    docs: docs(&field.docs),
+                })
+                .collect(),
+        ),
+        TypeDefKind::Variant(variant) => (
+            None,
+            variant
+                .cases
+                .iter()
+                .map(|case| ItemDocs {
+                    name: case.name.clone(),
+                    type_: case.ty.as_ref().map(|ty| type_ref(resolve, ty)),
+                    docs: docs(&case.docs),
+                })
+                .collect(),
+        ),
+        TypeDefKind::Enum(enum_) => (
+            None,
+            enum_
+                .cases
+                .iter()
+                .map(|case| ItemDocs {
+                    name: case.name.clone(),
+                    type_: None,
+                    docs: docs(&case.docs),
+                })
+                .collect(),
+        ),
+        TypeDefKind::Flags(flags) => (
+            None,
+            flags
+                .flags
+                .iter()
+                .map(|flag| ItemDocs {
+                    name: flag.name.clone(),
+                    type_: None,
+                    docs: docs(&flag.docs),
+                })
+                .collect(),
+        ),
+        TypeDefKind::Resource => (None, vec![]),
+        TypeDefKind::Type(typ) => (Some(type_ref(resolve, typ)), vec![]),
+        _ => (Some(anonymous_type_ref(resolve, type_id)), vec![]),
+    };
+
+    TypeDocs {
+        name: name.to_string(),
+        kind: type_def.kind.as_str().to_string(),
+        definition,
+        docs: docs(&type_def.docs),
+        items,
+    }
+}
+
+fn type_ref(resolve: &Resolve, typ: &Type) -> String {
+    match typ {
+        Type::Bool => "bool".to_string(),
+        Type::U8 => "u8".to_string(),
+        Type::U16 => "u16".to_string(),
+        Type::U32 => "u32".to_string(),
+        Type::U64 => "u64".to_string(),
+        Type::S8 => "s8".to_string(),
+        Type::S16 => "s16".to_string(),
+        Type::S32 => "s32".to_string(),
+        Type::S64 => "s64".to_string(),
+        Type::F32 => "f32".to_string(),
+        Type::F64 => "f64".to_string(),
+        Type::Char => "char".to_string(),
+        Type::String => "string".to_string(),
+        Type::ErrorContext => "error-context".to_string(),
+        Type::Id(type_id) => match &resolve.types[*type_id].name {
+            Some(name) => name.clone(),
+            None => anonymous_type_ref(resolve, *type_id),
+        },
+    }
+}
+
+fn anonymous_type_ref(resolve: &Resolve, type_id: TypeId) -> String {
+    let optional_type_ref = |typ: &Option<Type>| {
+        typ.as_ref()
+            .map(|typ| type_ref(resolve, typ))
+            .unwrap_or_else(|| "_".to_string())
+    };
+
+    match &resolve.types[type_id].kind {
+        TypeDefKind::Option(inner) => format!("option<{}>", type_ref(resolve, inner)),
+        TypeDefKind::List(inner) => format!("list<{}>", type_ref(resolve, inner)),
+        TypeDefKind::Tuple(tuple) => format!(
+            "tuple<{}>",
+            tuple
+                .types
+                .iter()
+                .map(|typ| type_ref(resolve, typ))
+                .join(", ")
+        ),
+        TypeDefKind::Result(result) => match (&result.ok, &result.err) {
+            (None, None) => "result".to_string(),
+            (ok, None) => format!("result<{}>", optional_type_ref(ok)),
+            (ok, err) => format!(
+                "result<{}, {}>",
+                optional_type_ref(ok),
+                optional_type_ref(err)
+            ),
+        },
+        TypeDefKind::Handle(Handle::Own(type_id)) => type_ref(resolve, &Type::Id(*type_id)),
+        TypeDefKind::Handle(Handle::Borrow(type_id)) => {
+            format!("borrow<{}>", type_ref(resolve, &Type::Id(*type_id)))
+        }
+        TypeDefKind::Future(inner) => match inner {
+            Some(inner) => format!("future<{}>", type_ref(resolve, inner)),
+            None => "future".to_string(),
+        },
+        TypeDefKind::Stream(inner) => match inner {
+            Some(inner) => format!("stream<{}>", type_ref(resolve, inner)),
+            None => "stream".to_string(),
+        },
+        TypeDefKind::Type(inner) => type_ref(resolve, inner),
+        kind => kind.as_str().to_string(),
+    }
+}
+
+fn data_schema_ref(schema: &DataSchema) -> String {
+    match schema {
+        DataSchema::Tuple(NamedElementSchemas { elements }) => format!(
+            "({})",
+            elements
+                .iter()
+                .map(|element| format!("{}: {}", element.name, element_schema_ref(&element.schema)))
+                .join(", ")
+        ),
+        DataSchema::Multimodal(NamedElementSchemas { elements }) => format!(
+            "multimodal({})",
+            elements
+                .iter()
+                .map(|element| format!("{}: {}", element.name, element_schema_ref(&element.schema)))
+                .join(" | ")
+        ),
+    }
+}
+
+fn element_schema_ref(schema: &ElementSchema) -> String {
+    match schema {
+        ElementSchema::ComponentModel(typ) => analysed_type_ref(typ),
+        ElementSchema::UnstructuredText(_) => "text".to_string(),
+        ElementSchema::UnstructuredBinary(_) => "binary".to_string(),
+    }
+}
+
+fn analysed_type_ref(typ: &AnalysedType) -> String {
+    if let Some(name) = typ.name() {
+        return name.to_string();
+    }
+
+    match typ {
+        AnalysedType::Variant(_) => "variant".to_string(),
+        AnalysedType::Enum(enum_) => format!("enum {{ {} }}", enum_.cases.join(", ")),
+        AnalysedType::Flags(flags) => format!("flags {{ {} }}", flags.names.join(", ")),
+        AnalysedType::Record(record) => format!(
+            "record {{ {} }}",
+            record
+                .fields
+                .iter()
+                .map(|field| format!("{}: {}", field.name, analysed_type_ref(&field.typ)))
+                .join(", ")
+        ),
+        AnalysedType::Result(result) => match (&result.ok, &result.err) {
+            (None, None) => "result".to_string(),
+            (Some(ok), None) => format!("result<{}>", analysed_type_ref(ok)),
+            (ok, Some(err)) => format!(
+                "result<{}, {}>",
+                ok.as_ref()
+                    .map(|ok| analysed_type_ref(ok))
+                    .unwrap_or_else(|| "_".to_string()),
+                analysed_type_ref(err)
+            ),
+        },
+        AnalysedType::Option(option) => format!("option<{}>", analysed_type_ref(&option.inner)),
+        AnalysedType::Tuple(tuple) => format!(
+            "tuple<{}>",
+            tuple.items.iter().map(analysed_type_ref).join(", ")
+        ),
+        AnalysedType::List(list) => format!("list<{}>", analysed_type_ref(&list.inner)),
+        AnalysedType::Str(_) => "string".to_string(),
+        AnalysedType::Chr(_) => "char".to_string(),
+        AnalysedType::F64(_) => "f64".to_string(),
+        AnalysedType::F32(_) => "f32".to_string(),
+        AnalysedType::U64(_) => "u64".to_string(),
+        AnalysedType::S64(_) => "s64".to_string(),
+        AnalysedType::U32(_) => "u32".to_string(),
+        AnalysedType::S32(_) => "s32".to_string(),
+        AnalysedType::U16(_) => "u16".to_string(),
+        AnalysedType::S16(_) => "s16".to_string(),
+        AnalysedType::U8(_) => "u8".to_string(),
+        AnalysedType::S8(_) => "s8".to_string(),
+        AnalysedType::Bool(_) => "bool".to_string(),
+        AnalysedType::Handle(_) => "handle".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::agent::test::multi_agent_wrapper_2_types;
+    use crate::model::app::AppComponentName;
+    use crate::model::app_docs::{AppDocsFormat, ComponentDocs, HttpApiRouteDocs};
+    use crate::model::component::AppComponentType;
+    use crate::wasm_rpc_stubgen::wit_resolve::ResolvedWitDir;
+    use assert2::check;
+    use indexmap::IndexMap;
+    use indoc::indoc;
+    use test_r::test;
+    use wit_parser::Resolve;
+
+    #[test]
+    fn component_docs() {
+        let mut resolve = Resolve::new();
+        let package_id = resolve
+            .push_str(
+                "main.wit",
+                indoc! {"
+                    package app:main;
+
+                    /// Shopping cart API
+                    interface api {
+                      /// Cart item
+                      record item {
+                        /// Product id
+                        product-id: string,
+                        quantity: u32,
+                      }
+
+                      /// Adds an item to the cart
+                      add-item: func(item: item) -> result<_, string>;
+                      get-items: func() -> list<item>;
+                    }
+
+                    world main {
+                      export api;
+                    }
+                "},
+            )
+            .unwrap();
+        let wit = ResolvedWitDir {
+            path: "wit".into(),
+            resolve,
+            package_id,
+            package_sources: IndexMap::new(),
+        };
+
+        let route = |path: &str, response: &str| HttpApiRouteDocs {
+            api: "cart@0.1.0".to_string(),
+            method: "POST".to_string(),
+            path: path.to_string(),
+            binding_type: "default".to_string(),
+            response: Some(response.to_string()),
+        };
+
+        let docs = ComponentDocs::new(
+            AppComponentName::from("app:main"),
+            AppComponentType::Durable,
+            &wit,
+            None,
+            &multi_agent_wrapper_2_types(),
+            vec![
+                route(
+                    "/cart",
+                    "let result = worker.add-item(request.body); {status: 200u64}",
+                ),
+                route("/items", "let items = api.{get-items}(); {status: 200u64}"),
+                route("/other", "let x = worker.add-item2(); {status: 200u64}"),
+            ],
+        )
+        .unwrap();
+
+        check!(docs.package == "app:main");
+        check!(docs.exports.len() == 1);
+        let api = &docs.exports[0];
+        check!(api.name == "app:main/api");
+        check!(api.docs.as_deref() == Some("Shopping cart API"));
+        check!(api.types.len() == 1);
+        check!(api.types[0].items.len() == 2);
+        check!(api.types[0].items[0].docs.as_deref() == Some("Product id"));
+        check!(api.functions[0].signature == "add-item: func(item: item) -> result<_, string>");
+        check!(api.functions[0].routes == vec!["POST /cart (cart@0.1.0)".to_string()]);
+        check!(api.functions[1].routes == vec!["POST /items (cart@0.1.0)".to_string()]);
+
+        check!(docs.agent_types.len() == 2);
+        check!(docs.agent_types[0].methods[0].signature == "f1() -> (return: location)");
+        check!(docs.file_name(AppDocsFormat::Markdown) == "app-main.md");
+
+        let markdown = docs.render(AppDocsFormat::Markdown);
+        check!(markdown.contains("### app:main/api"));
+        check!(markdown.contains("Adds an item to the cart"));
+
+        let html = docs.render(AppDocsFormat::Html);
+        check!(html.contains("<h3>app:main/api</h3>"));
+        check!(html.contains("result&lt;_, string&gt;"));
+    }
+
+    fn resolved_wit(source: &str) -> ResolvedWitDir {
+        let mut resolve = Resolve::new();
+        let package_id = resolve.push_str("main.wit", source).unwrap();
+        ResolvedWitDir {
+            path: "wit".into(),
+            resolve,
+            package_id,
+            package_sources: IndexMap::new(),
+        }
+    }
+
+    fn route(path: &str, response: &str) -> HttpApiRouteDocs {
+        HttpApiRouteDocs {
+            api: "cart@0.1.0".to_string(),
+            method: "GET".to_string(),
+            path: path.to_string(),
+            binding_type: "default".to_string(),
+            response: Some(response.to_string()),
+        }
+    }
+
+    #[test]
+    fn route_calls_function() {
+        let qualified = route("/a", "let x = app:main/api@0.1.0.{get-items}(); x");
+        check!(qualified.calls_function(Some("app:main/api@0.1.0"), "get-items", false));
+        check!(qualified.calls_function(Some("app:main/api"), "get-items", true));
+        check!(!qualified.calls_function(Some("app:main/other"), "get-items", true));
+        check!(!qualified.calls_function(None, "get-items", true));
+
+        let short = route("/b", "let x = api.{get-items}(); x");
+        check!(short.calls_function(Some("app:main/api"), "get-items", false));
+        check!(!short.calls_function(Some("app:main/other"), "get-items", true));
+
+        let method = route("/c", "let x = worker.get-items(); x");
+        check!(method.calls_function(Some("app:main/api"), "get-items", true));
+        check!(!method.calls_function(Some("app:main/api"), "get-items", false));
+        check!(!method.calls_function(Some("app:main/api"), "items", true));
+    }
+
+    #[test]
+    fn routes_are_bound_to_same_named_functions_by_interface() {
+        let wit = resolved_wit(indoc! {"
+            package app:main;
+
+            interface api {
+              get-items: func() -> list<string>;
+              add-item: func(item: string);
+            }
+
+            interface other {
+              get-items: func() -> list<string>;
+            }
+
+            world main {
+              export api;
+              export other;
+            }
+        "});
+
+        let docs = ComponentDocs::new(
+            AppComponentName::from("app:main"),
+            AppComponentType::Durable,
+            &wit,
+            None,
+            &[],
+            vec![
+                route("/api", "let items = api.{get-items}(); items"),
+                route("/other", "let items = app:main/other.{get-items}(); items"),
+                route("/ambiguous", "let items = worker.get-items(); items"),
+                route("/add", "let result = worker.add-item(\"x\"); result"),
+            ],
+        )
+        .unwrap();
+
+        let routes = |interface_idx: usize, function_idx: usize| {
+            docs.exports[interface_idx].functions[function_idx]
+                .routes
+                .clone()
+        };
+
+        check!(docs.exports[0].name == "app:main/api");
+        check!(routes(0, 0) == vec!["GET /api (cart@0.1.0)".to_string()]);
+        check!(routes(0, 1) == vec!["GET /add (cart@0.1.0)".to_string()]);
+        check!(docs.exports[1].name == "app:main/other");
+        check!(routes(1, 0) == vec!["GET /other (cart@0.1.0)".to_string()]);
+    }
+
+    #[test]
+    fn markdown_is_escaped() {
+        let wit = resolved_wit(indoc! {"
+            package app:main;
+
+            /// Returns *all* the items, see [docs] | <b>
+            interface api {
+              get-items: func() -> list<string>;
+            }
+
+            world main {
+              export api;
+            }
+        "});
+
+        let docs = ComponentDocs::new(
+            AppComponentName::from("app:main"),
+            AppComponentType::Durable,
+            &wit,
+            None,
+            &[],
+            vec![route("/items/{id}", "let items = api.{get-items}(); items")],
+        )
+        .unwrap();
+
+        let markdown = docs.render(AppDocsFormat::Markdown);
+        check!(markdown.contains(r"Returns \*all\* the items, see \[docs\] \| \<b\>"));
+        check!(markdown.contains("`/items/{id}`"));
+
+        let html = docs.render(AppDocsFormat::Html);
+        check!(html.contains("Returns *all* the items, see [docs] | &lt;b&gt;"));
+    }
+}
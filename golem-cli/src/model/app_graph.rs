@@ -0,0 +1,562 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::app::{Application, BinaryComponentSource, BuildProfileName};
+use crate::model::component::AppComponentType;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// Syntax of the graph for text output, structured output is selected by the global format
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AppGraphFormat {
+    /// Graphviz DOT
+    #[default]
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AppGraphNodeKind {
+    Component,
+    ExternalComponent,
+    HttpApiDefinition,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppGraphNode {
+    pub id: String,
+    pub kind: AppGraphNodeKind,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub component_type: Option<AppComponentType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppGraphEdge {
+    pub from: String,
+    pub to: String,
+    /// Dependency type, or "binding" for HTTP API definition routes
+    pub kind: String,
+}
+
+/// Dependency graph of the application components, external component dependencies and the
+/// HTTP API definitions binding the components
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppGraph {
+    pub nodes: Vec<AppGraphNode>,
+    pub edges: Vec<AppGraphEdge>,
+    /// Groups of components depending on each other in a cycle
+    pub cycles: Vec<Vec<String>>,
+    /// Library components which are not used by any deployable component or HTTP API definition
+    pub unreachable_libraries: Vec<String>,
+}
+
+pub const BINDING_EDGE_KIND: &str = "binding";
+
+impl AppGraph {
+    pub fn new(app: &Application, profile: Option<&BuildProfileName>) -> Self {
+        let mut nodes = BTreeMap::<String, AppGraphNode>::new();
+        let mut edges = BTreeSet::<AppGraphEdge>::new();
+
+        for component_name in app.component_names() {
+            nodes.insert(
+                component_name.to_string(),
+                AppGraphNode {
+                    id: component_name.to_string(),
+                    kind: AppGraphNodeKind::Component,
+                    component_type: Some(
+                        app.component_properties(component_name, profile)
+                            .component_type(),
+                    ),
+                },
+            );
+        }
+
+        for component_name in app.component_names() {
+            for dependency in app.component_dependencies(component_name) {
+                let target = dependency.source.to_string();
+                if !matches!(
+                    dependency.source,
+                    BinaryComponentSource::AppComponent { .. }
+                ) {
+                    nodes.entry(target.clone()).or_insert_with(|| AppGraphNode {
+                        id: target.clone(),
+                        kind: AppGraphNodeKind::ExternalComponent,
+                        component_type: None,
+                    });
+                }
+                edges.insert(AppGraphEdge {
+                    from: component_name.to_string(),
+                    to: target,
+                    kind: dependency.dep_type.as_str().to_string(),
+                });
+            }
+        }
+
+        for (api_definition_name, api_definition) in app.http_api_definitions() {
+            let id = format!("{}@{}", api_definition_name, api_definition.value.version);
+            nodes.insert(
+                id.clone(),
+                AppGraphNode {
+                    id: id.clone(),
+                    kind: AppGraphNodeKind::HttpApiDefinition,
+                    component_type: None,
+                },
+            );
+            for route in &api_definition.value.routes {
+                if let Some(component_name) = &route.binding.component_name {
+                    edges.insert(AppGraphEdge {
+                        from: id.clone(),
+                        to: component_name.clone(),
+                        kind: BINDING_EDGE_KIND.to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut graph = Self {
+            nodes: nodes.into_values().collect(),
+            edges: edges.into_iter().collect(),
+            cycles: vec![],
+            unreachable_libraries: vec![],
+        };
+        graph.cycles = graph.find_cycles();
+        graph.unreachable_libraries = graph.find_unreachable_libraries();
+        graph
+    }
+
+    fn component_ids(&self) -> BTreeSet<&str> {
+        self.nodes
+            .iter()
+            .filter(|node| node.kind == AppGraphNodeKind::Component)
+            .map(|node| node.id.as_str())
+            .collect()
+    }
+
+    fn successors<'a>(&'a self, id: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.from == id)
+            .map(|edge| edge.to.as_str())
+    }
+
+    /// Finds the strongly connected components which form a cycle, using Tarjan's algorithm
+    fn find_cycles(&self) -> Vec<Vec<String>> {
+        struct Tarjan<'a> {
+            graph: &'a AppGraph,
+            components: BTreeSet<&'a str>,
+            index: usize,
+            indices: BTreeMap<&'a str, usize>,
+            low_links: BTreeMap<&'a str, usize>,
+            stack: Vec<&'a str>,
+            on_stack: BTreeSet<&'a str>,
+            cycles: Vec<Vec<String>>,
+        }
+
+        impl<'a> Tarjan<'a> {
+            fn visit(&mut self, id: &'a str) {
+                self.indices.insert(id, self.index);
+                self.low_links.insert(id, self.index);
+                self.index += 1;
+                self.stack.push(id);
+                self.on_stack.insert(id);
+
+                let successors = self
+                    .graph
+                    .successors(id)
+                    .filter(|successor| self.components.contains(successor))
+                    .collect::<Vec<_>>();
+
+                for successor in &successors {
+                    if !self.indices.contains_key(successor) {
+                        self.visit(successor);
+                        let low_link = self.low_links[id].min(self.low_links[successor]);
+                        self.low_links.insert(id, low_link);
+                    } else if self.on_stack.contains(successor) {
+                        let low_link = self.low_links[id].min(self.indices[successor]);
+                        self.low_links.insert(id, low_link);
+                    }
+                }
+
+                if self.low_links[id] == self.indices[id] {
+                    let mut scc = Vec::new();
+                    while let Some(member) = self.stack.pop() {
+                        self.on_stack.remove(member);
+                        scc.push(member.to_string());
+                        if member == id {
+                            break;
+                        }
+                    }
+                    if scc.len() > 1 || successors.contains(&id) {
+                        scc.sort();
+                        self.cycles.push(scc);
+                    }
+                }
+            }
+        }
+
+        let components = self.component_ids();
+        let mut tarjan = Tarjan {
+            graph: self,
+            components: components.clone(),
+            index: 0,
+            indices: BTreeMap::new(),
+            low_links: BTreeMap::new(),
+            stack: Vec::new(),
+            on_stack: BTreeSet::new(),
+            cycles: Vec::new(),
+        };
+
+        for id in components {
+            if !tarjan.indices.contains_key(id) {
+                tarjan.visit(id);
+            }
+        }
+
+        let mut cycles = tarjan.cycles;
+        cycles.sort();
+        cycles
+    }
+
+    fn find_unreachable_libraries(&self) -> Vec<String> {
+        let mut visited = BTreeSet::<&str>::new();
+        let mut queue = self
+            .nodes
+            .iter()
+            .filter(|node| match node.kind {
+                AppGraphNodeKind::Component => {
+                    node.component_type != Some(AppComponentType::Library)
+                }
+                AppGraphNodeKind::ExternalComponent => false,
+                AppGraphNodeKind::HttpApiDefinition => true,
+            })
+            .map(|node| node.id.as_str())
+            .collect::<Vec<_>>();
+
+        while let Some(id) = queue.pop() {
+            if visited.insert(id) {
+                queue.extend(self.successors(id));
+            }
+        }
+
+        self.nodes
+            .iter()
+            .filter(|node| {
+                node.component_type == Some(AppComponentType::Library)
+                    && !visited.contains(node.id.as_str())
+            })
+            .map(|node| node.id.clone())
+            .collect()
+    }
+
+    pub fn to_dot(&self) -> String {
+        fn quote(value: &str) -> String {
+            format!(
+                "\"{}\"",
+                value
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+            )
+        }
+
+        let mut dot = String::new();
+        writeln!(dot, "digraph application {{").unwrap();
+        writeln!(dot, "  rankdir=LR;").unwrap();
+        for node in &self.nodes {
+            let (label, attributes) = match node.kind {
+                AppGraphNodeKind::Component => {
+                    let component_type = node.component_type.unwrap_or_default();
+                    let style = match component_type {
+                        AppComponentType::Durable => "solid",
+                        AppComponentType::Ephemeral => "rounded",
+                        AppComponentType::Library => "dashed",
+                    };
+                    (
+                        format!(
+                            "{}\n({})",
+                            node.id,
+                            component_type.to_string().to_lowercase()
+                        ),
+                        format!("shape=box, style={style}"),
+                    )
+                }
+                AppGraphNodeKind::ExternalComponent => (node.id.clone(), "shape=note".to_string()),
+                AppGraphNodeKind::HttpApiDefinition => (
+                    format!("HTTP API\n{}", node.id),
+                    "shape=hexagon".to_string(),
+                ),
+            };
+            writeln!(
+                dot,
+                "  {} [label={}, {}];",
+                quote(&node.id),
+                quote(&label),
+                attributes
+            )
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let style = if edge.kind == BINDING_EDGE_KIND {
+                ", style=dotted"
+            } else {
+                ""
+            };
+            writeln!(
+                dot,
+                "  {} -> {} [label={}{}];",
+                quote(&edge.from),
+                quote(&edge.to),
+                quote(&edge.kind),
+                style
+            )
+            .unwrap();
+        }
+        writeln!(dot, "}}").unwrap();
+        dot
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        fn label(value: &str) -> String {
+            format!("\"{}\"", value.replace('"', "#quot;"))
+        }
+
+        let node_ids = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, node)| (node.id.as_str(), format!("n{idx}")))
+            .collect::<BTreeMap<_, _>>();
+        let node_id = |id: &str| {
+            node_ids.get(id).cloned().unwrap_or_else(|| {
+                format!(
+                    "missing_{}",
+                    id.replace(|c: char| !c.is_alphanumeric(), "_")
+                )
+            })
+        };
+
+        let mut mermaid = String::new();
+        writeln!(mermaid, "flowchart LR").unwrap();
+        for node in &self.nodes {
+            let id = node_id(&node.id);
+            match node.kind {
+                AppGraphNodeKind::Component => {
+                    let component_type = node.component_type.unwrap_or_default();
+                    let text = label(&format!(
+                        "{}<br/>{}",
+                        node.id,
+                        component_type.to_string().to_lowercase()
+                    ));
+                    match component_type {
+                        AppComponentType::Durable => writeln!(mermaid, "  {id}[{text}]"),
+                        AppComponentType::Ephemeral => writeln!(mermaid, "  {id}({text})"),
+                        AppComponentType::Library => writeln!(mermaid, "  {id}[/{text}/]"),
+                    }
+                }
+                AppGraphNodeKind::ExternalComponent => {
+                    writeln!(mermaid, "  {id}[[{}]]", label(&node.id))
+                }
+                AppGraphNodeKind::HttpApiDefinition => writeln!(
+                    mermaid,
+                    "  {id}{{{{{}}}}}",
+                    label(&format!("HTTP API<br/>{}", node.id))
+                ),
+            }
+            .unwrap();
+        }
+        for edge in &self.edges {
+            let arrow = if edge.kind == BINDING_EDGE_KIND {
+                "-.->"
+            } else {
+                "-->"
+            };
+            writeln!(
+                mermaid,
+                "  {} {}|{}| {}",
+                node_id(&edge.from),
+                arrow,
+                edge.kind,
+                node_id(&edge.to)
+            )
+            .unwrap();
+        }
+        mermaid
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::app::Application;
+    use crate::model::app_graph::{
+        AppGraph, AppGraphEdge, AppGraphNode, AppGraphNodeKind, BINDING_EDGE_KIND,
+    };
+    use crate::model::app_raw;
+    use crate::model::component::AppComponentType;
+    use assert2::check;
+    use indoc::indoc;
+    use test_r::test;
+
+    #[test]
+    fn cycles_and_unreachable_libraries() {
+        let manifest = indoc! {"
+            components:
+              app:a:
+                componentType: durable
+              app:b:
+                componentType: ephemeral
+              app:c:
+                componentType: durable
+              app:lib-used:
+                componentType: library
+              app:lib-unused:
+                componentType: library
+            dependencies:
+              app:a:
+              - type: wasm-rpc
+                target: app:b
+              - type: wasm
+                target: app:lib-used
+              app:b:
+              - type: wasm-rpc
+                target: app:a
+              app:c:
+              - type: wasm-rpc
+                target: app:c
+        "};
+
+        let (app, _warns, errors) = Application::from_raw_apps(
+            &Default::default(),
+            vec![app_raw::ApplicationWithSource::from_yaml_string(
+                "dummy-source".into(),
+                manifest.to_string(),
+            )
+            .unwrap()],
+        )
+        .into_product();
+        check!(errors.is_empty(), "\n{}", errors.join("\n\n"));
+        let app = app.unwrap();
+
+        let graph = AppGraph::new(&app, None);
+
+        check!(graph.nodes.len() == 5);
+        check!(graph.edges.len() == 4);
+        check!(
+            graph.cycles
+                == vec![
+                    vec!["app:a".to_string(), "app:b".to_string()],
+                    vec!["app:c".to_string()]
+                ]
+        );
+        check!(graph.unreachable_libraries == vec!["app:lib-unused".to_string()]);
+
+        let dot = graph.to_dot();
+        check!(dot.contains("\"app:a\" -> \"app:lib-used\" [label=\"wasm\"];"));
+
+        let mermaid = graph.to_mermaid();
+        check!(mermaid.starts_with("flowchart LR\n"));
+        check!(mermaid.contains("-->|wasm-rpc|"));
+    }
+
+    fn rendered_graph() -> AppGraph {
+        AppGraph {
+            nodes: vec![
+                AppGraphNode {
+                    id: "app:a".to_string(),
+                    kind: AppGraphNodeKind::Component,
+                    component_type: Some(AppComponentType::Durable),
+                },
+                AppGraphNode {
+                    id: "app:lib".to_string(),
+                    kind: AppGraphNodeKind::Component,
+                    component_type: Some(AppComponentType::Library),
+                },
+                AppGraphNode {
+                    id: "https://example.com/\"quoted\".wasm".to_string(),
+                    kind: AppGraphNodeKind::ExternalComponent,
+                    component_type: None,
+                },
+                AppGraphNode {
+                    id: "api@1.0.0".to_string(),
+                    kind: AppGraphNodeKind::HttpApiDefinition,
+                    component_type: None,
+                },
+            ],
+            edges: vec![
+                AppGraphEdge {
+                    from: "app:a".to_string(),
+                    to: "app:lib".to_string(),
+                    kind: "wasm".to_string(),
+                },
+                AppGraphEdge {
+                    from: "app:a".to_string(),
+                    to: "https://example.com/\"quoted\".wasm".to_string(),
+                    kind: "wasm-rpc".to_string(),
+                },
+                AppGraphEdge {
+                    from: "api@1.0.0".to_string(),
+                    to: "app:a".to_string(),
+                    kind: BINDING_EDGE_KIND.to_string(),
+                },
+            ],
+            cycles: vec![],
+            unreachable_libraries: vec![],
+        }
+    }
+
+    #[test]
+    fn to_dot() {
+        let dot = rendered_graph().to_dot();
+
+        check!(dot.starts_with("digraph application {\n  rankdir=LR;\n"));
+        check!(dot.ends_with("}\n"));
+        check!(dot.contains("  \"app:a\" [label=\"app:a\\n(durable)\", shape=box, style=solid];\n"));
+        check!(dot
+            .contains("  \"app:lib\" [label=\"app:lib\\n(library)\", shape=box, style=dashed];\n"));
+        check!(dot.contains(
+            "  \"https://example.com/\\\"quoted\\\".wasm\" [label=\"https://example.com/\\\"quoted\\\".wasm\", shape=note];\n"
+        ));
+        check!(dot.contains("  \"api@1.0.0\" [label=\"HTTP API\\napi@1.0.0\", shape=hexagon];\n"));
+        check!(dot.contains("  \"api@1.0.0\" -> \"app:a\" [label=\"binding\", style=dotted];\n"));
+        // NOTE: labels must not contain raw newlines
+        check!(dot.lines().all(|line| line.is_empty()
+            || line.starts_with("  ")
+            || line.starts_with("digraph")
+            || line == "}"));
+    }
+
+    #[test]
+    fn to_mermaid() {
+        let mermaid = rendered_graph().to_mermaid();
+
+        check!(
+            mermaid
+                == indoc! {r#"
+                    flowchart LR
+                      n0["app:a<br/>durable"]
+                      n1[/"app:lib<br/>library"/]
+                      n2[["https://example.com/#quot;quoted#quot;.wasm"]]
+                      n3{{"HTTP API<br/>api@1.0.0"}}
+                      n0 -->|wasm| n1
+                      n0 -->|wasm-rpc| n2
+                      n3 -.->|binding| n0
+                "#}
+        );
+    }
+}
@@ -2,11 +2,12 @@ use crate::config::ProfileName;
 use crate::fs;
 use crate::log::LogColorize;
 use crate::model::component::AppComponentType;
+use crate::model::deploy::{WorkerRolloutFailureAction, WorkerRolloutStrategy};
 use crate::model::Format;
 use anyhow::{anyhow, Context};
 use golem_common::model::{ComponentFilePath, ComponentFilePermissions};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 use url::Url;
 
@@ -43,6 +44,8 @@ pub struct Application {
     pub temp_dir: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub wit_deps: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remote_wit_deps: Vec<RemoteWitDep>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub templates: HashMap<String, ComponentTemplate>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
@@ -57,6 +60,8 @@ pub struct Application {
     pub http_api: Option<HttpApi>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub profiles: HashMap<ProfileName, Profile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ComponentMetadata>,
 }
 
 impl Application {
@@ -233,6 +238,23 @@ pub struct Profile {
     pub redeploy_http_api: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub redeploy_all: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub worker_rollout: Option<WorkerRollout>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct WorkerRollout {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub strategy: Option<WorkerRolloutStrategy>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub canary_percent: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub concurrency: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_failure_percent: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub on_failure: Option<WorkerRolloutFailureAction>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -241,6 +263,9 @@ pub struct InitialComponentFile {
     pub source_path: String,
     pub target_path: ComponentFilePath,
     pub permissions: Option<ComponentFilePermissions>,
+    /// Optional sha256:<HEX> or blake3:<HEX> pin for http(s) source paths
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -268,6 +293,8 @@ pub struct ComponentProperties {
     pub plugins: Vec<PluginInstallation>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub env: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ComponentMetadata>,
 }
 
 impl ComponentProperties {
@@ -312,6 +339,63 @@ impl ComponentProperties {
             self.env.extend(overrides.env);
         }
 
+        if let Some(metadata) = overrides.metadata {
+            self.metadata = Some(
+                self.metadata
+                    .unwrap_or_default()
+                    .merge_with_overrides(metadata),
+            );
+        }
+
+        self
+    }
+}
+
+/// Metadata written into the custom sections of the linked component WASM, following the
+/// OCI annotation names. Can be defined on the application level, as defaults for all components.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ComponentMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authors: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub licenses: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub homepage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+}
+
+impl ComponentMetadata {
+    pub fn merge_with_overrides(mut self, overrides: ComponentMetadata) -> Self {
+        if overrides.authors.is_some() {
+            self.authors = overrides.authors;
+        }
+
+        if overrides.description.is_some() {
+            self.description = overrides.description;
+        }
+
+        if overrides.licenses.is_some() {
+            self.licenses = overrides.licenses;
+        }
+
+        if overrides.source.is_some() {
+            self.source = overrides.source;
+        }
+
+        if overrides.homepage.is_some() {
+            self.homepage = overrides.homepage;
+        }
+
+        if overrides.version.is_some() {
+            self.version = overrides.version;
+        }
+
         self
     }
 }
@@ -325,6 +409,7 @@ pub enum BuildCommand {
     AgentWrapper(GenerateAgentWrapper),
     ComposeAgentWrapper(ComposeAgentWrapper),
     InjectToPrebuiltQuickJs(InjectToPrebuiltQuickJs),
+    ComposeWac(ComposeWac),
 }
 
 impl BuildCommand {
@@ -336,6 +421,7 @@ impl BuildCommand {
             BuildCommand::AgentWrapper(_) => None,
             BuildCommand::ComposeAgentWrapper(_) => None,
             BuildCommand::InjectToPrebuiltQuickJs(_) => None,
+            BuildCommand::ComposeWac(_) => None,
         }
     }
 
@@ -347,6 +433,7 @@ impl BuildCommand {
             BuildCommand::AgentWrapper(cmd) => vec![cmd.generate_agent_wrapper.clone()],
             BuildCommand::ComposeAgentWrapper(cmd) => vec![cmd.to.clone()],
             BuildCommand::InjectToPrebuiltQuickJs(cmd) => vec![cmd.into.clone()],
+            BuildCommand::ComposeWac(cmd) => vec![cmd.to.clone()],
         }
     }
 }
@@ -419,6 +506,23 @@ pub struct InjectToPrebuiltQuickJs {
     pub into: String,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ComposeWac {
+    /// The path of the WAC composition file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compose_wac: Option<String>,
+    /// Inline WAC composition, used instead of a composition file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compose_wac_inline: Option<String>,
+    /// Additional packages by package name and WASM path, the outputs of the application
+    /// components are available by their component names
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub packages: BTreeMap<String, String>,
+    /// The path of the resulting composed WASM component
+    pub to: String,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct Dependency {
@@ -430,6 +534,36 @@ pub struct Dependency {
     pub path: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Optional sha256:<HEX> or blake3:<HEX> pin for url dependencies
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+    /// Imported interfaces to plug from this dependency when linking, all matching interfaces
+    /// are plugged if empty. Selected interfaces take precedence over interfaces plugged by
+    /// matching names, which are taken from the last linked dependency exporting them
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub interfaces: Vec<String>,
+    /// Imports of the component mapped to differently named exports of this dependency
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub mappings: BTreeMap<String, String>,
+}
+
+/// WIT package fetched from a registry or from a git repository, and vendored into wit/deps
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RemoteWitDep {
+    /// Package name with optional version, e.g. wasi:http@0.2.3
+    pub package: String,
+    /// OCI registry namespace, e.g. ghcr.io/webassembly, defaults to ghcr.io/webassembly for wasi packages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub git: Option<String>,
+    /// Git branch, tag or commit, defaults to HEAD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rev: Option<String>,
+    /// Directory of the package in the git repository, defaults to wit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -0,0 +1,589 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::component::{format_function_name, render_type};
+use golem_wasm_ast::analysis::{
+    AnalysedExport, AnalysedFunction, AnalysedInstance, AnalysedType, NameOptionTypePair,
+    NameTypePair, TypeEnum, TypeFlags, TypeRecord, TypeTuple, TypeVariant,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::mem::discriminant;
+
+/// Classification of a change in the exported functions, from the point of view of
+/// automatically updating existing workers (which replays their oplog using the new version)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompatibilityLevel {
+    Compatible,
+    Risky,
+    Incompatible,
+}
+
+impl Display for CompatibilityLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompatibilityLevel::Compatible => write!(f, "compatible"),
+            CompatibilityLevel::Risky => write!(f, "risky"),
+            CompatibilityLevel::Incompatible => write!(f, "incompatible"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityChange {
+    pub level: CompatibilityLevel,
+    pub function: String,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompatibilityReport {
+    pub changes: Vec<CompatibilityChange>,
+}
+
+impl CompatibilityReport {
+    pub fn level(&self) -> CompatibilityLevel {
+        self.changes
+            .iter()
+            .map(|change| change.level)
+            .max()
+            .unwrap_or(CompatibilityLevel::Compatible)
+    }
+
+    pub fn is_incompatible(&self) -> bool {
+        self.level() == CompatibilityLevel::Incompatible
+    }
+
+    pub fn changes_with_level(
+        &self,
+        level: CompatibilityLevel,
+    ) -> impl Iterator<Item = &CompatibilityChange> {
+        self.changes
+            .iter()
+            .filter(move |change| change.level == level)
+    }
+}
+
+/// Compares the exports of the current (deployed) and the new component versions
+pub fn check_exports_compatibility(
+    current_exports: &[AnalysedExport],
+    new_exports: &[AnalysedExport],
+) -> CompatibilityReport {
+    let current_functions = exported_functions(current_exports);
+    let new_functions = exported_functions(new_exports);
+
+    let mut changes = Vec::new();
+
+    for (name, current_function) in &current_functions {
+        match new_functions.get(name) {
+            Some(new_function) => {
+                check_function(&mut changes, name, current_function, new_function);
+            }
+            None => changes.push(CompatibilityChange {
+                level: CompatibilityLevel::Incompatible,
+                function: name.clone(),
+                description: "removed function".to_string(),
+            }),
+        }
+    }
+
+    for name in new_functions.keys() {
+        if !current_functions.contains_key(name) {
+            changes.push(CompatibilityChange {
+                level: CompatibilityLevel::Compatible,
+                function: name.clone(),
+                description: "added function".to_string(),
+            });
+        }
+    }
+
+    CompatibilityReport { changes }
+}
+
+fn exported_functions(exports: &[AnalysedExport]) -> BTreeMap<String, &AnalysedFunction> {
+    exports
+        .iter()
+        .flat_map(|export| match export {
+            AnalysedExport::Instance(AnalysedInstance { name, functions }) => functions
+                .iter()
+                .map(|function| (format_function_name(Some(name), &function.name), function))
+                .collect::<Vec<_>>(),
+            AnalysedExport::Function(function) => {
+                vec![(format_function_name(None, &function.name), function)]
+            }
+        })
+        .collect()
+}
+
+fn check_function(
+    changes: &mut Vec<CompatibilityChange>,
+    name: &str,
+    current: &AnalysedFunction,
+    new: &AnalysedFunction,
+) {
+    let mut add = |level: CompatibilityLevel, description: String| {
+        changes.push(CompatibilityChange {
+            level,
+            function: name.to_string(),
+            description,
+        })
+    };
+
+    if current.parameters.len() != new.parameters.len() {
+        add(
+            CompatibilityLevel::Incompatible,
+            format!(
+                "changed parameter count from {} to {}",
+                current.parameters.len(),
+                new.parameters.len()
+            ),
+        );
+    } else {
+        for (current_param, new_param) in current.parameters.iter().zip(&new.parameters) {
+            if current_param.name != new_param.name {
+                add(
+                    CompatibilityLevel::Risky,
+                    format!(
+                        "renamed parameter {} to {}",
+                        current_param.name, new_param.name
+                    ),
+                );
+            }
+            for (level, description) in
+                check_type(TypeDirection::Input, &current_param.typ, &new_param.typ)
+            {
+                add(
+                    level,
+                    format!("parameter {}: {}", new_param.name, description),
+                );
+            }
+        }
+    }
+
+    match (&current.result, &new.result) {
+        (None, None) => {}
+        (Some(_), None) => add(
+            CompatibilityLevel::Incompatible,
+            "removed result".to_string(),
+        ),
+        (None, Some(_)) => add(CompatibilityLevel::Incompatible, "added result".to_string()),
+        (Some(current_result), Some(new_result)) => {
+            for (level, description) in
+                check_type(TypeDirection::Output, &current_result.typ, &new_result.typ)
+            {
+                add(level, format!("result: {description}"));
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeDirection {
+    Input,
+    Output,
+}
+
+impl TypeDirection {
+    // NOTE: extending the set of accepted values is safe for inputs, as the old values are still
+    //       valid, but callers and replayed results may not expect the new values for outputs
+    fn added_case_level(&self) -> CompatibilityLevel {
+        match self {
+            TypeDirection::Input => CompatibilityLevel::Compatible,
+            TypeDirection::Output => CompatibilityLevel::Risky,
+        }
+    }
+}
+
+fn check_type(
+    direction: TypeDirection,
+    current: &AnalysedType,
+    new: &AnalysedType,
+) -> Vec<(CompatibilityLevel, String)> {
+    let mut changes = Vec::new();
+    check_type_into(&mut changes, direction, "", current, new);
+    changes
+}
+
+fn check_type_into(
+    changes: &mut Vec<(CompatibilityLevel, String)>,
+    direction: TypeDirection,
+    path: &str,
+    current: &AnalysedType,
+    new: &AnalysedType,
+) {
+    let at = |description: String| {
+        if path.is_empty() {
+            description
+        } else {
+            format!("{description} at {path}")
+        }
+    };
+    let nested = |name: &str| {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{path}.{name}")
+        }
+    };
+
+    match (current, new) {
+        (
+            AnalysedType::Variant(TypeVariant {
+                cases: current_cases,
+                ..
+            }),
+            AnalysedType::Variant(TypeVariant {
+                cases: new_cases, ..
+            }),
+        ) => {
+            let new_cases_by_name = new_cases
+                .iter()
+                .map(|case| (case.name.as_str(), case))
+                .collect::<BTreeMap<_, _>>();
+            for NameOptionTypePair { name, typ } in current_cases {
+                match new_cases_by_name.get(name.as_str()) {
+                    Some(new_case) => match (typ, &new_case.typ) {
+                        (None, None) => {}
+                        (Some(current_typ), Some(new_typ)) => {
+                            check_type_into(changes, direction, &nested(name), current_typ, new_typ)
+                        }
+                        _ => changes.push((
+                            CompatibilityLevel::Incompatible,
+                            at(format!("changed payload of case {name}")),
+                        )),
+                    },
+                    None => changes.push((
+                        CompatibilityLevel::Incompatible,
+                        at(format!("removed case {name}")),
+                    )),
+                }
+            }
+            check_added_names(
+                changes,
+                direction,
+                &at,
+                "case",
+                current_cases.iter().map(|case| case.name.as_str()),
+                new_cases.iter().map(|case| case.name.as_str()),
+            );
+        }
+        (
+            AnalysedType::Enum(TypeEnum {
+                cases: current_cases,
+                ..
+            }),
+            AnalysedType::Enum(TypeEnum {
+                cases: new_cases, ..
+            }),
+        ) => {
+            check_removed_names(changes, &at, "case", current_cases, new_cases);
+            check_added_names(
+                changes,
+                direction,
+                &at,
+                "case",
+                current_cases.iter().map(|case| case.as_str()),
+                new_cases.iter().map(|case| case.as_str()),
+            );
+        }
+        (
+            AnalysedType::Flags(TypeFlags {
+                names: current_names,
+                ..
+            }),
+            AnalysedType::Flags(TypeFlags {
+                names: new_names, ..
+            }),
+        ) => {
+            check_removed_names(changes, &at, "flag", current_names, new_names);
+            check_added_names(
+                changes,
+                direction,
+                &at,
+                "flag",
+                current_names.iter().map(|name| name.as_str()),
+                new_names.iter().map(|name| name.as_str()),
+            );
+        }
+        (
+            AnalysedType::Record(TypeRecord {
+                fields: current_fields,
+                ..
+            }),
+            AnalysedType::Record(TypeRecord {
+                fields: new_fields, ..
+            }),
+        ) => {
+            let new_fields_by_name = new_fields
+                .iter()
+                .map(|field| (field.name.as_str(), field))
+                .collect::<BTreeMap<_, _>>();
+            for NameTypePair { name, typ } in current_fields {
+                match new_fields_by_name.get(name.as_str()) {
+                    Some(new_field) => {
+                        check_type_into(changes, direction, &nested(name), typ, &new_field.typ)
+                    }
+                    None => changes.push((
+                        CompatibilityLevel::Incompatible,
+                        at(format!("removed field {name}")),
+                    )),
+                }
+            }
+            let current_field_names = current_fields
+                .iter()
+                .map(|field| field.name.as_str())
+                .collect::<BTreeSet<_>>();
+            for field in new_fields {
+                if !current_field_names.contains(field.name.as_str()) {
+                    changes.push((
+                        CompatibilityLevel::Incompatible,
+                        at(format!("added field {}", field.name)),
+                    ));
+                }
+            }
+        }
+        (
+            AnalysedType::Tuple(TypeTuple {
+                items: current_items,
+                ..
+            }),
+            AnalysedType::Tuple(TypeTuple {
+                items: new_items, ..
+            }),
+        ) => {
+            if current_items.len() != new_items.len() {
+                changes.push((
+                    CompatibilityLevel::Incompatible,
+                    at(format!(
+                        "changed tuple size from {} to {}",
+                        current_items.len(),
+                        new_items.len()
+                    )),
+                ));
+            } else {
+                for (idx, (current_item, new_item)) in
+                    current_items.iter().zip(new_items).enumerate()
+                {
+                    check_type_into(
+                        changes,
+                        direction,
+                        &nested(&idx.to_string()),
+                        current_item,
+                        new_item,
+                    );
+                }
+            }
+        }
+        (AnalysedType::Option(current), AnalysedType::Option(new)) => {
+            check_type_into(changes, direction, path, &current.inner, &new.inner)
+        }
+        (AnalysedType::List(current), AnalysedType::List(new)) => {
+            check_type_into(changes, direction, path, &current.inner, &new.inner)
+        }
+        (AnalysedType::Result(current), AnalysedType::Result(new)) => {
+            for (name, current, new) in [
+                ("ok", &current.ok, &new.ok),
+                ("err", &current.err, &new.err),
+            ] {
+                match (current, new) {
+                    (None, None) => {}
+                    (Some(current), Some(new)) => {
+                        check_type_into(changes, direction, &nested(name), current, new)
+                    }
+                    _ => changes.push((
+                        CompatibilityLevel::Incompatible,
+                        at(format!("changed {name} type of result")),
+                    )),
+                }
+            }
+        }
+        (AnalysedType::Handle(current), AnalysedType::Handle(new)) => {
+            // NOTE: resource ids are not stable between component versions, so only the mode is compared
+            if current.mode != new.mode {
+                changes.push((
+                    CompatibilityLevel::Incompatible,
+                    at("changed resource handle mode".to_string()),
+                ));
+            }
+        }
+        (current, new) => {
+            if discriminant(current) != discriminant(new) {
+                changes.push((
+                    CompatibilityLevel::Incompatible,
+                    at(format!(
+                        "changed type from {} to {}",
+                        render_type(current),
+                        render_type(new)
+                    )),
+                ));
+            }
+        }
+    }
+}
+
+fn check_removed_names(
+    changes: &mut Vec<(CompatibilityLevel, String)>,
+    at: &impl Fn(String) -> String,
+    kind: &str,
+    current_names: &[String],
+    new_names: &[String],
+) {
+    for name in current_names {
+        if !new_names.contains(name) {
+            changes.push((
+                CompatibilityLevel::Incompatible,
+                at(format!("removed {kind} {name}")),
+            ));
+        }
+    }
+}
+
+fn check_added_names<'a>(
+    changes: &mut Vec<(CompatibilityLevel, String)>,
+    direction: TypeDirection,
+    at: &impl Fn(String) -> String,
+    kind: &str,
+    current_names: impl Iterator<Item = &'a str>,
+    new_names: impl Iterator<Item = &'a str>,
+) {
+    let current_names = current_names.collect::<BTreeSet<_>>();
+    for name in new_names {
+        if !current_names.contains(name) {
+            changes.push((
+                direction.added_case_level(),
+                at(format!("added {kind} {name}")),
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::compat::{check_exports_compatibility, CompatibilityLevel};
+    use golem_wasm_ast::analysis::analysed_type::{case, field, record, str, u32, u64, variant};
+    use golem_wasm_ast::analysis::{
+        AnalysedExport, AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult,
+        AnalysedInstance, AnalysedType,
+    };
+    use test_r::test;
+
+    use assert2::{assert, let_assert};
+
+    #[test]
+    fn identical_exports_are_compatible() {
+        let exports = exports(vec![function("get", vec![("id", str())], Some(u32()))]);
+
+        let report = check_exports_compatibility(&exports, &exports);
+
+        assert!(report.changes.is_empty());
+        assert!(report.level() == CompatibilityLevel::Compatible);
+    }
+
+    #[test]
+    fn added_function_and_input_case_are_compatible() {
+        let current = exports(vec![function(
+            "set",
+            vec![("mode", variant(vec![case("a", str())]))],
+            None,
+        )]);
+        let new = exports(vec![
+            function(
+                "set",
+                vec![("mode", variant(vec![case("a", str()), case("b", u32())]))],
+                None,
+            ),
+            function("get", vec![], Some(u32())),
+        ]);
+
+        let report = check_exports_compatibility(&current, &new);
+
+        assert!(report.changes.len() == 2);
+        assert!(report.level() == CompatibilityLevel::Compatible);
+    }
+
+    #[test]
+    fn added_output_case_is_risky() {
+        let current = exports(vec![function(
+            "get",
+            vec![],
+            Some(variant(vec![case("a", str())])),
+        )]);
+        let new = exports(vec![function(
+            "get",
+            vec![],
+            Some(variant(vec![case("a", str()), case("b", str())])),
+        )]);
+
+        let report = check_exports_compatibility(&current, &new);
+
+        assert!(report.level() == CompatibilityLevel::Risky);
+    }
+
+    #[test]
+    fn changed_parameter_type_and_removed_function_are_incompatible() {
+        let current = exports(vec![
+            function(
+                "add",
+                vec![("item", record(vec![field("quantity", u32())]))],
+                None,
+            ),
+            function("remove", vec![("id", str())], None),
+        ]);
+        let new = exports(vec![function(
+            "add",
+            vec![("item", record(vec![field("quantity", u64())]))],
+            None,
+        )]);
+
+        let report = check_exports_compatibility(&current, &new);
+
+        assert!(report.is_incompatible());
+        let incompatible = report
+            .changes_with_level(CompatibilityLevel::Incompatible)
+            .collect::<Vec<_>>();
+        assert!(incompatible.len() == 2);
+        let_assert!(Some(changed) = incompatible.iter().find(|c| c.description.contains("u64")));
+        assert!(changed.function == "golem:it/api.{add}");
+        assert!(changed.description == "parameter item: changed type from u32 to u64 at quantity");
+    }
+
+    fn exports(functions: Vec<AnalysedFunction>) -> Vec<AnalysedExport> {
+        vec![AnalysedExport::Instance(AnalysedInstance {
+            name: "golem:it/api".to_string(),
+            functions,
+        })]
+    }
+
+    fn function(
+        name: &str,
+        parameters: Vec<(&str, AnalysedType)>,
+        result: Option<AnalysedType>,
+    ) -> AnalysedFunction {
+        AnalysedFunction {
+            name: name.to_string(),
+            parameters: parameters
+                .into_iter()
+                .map(|(name, typ)| AnalysedFunctionParameter {
+                    name: name.to_string(),
+                    typ,
+                })
+                .collect(),
+            result: result.map(|typ| AnalysedFunctionResult { typ }),
+        }
+    }
+}
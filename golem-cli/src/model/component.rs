@@ -12,14 +12,17 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::model::compat::{CompatibilityChange, CompatibilityLevel, CompatibilityReport};
 use crate::model::wave::function_wave_compatible;
 use crate::model::ComponentName;
 use crate::model::ProjectId;
+use crate::wasm_metadata::Payload;
 use anyhow::{anyhow, bail};
 use chrono::{DateTime, Utc};
 use golem_client::model::{
     AnalysedType, ComponentMetadata, ComponentType, InitialComponentFile, VersionedComponentId,
 };
+use golem_common::model::agent::AgentType;
 use golem_common::model::component_metadata::DynamicLinkedInstance;
 use golem_common::model::trim_date::TrimDateTime;
 use golem_wasm_ast::analysis::wave::DisplayNamedFunc;
@@ -31,6 +34,7 @@ use rib::{ParsedFunctionName, ParsedFunctionSite};
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -166,6 +170,9 @@ pub struct ComponentView {
     pub dynamic_linking: BTreeMap<String, BTreeMap<String, String>>,
     pub files: Vec<InitialComponentFile>,
     pub env: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub wasm_metadata: Option<ComponentWasmMetadataView>,
 }
 
 impl ComponentView {
@@ -201,6 +208,146 @@ impl ComponentView {
                 .collect(),
             files: value.files,
             env: value.env,
+            wasm_metadata: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentInspectView {
+    /// Local path or deployed component name and version
+    pub source: String,
+    pub exports: Vec<String>,
+    pub imports: Vec<String>,
+    pub used_interfaces: Vec<String>,
+    pub golem_packages: Vec<String>,
+    pub agent_types: Vec<AgentType>,
+    pub wasm_metadata: ComponentWasmMetadataView,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentCompatibilityView {
+    pub component_name: ComponentName,
+    /// Deployed component version or local path the component was compared with
+    pub against: String,
+    pub level: CompatibilityLevel,
+    pub changes: Vec<CompatibilityChange>,
+}
+
+impl ComponentCompatibilityView {
+    pub fn new(
+        component_name: ComponentName,
+        against: String,
+        report: CompatibilityReport,
+    ) -> Self {
+        Self {
+            component_name,
+            against,
+            level: report.level(),
+            changes: report.changes,
+        }
+    }
+}
+
+/// Metadata sections of a component WASM, describing where and how the component was built
+#[derive(Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComponentWasmMetadataView {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub authors: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub licenses: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub source: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub revision: Option<String>,
+    /// Producers by field (language, processed-by, sdk) and name, with versions as values
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    pub producers: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+impl ComponentWasmMetadataView {
+    pub fn from_wasm(wasm: &[u8]) -> anyhow::Result<Self> {
+        let payload = Payload::from_binary(wasm)?;
+        let metadata = payload.metadata();
+
+        Ok(Self {
+            name: metadata.name.clone(),
+            version: metadata.version.as_ref().map(|v| v.to_string()),
+            authors: metadata.authors.as_ref().map(|v| v.to_string()),
+            description: metadata.description.as_ref().map(|v| v.to_string()),
+            licenses: metadata.licenses.as_ref().map(|v| v.to_string()),
+            source: metadata.source.as_ref().map(|v| v.to_string()),
+            homepage: metadata.homepage.as_ref().map(|v| v.to_string()),
+            revision: metadata.revision.as_ref().map(|v| v.to_string()),
+            producers: metadata
+                .producers
+                .iter()
+                .flat_map(|producers| producers.iter())
+                .map(|(field, values)| {
+                    (
+                        field.clone(),
+                        values
+                            .iter()
+                            .map(|(name, version)| (name.clone(), version.clone()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+        })
+    }
+}
+
+/// Source of `component inspect`, either a local WASM file or a deployed component
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ComponentInspectSource {
+    LocalWasm(PathBuf),
+    Deployed {
+        component_name: ComponentName,
+        version: Option<u64>,
+    },
+}
+
+impl ComponentInspectSource {
+    /// Parses a local path or a <COMPONENT_NAME>[@<VERSION>] reference. Existing paths are
+    /// preferred, and arguments that look like paths are never split on '@', as local paths can
+    /// contain it too.
+    pub fn parse(source: &str) -> anyhow::Result<Self> {
+        let path = Path::new(source);
+        if path.exists() {
+            return Ok(Self::LocalWasm(path.to_path_buf()));
+        }
+
+        let looks_like_path = source.contains(std::path::is_separator)
+            || path
+                .extension()
+                .is_some_and(|extension| extension == "wasm");
+        if looks_like_path {
+            bail!("Local WASM file not found: {source}");
+        }
+
+        match source.rsplit_once('@') {
+            Some((component_name, version)) => match version.parse::<u64>() {
+                Ok(version) => Ok(Self::Deployed {
+                    component_name: component_name.into(),
+                    version: Some(version),
+                }),
+                Err(_) => bail!("Invalid component version: {version}"),
+            },
+            None => Ok(Self::Deployed {
+                component_name: source.into(),
+                version: None,
+            }),
         }
     }
 }
@@ -422,7 +569,13 @@ pub fn function_params_types<'t>(
 mod tests {
     use test_r::test;
 
-    use crate::model::component::render_exported_function;
+    use crate::diagnose::DetectedBuildTools;
+    use crate::model::app_raw::ComponentMetadata;
+    use crate::model::component::{
+        render_exported_function, ComponentInspectSource, ComponentWasmMetadataView,
+    };
+    use crate::wasm_rpc_stubgen::commands::metadata::add_metadata;
+    use assert2::{check, let_assert};
     use golem_wasm_ast::analysis::analysed_type::{
         bool, case, chr, f32, f64, field, flags, handle, list, option, r#enum, record, result,
         result_err, result_ok, s16, s32, s64, s8, str, tuple, u16, u32, u64, u8, unit_case,
@@ -432,6 +585,8 @@ mod tests {
         AnalysedFunction, AnalysedFunctionParameter, AnalysedFunctionResult, AnalysedResourceId,
         AnalysedResourceMode, AnalysedType,
     };
+    use std::collections::BTreeMap;
+    use wit_parser::PackageName;
 
     #[test]
     fn show_exported_function_handles_type_handle() {
@@ -700,4 +855,100 @@ mod tests {
     fn same_export_for_bool() {
         ensure_same_export(bool(), "bool")
     }
+
+    #[test]
+    fn wasm_metadata_from_linked_wasm() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let source = temp_dir.path().join("source.wasm");
+        let target = temp_dir.path().join("target.wasm");
+        std::fs::write(&source, wasm_encoder::Component::new().finish()).unwrap();
+
+        add_metadata(
+            &source,
+            PackageName {
+                namespace: "test".to_string(),
+                name: "component".to_string(),
+                version: None,
+            },
+            &ComponentMetadata {
+                version: Some("1.2.3".to_string()),
+                authors: Some("Golem".to_string()),
+                ..ComponentMetadata::default()
+            },
+            Some("abcdef-dirty"),
+            &DetectedBuildTools {
+                language: Some("rust".to_string()),
+                sdk: vec![("golem-rust".to_string(), "1.0.0".to_string())],
+                processed_by: vec![("cargo".to_string(), "1.85.0".to_string())],
+            },
+            &target,
+        )
+        .unwrap();
+
+        let view = ComponentWasmMetadataView::from_wasm(&std::fs::read(&target).unwrap()).unwrap();
+
+        check!(view.name.as_deref() == Some("test:component"));
+        check!(view.version.as_deref() == Some("1.2.3"));
+        check!(view.authors.as_deref() == Some("Golem"));
+        check!(view.revision.as_deref() == Some("abcdef-dirty"));
+        check!(
+            view.producers.get("sdk")
+                == Some(&BTreeMap::from([(
+                    "golem-rust".to_string(),
+                    "1.0.0".to_string()
+                )]))
+        );
+        check!(view
+            .producers
+            .get("processed-by")
+            .is_some_and(|tools| tools.get("cargo").map(|v| v.as_str()) == Some("1.85.0")));
+        check!(view
+            .producers
+            .get("language")
+            .is_some_and(|languages| languages.contains_key("rust")));
+    }
+
+    #[test]
+    fn wasm_metadata_from_invalid_wasm() {
+        let_assert!(Err(_) = ComponentWasmMetadataView::from_wasm(b"not a wasm"));
+    }
+
+    #[test]
+    fn inspect_source_local_path_with_at() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let dir = temp_dir.path().join("build@2");
+        std::fs::create_dir(&dir).unwrap();
+        let wasm = dir.join("component@1.wasm");
+        std::fs::write(&wasm, wasm_encoder::Component::new().finish()).unwrap();
+
+        let_assert!(
+            Ok(ComponentInspectSource::LocalWasm(path)) =
+                ComponentInspectSource::parse(wasm.to_str().unwrap())
+        );
+        check!(path == wasm);
+
+        let missing = dir.join("missing@3.wasm");
+        let_assert!(Err(err) = ComponentInspectSource::parse(missing.to_str().unwrap()));
+        check!(err.to_string().contains("Local WASM file not found"));
+    }
+
+    #[test]
+    fn inspect_source_deployed_component() {
+        check!(
+            ComponentInspectSource::parse("ns:component@3").unwrap()
+                == ComponentInspectSource::Deployed {
+                    component_name: "ns:component".into(),
+                    version: Some(3),
+                }
+        );
+        check!(
+            ComponentInspectSource::parse("ns:component").unwrap()
+                == ComponentInspectSource::Deployed {
+                    component_name: "ns:component".into(),
+                    version: None,
+                }
+        );
+        let_assert!(Err(err) = ComponentInspectSource::parse("ns:component@latest"));
+        check!(err.to_string().contains("Invalid component version"));
+    }
 }
@@ -0,0 +1,158 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum HashAlgorithm {
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Blake3 => "blake3",
+        }
+    }
+}
+
+/// Content hash in `<algorithm>:<hex>` form, used for pinning remote resources
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentHash {
+    pub algorithm: HashAlgorithm,
+    pub hex: String,
+}
+
+impl ContentHash {
+    pub fn compute(algorithm: HashAlgorithm, content: &[u8]) -> Self {
+        let hex = match algorithm {
+            HashAlgorithm::Sha256 => format!("{:x}", Sha256::digest(content)),
+            HashAlgorithm::Blake3 => blake3::hash(content).to_hex().to_string(),
+        };
+        Self { algorithm, hex }
+    }
+
+    pub fn sha256(content: &[u8]) -> Self {
+        Self::compute(HashAlgorithm::Sha256, content)
+    }
+
+    pub fn matches(&self, content: &[u8]) -> bool {
+        Self::compute(self.algorithm, content) == *self
+    }
+
+    pub fn verify(&self, source: &str, content: &[u8]) -> anyhow::Result<()> {
+        let actual = Self::compute(self.algorithm, content);
+        if actual != *self {
+            anyhow::bail!("Hash mismatch for {source}, expected {self}, got {actual}");
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ContentHash {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((algorithm, hex)) = s.split_once(':') else {
+            return Err(format!(
+                "Invalid hash {s}, expected sha256:<hex> or blake3:<hex>"
+            ));
+        };
+
+        let algorithm = match algorithm.to_ascii_lowercase().as_str() {
+            "sha256" => HashAlgorithm::Sha256,
+            "blake3" => HashAlgorithm::Blake3,
+            _ => {
+                return Err(format!(
+                    "Unsupported hash algorithm {algorithm}, supported algorithms: sha256, blake3"
+                ))
+            }
+        };
+
+        if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(format!(
+                "Invalid {} hash {hex}, expected 64 hex characters",
+                algorithm.as_str()
+            ));
+        }
+
+        Ok(Self {
+            algorithm,
+            hex: hex.to_ascii_lowercase(),
+        })
+    }
+}
+
+impl Display for ContentHash {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.algorithm.as_str(), self.hex)
+    }
+}
+
+impl Serialize for ContentHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        ContentHash::from_str(&value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::model::content_hash::{ContentHash, HashAlgorithm};
+    use assert2::{assert, let_assert};
+    use std::str::FromStr;
+    use test_r::test;
+
+    #[test]
+    fn parse_and_verify() {
+        let content = b"hello";
+
+        let_assert!(
+            Ok(hash) = ContentHash::from_str(
+                "SHA256:2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824"
+            )
+        );
+        assert!(hash.algorithm == HashAlgorithm::Sha256);
+        assert!(hash.matches(content));
+        assert!(hash == ContentHash::sha256(content));
+        assert!(
+            hash.to_string()
+                == "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+
+        let blake3 = ContentHash::compute(HashAlgorithm::Blake3, content);
+        let_assert!(Ok(parsed) = ContentHash::from_str(&blake3.to_string()));
+        assert!(parsed == blake3);
+        assert!(!parsed.matches(b"world"));
+        assert!(parsed.verify("test", b"world").is_err());
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert!(ContentHash::from_str("abcd").is_err());
+        assert!(ContentHash::from_str("md5:abcd").is_err());
+        assert!(ContentHash::from_str("sha256:abcd").is_err());
+    }
+}
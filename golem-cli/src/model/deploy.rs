@@ -12,20 +12,74 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::model::app::HttpApiDeploymentSite;
+use crate::model::component::Component;
+use crate::model::deploy_diff::api_definition::DiffableHttpApiDefinition;
+use crate::model::deploy_diff::api_deployment::DiffableHttpApiDeployment;
 use crate::model::{ComponentName, WorkerName};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+// NOTE: the snapshot is taken before deploying, so in case of a failure we can point back
+//       HTTP APIs and workers to the previous versions. Component versions cannot be deleted,
+//       so new component versions remain available on the server.
+#[derive(Clone, Debug, Default)]
+pub struct DeploySnapshot {
+    /// Latest server component versions, keyed by component name
+    pub components: BTreeMap<String, Component>,
+    /// Server HTTP API definitions that are matching the manifest ids and versions, keyed by id
+    pub api_definitions: BTreeMap<String, DiffableHttpApiDefinition>,
+    /// Server HTTP API deployments for the manifest sites, None if the site was not deployed
+    pub api_deployments: BTreeMap<HttpApiDeploymentSite, Option<DiffableHttpApiDeployment>>,
+}
+
+impl DeploySnapshot {
+    /// Returns the component versions created since the snapshot, which cannot be rolled back
+    pub fn new_component_versions(
+        &self,
+        latest_components: &BTreeMap<String, Component>,
+    ) -> Vec<(String, u64)> {
+        new_component_versions(
+            &component_versions(&self.components),
+            &component_versions(latest_components),
+        )
+    }
+}
+
+fn component_versions(components: &BTreeMap<String, Component>) -> BTreeMap<&str, u64> {
+    components
+        .iter()
+        .map(|(name, component)| (name.as_str(), component.versioned_component_id.version))
+        .collect()
+}
+
+fn new_component_versions(
+    previous_versions: &BTreeMap<&str, u64>,
+    latest_versions: &BTreeMap<&str, u64>,
+) -> Vec<(String, u64)> {
+    latest_versions
+        .iter()
+        .filter(|(name, version)| previous_versions.get(*name) != Some(*version))
+        .map(|(name, version)| (name.to_string(), *version))
+        .collect()
+}
 
 #[derive(Clone, Default, PartialEq, Eq, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TryUpdateAllWorkersResult {
     pub triggered: Vec<WorkerUpdateAttempt>,
     pub failed: Vec<WorkerUpdateAttempt>,
+    /// Workers not updated because the rollout was stopped
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub skipped: Vec<WorkerUpdateAttempt>,
 }
 
 impl TryUpdateAllWorkersResult {
     pub fn extend(&mut self, other: TryUpdateAllWorkersResult) {
         self.triggered.extend(other.triggered);
         self.failed.extend(other.failed);
+        self.skipped.extend(other.skipped);
     }
 }
 
@@ -38,3 +92,156 @@ pub struct WorkerUpdateAttempt {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 }
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkerRolloutStrategy {
+    /// Trigger the update for all workers at once
+    #[default]
+    All,
+    /// Update the workers in batches, checking the results after each batch
+    Rolling,
+    /// Update a percentage of the workers first, then continue in batches
+    Canary,
+}
+
+impl Display for WorkerRolloutStrategy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WorkerRolloutStrategy::All => write!(f, "all"),
+            WorkerRolloutStrategy::Rolling => write!(f, "rolling"),
+            WorkerRolloutStrategy::Canary => write!(f, "canary"),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkerRolloutFailureAction {
+    /// Stop the rollout
+    #[default]
+    Abort,
+    /// Ask for confirmation before continuing the rollout, stops the rollout in non-interactive mode
+    Pause,
+}
+
+/// Resolved worker update rollout settings
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WorkerRolloutConfig {
+    pub strategy: WorkerRolloutStrategy,
+    pub canary_percent: u8,
+    pub concurrency: usize,
+    pub max_failure_percent: u8,
+    pub on_failure: WorkerRolloutFailureAction,
+}
+
+impl Default for WorkerRolloutConfig {
+    fn default() -> Self {
+        Self {
+            strategy: WorkerRolloutStrategy::All,
+            canary_percent: 10,
+            concurrency: 10,
+            max_failure_percent: 0,
+            on_failure: WorkerRolloutFailureAction::Abort,
+        }
+    }
+}
+
+impl WorkerRolloutConfig {
+    /// Returns the batch sizes for updating the given number of workers, for the canary strategy
+    /// the first batch contains the canaries, limited by the concurrency
+    pub fn batches(&self, worker_count: usize) -> Vec<usize> {
+        let concurrency = self.concurrency.max(1);
+        let mut batches = Vec::new();
+        let mut remaining = worker_count;
+
+        match self.strategy {
+            WorkerRolloutStrategy::All => return vec![worker_count],
+            WorkerRolloutStrategy::Rolling => {}
+            WorkerRolloutStrategy::Canary => {
+                let canaries = (worker_count * self.canary_percent as usize)
+                    .div_ceil(100)
+                    .max(1)
+                    .min(concurrency)
+                    .min(worker_count);
+                if canaries > 0 {
+                    batches.push(canaries);
+                    remaining -= canaries;
+                }
+            }
+        }
+
+        while remaining > 0 {
+            let batch = remaining.min(concurrency);
+            batches.push(batch);
+            remaining -= batch;
+        }
+
+        batches
+    }
+
+    pub fn is_failure_threshold_exceeded(&self, failed: usize, processed: usize) -> bool {
+        processed > 0 && failed * 100 > self.max_failure_percent as usize * processed
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::deploy::{
+        new_component_versions, WorkerRolloutConfig, WorkerRolloutStrategy,
+    };
+    use assert2::check;
+    use std::collections::BTreeMap;
+    use test_r::test;
+
+    #[test]
+    fn deploy_snapshot_new_component_versions() {
+        let previous = BTreeMap::from([("app:a", 1), ("app:b", 3)]);
+        let latest = BTreeMap::from([("app:a", 1), ("app:b", 4), ("app:c", 0)]);
+
+        check!(
+            new_component_versions(&previous, &latest)
+                == vec![("app:b".to_string(), 4), ("app:c".to_string(), 0)]
+        );
+        check!(new_component_versions(&previous, &previous).is_empty());
+        check!(new_component_versions(&BTreeMap::new(), &BTreeMap::new()).is_empty());
+    }
+
+    #[test]
+    fn worker_rollout_batches() {
+        let config = |strategy| WorkerRolloutConfig {
+            strategy,
+            canary_percent: 10,
+            concurrency: 4,
+            ..WorkerRolloutConfig::default()
+        };
+
+        check!(config(WorkerRolloutStrategy::All).batches(9) == vec![9]);
+        check!(config(WorkerRolloutStrategy::Rolling).batches(9) == vec![4, 4, 1]);
+        check!(config(WorkerRolloutStrategy::Rolling).batches(0).is_empty());
+        check!(config(WorkerRolloutStrategy::Canary).batches(9) == vec![1, 4, 4]);
+        check!(config(WorkerRolloutStrategy::Canary).batches(25) == vec![3, 4, 4, 4, 4, 4, 2]);
+        check!(config(WorkerRolloutStrategy::Canary).batches(0).is_empty());
+        check!(
+            WorkerRolloutConfig {
+                canary_percent: 50,
+                ..config(WorkerRolloutStrategy::Canary)
+            }
+            .batches(20)
+                == vec![4, 4, 4, 4, 4]
+        );
+    }
+
+    #[test]
+    fn worker_rollout_failure_threshold() {
+        let config = WorkerRolloutConfig {
+            max_failure_percent: 20,
+            ..WorkerRolloutConfig::default()
+        };
+
+        check!(!config.is_failure_threshold_exceeded(0, 0));
+        check!(!config.is_failure_threshold_exceeded(1, 5));
+        check!(config.is_failure_threshold_exceeded(2, 5));
+        check!(WorkerRolloutConfig::default().is_failure_threshold_exceeded(1, 100));
+    }
+}
@@ -96,6 +96,21 @@ impl DiffableHttpApiDefinition {
     }
 }
 
+impl DiffableHttpApiDefinition {
+    /// Rewrites the component versions of the route bindings, used when promoting definitions
+    /// between profiles, where the same component has different versions
+    pub fn with_component_versions(mut self, component_versions: &BTreeMap<String, u64>) -> Self {
+        for route in &mut self.0.routes {
+            if let Some(component) = &mut route.binding.component {
+                if let Some(version) = component_versions.get(&component.name) {
+                    component.version = Some(*version);
+                }
+            }
+        }
+        self
+    }
+}
+
 impl DiffSerialize for DiffableHttpApiDefinition {
     fn to_diffable_string(&self) -> anyhow::Result<String> {
         let yaml_value = self.0.clone().to_yaml_value_without_nulls()?;
@@ -177,3 +192,67 @@ fn normalize_rib_source_code(rib: &str) -> anyhow::Result<String> {
         })?
         .to_string())
 }
+
+#[cfg(test)]
+mod test {
+    use crate::model::api::to_method_pattern;
+    use crate::model::deploy_diff::api_definition::DiffableHttpApiDefinition;
+    use assert2::check;
+    use golem_client::model::{
+        GatewayBindingComponent, GatewayBindingData, GatewayBindingType, HttpApiDefinitionRequest,
+        RouteRequestData,
+    };
+    use std::collections::BTreeMap;
+    use test_r::test;
+
+    fn route(path: &str, component: Option<(&str, u64)>) -> RouteRequestData {
+        RouteRequestData {
+            method: to_method_pattern("GET").unwrap(),
+            path: path.to_string(),
+            binding: GatewayBindingData {
+                binding_type: Some(GatewayBindingType::Default),
+                component: component.map(|(name, version)| GatewayBindingComponent {
+                    name: name.to_string(),
+                    version: Some(version),
+                }),
+                worker_name: None,
+                idempotency_key: None,
+                response: Some("\"ok\"".to_string()),
+                invocation_context: None,
+            },
+            security: None,
+        }
+    }
+
+    #[test]
+    fn api_definition_with_component_versions() {
+        let api_definition = DiffableHttpApiDefinition(HttpApiDefinitionRequest {
+            id: "api".to_string(),
+            version: "0.1.0".to_string(),
+            security: None,
+            routes: vec![
+                route("/a", Some(("app:a", 3))),
+                route("/b", Some(("app:b", 7))),
+                route("/static", None),
+            ],
+            draft: true,
+        });
+
+        let promoted =
+            api_definition.with_component_versions(&BTreeMap::from([("app:a".to_string(), 0)]));
+
+        let versions = promoted
+            .0
+            .routes
+            .iter()
+            .map(|route| {
+                route
+                    .binding
+                    .component
+                    .as_ref()
+                    .map(|component| (component.name.as_str(), component.version))
+            })
+            .collect::<Vec<_>>();
+        check!(versions == vec![Some(("app:a", Some(0))), Some(("app:b", Some(7))), None]);
+    }
+}
@@ -15,22 +15,35 @@
 pub mod agent;
 pub mod api;
 pub mod app;
+pub mod app_docs;
+pub mod app_graph;
 pub mod app_raw;
+pub mod compat;
 pub mod component;
+pub mod content_hash;
 pub mod deploy;
 pub mod deploy_diff;
 pub mod invoke_result_view;
 pub mod plugin_manifest;
 pub mod project;
+pub mod records;
 pub mod template;
 pub mod text;
 pub mod wave;
 pub mod worker;
+pub mod worker_files;
+pub mod worker_invocations;
+pub mod worker_logs;
+pub mod worker_query;
+pub mod worker_shell;
+pub mod worker_snapshot;
+pub mod worker_top;
 
 use crate::command::shared_args::{ComponentTemplateName, StreamArgs};
 use crate::config::AuthenticationConfig;
 use crate::config::{NamedProfile, ProfileConfig, ProfileName};
 use crate::log::LogColorize;
+use crate::model::worker_logs::WorkerLogFilter;
 use anyhow::{anyhow, Context};
 use chrono::{DateTime, Utc};
 use clap::builder::{StringValueParser, TypedValueParser};
@@ -70,6 +83,10 @@ pub enum Format {
     Json,
     #[serde(alias = "yaml")]
     Yaml,
+    #[serde(alias = "csv")]
+    Csv,
+    #[serde(alias = "ndjson")]
+    Ndjson,
     #[default]
     #[serde(alias = "text")]
     Text,
@@ -80,6 +97,8 @@ impl Display for Format {
         let s = match self {
             Self::Json => "json",
             Self::Yaml => "yaml",
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
             Self::Text => "text",
         };
         Display::fmt(&s, f)
@@ -93,6 +112,8 @@ impl FromStr for Format {
         match s {
             "json" => Ok(Format::Json),
             "yaml" => Ok(Format::Yaml),
+            "csv" => Ok(Format::Csv),
+            "ndjson" => Ok(Format::Ndjson),
             "text" => Ok(Format::Text),
             _ => {
                 let all = Format::iter()
@@ -581,6 +602,7 @@ pub struct WorkerConnectOptions {
     pub colors: bool,
     pub show_timestamp: bool,
     pub show_level: bool,
+    pub filter: WorkerLogFilter,
 }
 
 impl From<StreamArgs> for WorkerConnectOptions {
@@ -589,6 +611,7 @@ impl From<StreamArgs> for WorkerConnectOptions {
             colors: SHOULD_COLORIZE.should_colorize(),
             show_timestamp: !args.stream_no_timestamp,
             show_level: !args.stream_no_log_level,
+            filter: WorkerLogFilter::default(),
         }
     }
 }
@@ -607,6 +630,8 @@ pub struct ProfileView {
     pub allow_insecure: bool,
     #[serde(skip_serializing_if = "Option::is_none", default)]
     pub authenticated: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub project: Option<String>,
     pub config: ProfileConfig,
 }
 
@@ -617,6 +642,7 @@ impl ProfileView {
         let authenticated = match &profile.auth {
             AuthenticationConfig::OAuth2(inner) => Some(inner.data.is_some()),
             AuthenticationConfig::Static(_) => None,
+            AuthenticationConfig::Stored(_) => None,
         };
 
         ProfileView {
@@ -627,6 +653,7 @@ impl ProfileView {
             worker_url: profile.custom_worker_url,
             allow_insecure: profile.allow_insecure,
             authenticated,
+            project: profile.project,
             config: profile.config,
         }
     }
@@ -0,0 +1,228 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::log::LogColorize;
+use crate::model::text::fmt::log_warn;
+use itertools::Itertools;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+/// Flattens a view into records for the line based (CSV and NDJSON) formats, lists produce
+/// one record per element, every other view is a single record
+pub fn view_records<View: Serialize>(view: &View) -> Vec<Value> {
+    match serde_json::to_value(view).unwrap() {
+        Value::Array(records) => records,
+        value => vec![value],
+    }
+}
+
+/// Records of a view wrapping a list (e.g. workers with their cursors), used by views opting in
+/// to be rendered as the records of the list
+pub fn view_field_records<View: Serialize>(view: &View, field: &str) -> Vec<Value> {
+    match serde_json::to_value(view).unwrap() {
+        Value::Object(mut fields) => match fields.remove(field) {
+            Some(Value::Array(records)) => records,
+            Some(value) => vec![value],
+            None => vec![],
+        },
+        value => vec![value],
+    }
+}
+
+/// Adds the given fields as nulls to the records missing them, so fields skipped when empty
+/// still get a column when the CSV header is taken from a page without them
+pub fn with_fields(mut records: Vec<Value>, fields: &[&str]) -> Vec<Value> {
+    for record in &mut records {
+        if let Value::Object(record_fields) = record {
+            for field in fields {
+                record_fields
+                    .entry(field.to_string())
+                    .or_insert(Value::Null);
+            }
+        }
+    }
+    records
+}
+
+pub fn ndjson_lines(records: &[Value]) -> Vec<String> {
+    records
+        .iter()
+        .map(|record| serde_json::to_string(record).unwrap())
+        .collect()
+}
+
+/// Renders records as CSV, the header is emitted with the first non-empty batch of records,
+/// so pages of the same list can be written one by one. Fields of later pages without a column
+/// in the header are dropped with a warning.
+#[derive(Debug, Default)]
+pub struct CsvRecords {
+    columns: Option<Vec<String>>,
+    dropped_columns: BTreeSet<String>,
+}
+
+impl CsvRecords {
+    pub fn lines(&mut self, records: &[Value]) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        if self.columns.is_none() && !records.is_empty() {
+            let columns = csv_columns(records);
+            lines.push(csv_line(columns.iter().map(|column| column.as_str())));
+            self.columns = Some(columns);
+        }
+        let Some(columns) = &self.columns else {
+            return lines;
+        };
+
+        let dropped_columns = csv_columns(records)
+            .into_iter()
+            .filter(|column| !columns.contains(column) && !self.dropped_columns.contains(column))
+            .collect::<Vec<_>>();
+        if !dropped_columns.is_empty() {
+            log_warn(format!(
+                "Fields missing from the CSV header are not written: {}",
+                dropped_columns
+                    .iter()
+                    .map(|column| column.log_color_highlight())
+                    .join(", ")
+            ));
+            self.dropped_columns.extend(dropped_columns);
+        }
+
+        for record in records {
+            let cells = columns
+                .iter()
+                .map(|column| match record {
+                    Value::Object(fields) => fields.get(column).map(csv_cell).unwrap_or_default(),
+                    Value::Array(elements) => column
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|idx| elements.get(idx))
+                        .map(csv_cell)
+                        .unwrap_or_default(),
+                    value => csv_cell(value),
+                })
+                .collect::<Vec<_>>();
+            lines.push(csv_line(cells.iter().map(|cell| cell.as_str())));
+        }
+
+        lines
+    }
+}
+
+fn csv_columns(records: &[Value]) -> Vec<String> {
+    let mut columns = Vec::<String>::new();
+    for record in records {
+        let record_columns = match record {
+            Value::Object(fields) => fields.keys().cloned().collect(),
+            Value::Array(elements) => (0..elements.len()).map(|idx| idx.to_string()).collect(),
+            _ => vec!["value".to_string()],
+        };
+        for column in record_columns {
+            if !columns.contains(&column) {
+                columns.push(column);
+            }
+        }
+    }
+    columns
+}
+
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(value) => value.clone(),
+        Value::Bool(_) | Value::Number(_) => value.to_string(),
+        Value::Array(_) | Value::Object(_) => serde_json::to_string(value).unwrap(),
+    }
+}
+
+fn csv_line<'a>(cells: impl Iterator<Item = &'a str>) -> String {
+    cells
+        .map(|cell| {
+            if cell.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", cell.replace('"', "\"\""))
+            } else {
+                cell.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::records::{
+        ndjson_lines, view_field_records, view_records, with_fields, CsvRecords,
+    };
+    use assert2::check;
+    use serde_json::json;
+    use test_r::test;
+
+    #[test]
+    fn records_of_views() {
+        check!(view_records(&json!([{"a": 1}, {"a": 2}])).len() == 2);
+        check!(view_records(&json!({"workers": [{"a": 1}, {"a": 2}], "cursors": {}})).len() == 1);
+        check!(
+            view_field_records(
+                &json!({"workers": [{"a": 1}, {"a": 2}], "cursors": {}}),
+                "workers"
+            ) == vec![json!({"a": 1}), json!({"a": 2})]
+        );
+        check!(view_field_records(&json!({"cursors": {}}), "workers").is_empty());
+        check!(
+            ndjson_lines(&view_records(&json!([{"a": 1}, {"a": 2}])))
+                == vec![r#"{"a":1}"#, r#"{"a":2}"#]
+        );
+    }
+
+    #[test]
+    fn csv_pages() {
+        let mut csv = CsvRecords::default();
+
+        check!(csv.lines(&[]).is_empty());
+        check!(
+            csv.lines(&view_records(&json!([
+                {"name": "a,b", "version": 1, "env": {"K": "V"}},
+                {"name": "say \"hi\"", "version": 2, "env": null}
+            ]))) == vec![
+                "env,name,version",
+                r#""{""K"":""V""}","a,b",1"#,
+                r#","say ""hi""",2"#,
+            ]
+        );
+        check!(csv.dropped_columns.is_empty());
+
+        check!(csv.lines(&view_records(&json!([{"name": "c", "other": true}]))) == vec![",c,"]);
+        check!(csv.dropped_columns.iter().collect::<Vec<_>>() == vec!["other"]);
+    }
+
+    #[test]
+    fn csv_pages_with_fields() {
+        let mut csv = CsvRecords::default();
+
+        check!(
+            csv.lines(&with_fields(
+                view_records(&json!([{"name": "a"}])),
+                &["createdBy"]
+            )) == vec!["createdBy,name", ",a"]
+        );
+        check!(
+            csv.lines(&with_fields(
+                view_records(&json!([{"name": "b", "createdBy": "x"}])),
+                &["createdBy"]
+            )) == vec!["x,b"]
+        );
+        check!(csv.dropped_columns.is_empty());
+    }
+}
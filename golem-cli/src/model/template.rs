@@ -1,7 +1,7 @@
 use crate::model::app_raw;
 use minijinja::{Environment, Error};
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 pub trait Template<C: Serialize> {
     type Rendered;
@@ -68,6 +68,22 @@ impl<C: Serialize, T: Template<C>> Template<C> for HashMap<String, T> {
     }
 }
 
+impl<C: Serialize, T: Template<C>> Template<C> for BTreeMap<String, T> {
+    type Rendered = BTreeMap<String, T::Rendered>;
+
+    fn render(
+        &self,
+        env: &minijinja::Environment,
+        ctx: &C,
+    ) -> Result<Self::Rendered, minijinja::Error> {
+        let mut rendered = BTreeMap::<String, T::Rendered>::new();
+        for (key, template) in self {
+            rendered.insert(key.clone(), template.render(env, ctx)?);
+        }
+        Ok(rendered)
+    }
+}
+
 impl<C: Serialize> Template<C> for app_raw::BuildCommand {
     type Rendered = app_raw::BuildCommand;
 
@@ -93,6 +109,9 @@ impl<C: Serialize> Template<C> for app_raw::BuildCommand {
                     inject_to_prebuilt_quickjs.render(env, ctx)?,
                 ))
             }
+            app_raw::BuildCommand::ComposeWac(compose_wac) => Ok(
+                app_raw::BuildCommand::ComposeWac(compose_wac.render(env, ctx)?),
+            ),
         }
     }
 }
@@ -185,6 +204,20 @@ impl<C: Serialize> Template<C> for app_raw::InjectToPrebuiltQuickJs {
     }
 }
 
+impl<C: Serialize> Template<C> for app_raw::ComposeWac {
+    type Rendered = app_raw::ComposeWac;
+
+    fn render(&self, env: &Environment, ctx: &C) -> Result<Self::Rendered, Error> {
+        Ok(app_raw::ComposeWac {
+            compose_wac: self.compose_wac.render(env, ctx)?,
+            // NOTE: inline WAC is not rendered, as nested WAC blocks could clash with the template syntax
+            compose_wac_inline: self.compose_wac_inline.clone(),
+            packages: self.packages.render(env, ctx)?,
+            to: self.to.render(env, ctx)?,
+        })
+    }
+}
+
 impl<C: Serialize> Template<C> for serde_json::Value {
     type Rendered = serde_json::Value;
 
@@ -254,6 +287,22 @@ impl<C: Serialize> Template<C> for app_raw::ComponentProperties {
             files: self.files.clone(),
             plugins: self.plugins.clone(),
             env: self.env.render(env, ctx)?,
+            metadata: self.metadata.render(env, ctx)?,
+        })
+    }
+}
+
+impl<C: Serialize> Template<C> for app_raw::ComponentMetadata {
+    type Rendered = app_raw::ComponentMetadata;
+
+    fn render(&self, env: &Environment, ctx: &C) -> Result<Self::Rendered, Error> {
+        Ok(app_raw::ComponentMetadata {
+            authors: self.authors.render(env, ctx)?,
+            description: self.description.render(env, ctx)?,
+            licenses: self.licenses.render(env, ctx)?,
+            source: self.source.render(env, ctx)?,
+            homepage: self.homepage.render(env, ctx)?,
+            version: self.version.render(env, ctx)?,
         })
     }
 }
@@ -0,0 +1,35 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::log::logln;
+use crate::model::app_graph::{AppGraph, AppGraphFormat};
+use crate::model::text::fmt::TextView;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AppGraphView {
+    #[serde(flatten)]
+    pub graph: AppGraph,
+    #[serde(skip)]
+    pub text_format: AppGraphFormat,
+}
+
+impl TextView for AppGraphView {
+    fn log(&self) {
+        let rendered = match self.text_format {
+            AppGraphFormat::Dot => self.graph.to_dot(),
+            AppGraphFormat::Mermaid => self.graph.to_mermaid(),
+        };
+        logln(rendered.trim_end());
+    }
+}
@@ -12,10 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::model::component::ComponentView;
+use crate::log::LogColorize;
+use crate::model::compat::CompatibilityLevel;
+use crate::model::component::{
+    ComponentCompatibilityView, ComponentInspectView, ComponentView, ComponentWasmMetadataView,
+};
 use crate::model::text::fmt::*;
 use crate::model::ComponentName;
 use cli_table::{format::Justify, Table};
+use itertools::Itertools;
 
 use serde::{Deserialize, Serialize};
 
@@ -74,11 +79,62 @@ fn component_view_fields(view: &ComponentView) -> Vec<(String, String)> {
             view.files.as_slice(),
             !view.files.is_empty(),
             format_ifs_entry,
-        );
+        )
+        .fmt_field_option("WASM metadata", &view.wasm_metadata, format_wasm_metadata);
 
     fields.build()
 }
 
+fn format_wasm_metadata(metadata: &ComponentWasmMetadataView) -> String {
+    let sections = [
+        ("name", &metadata.name),
+        ("version", &metadata.version),
+        ("authors", &metadata.authors),
+        ("description", &metadata.description),
+        ("licenses", &metadata.licenses),
+        ("source", &metadata.source),
+        ("homepage", &metadata.homepage),
+        ("revision", &metadata.revision),
+    ];
+
+    let padding = sections
+        .iter()
+        .map(|(name, _)| name.len())
+        .chain(metadata.producers.keys().map(|field| field.len()))
+        .max()
+        .unwrap_or_default()
+        + 1;
+
+    sections
+        .into_iter()
+        .filter_map(|(name, value)| {
+            value.as_ref().map(|value| {
+                format!(
+                    "{:<padding$} {}",
+                    format!("{name}:"),
+                    value.log_color_highlight()
+                )
+            })
+        })
+        .chain(metadata.producers.iter().map(|(field, values)| {
+            format!(
+                "{:<padding$} {}",
+                format!("{field}:"),
+                values
+                    .iter()
+                    .map(|(name, version)| {
+                        if version.is_empty() {
+                            name.log_color_highlight().to_string()
+                        } else {
+                            format!("{} {}", name.log_color_highlight(), version)
+                        }
+                    })
+                    .join(", ")
+            )
+        }))
+        .join("\n")
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentCreateView(pub ComponentView);
 
@@ -128,6 +184,112 @@ impl MessageWithFields for ComponentGetView {
     }
 }
 
+impl MessageWithFields for ComponentInspectView {
+    fn message(&self) -> String {
+        format!(
+            "Inspected component {}",
+            format_message_highlight(&self.source)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Exports", &self.exports, |e| format_exports(e.as_slice()))
+            .fmt_field_optional("Imports", &self.imports, !self.imports.is_empty(), |i| {
+                format_exports(i.as_slice())
+            })
+            .fmt_field_optional(
+                "Used interfaces",
+                &self.used_interfaces,
+                !self.used_interfaces.is_empty(),
+                |i| format_exports(i.as_slice()),
+            )
+            .fmt_field_optional(
+                "Golem packages",
+                &self.golem_packages,
+                !self.golem_packages.is_empty(),
+                |p| p.iter().map(|p| p.log_color_highlight()).join("\n"),
+            )
+            .fmt_field_optional(
+                "Agent types",
+                &self.agent_types,
+                !self.agent_types.is_empty(),
+                |agent_types| {
+                    agent_types
+                        .iter()
+                        .map(|agent_type| {
+                            format!(
+                                "{}({})",
+                                agent_type.type_name.log_color_highlight(),
+                                agent_type
+                                    .methods
+                                    .iter()
+                                    .map(|method| method.name.as_str())
+                                    .join(", ")
+                            )
+                        })
+                        .join("\n")
+                },
+            )
+            .fmt_field("WASM metadata", &self.wasm_metadata, format_wasm_metadata);
+
+        fields.build()
+    }
+}
+
+impl MessageWithFields for ComponentCompatibilityView {
+    fn message(&self) -> String {
+        format!(
+            "Checked compatibility of component {} against {}: {}",
+            format_message_highlight(&self.component_name),
+            format_message_highlight(&self.against),
+            format_compatibility_level(&self.level)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        for (level, name) in [
+            (CompatibilityLevel::Incompatible, "Incompatible changes"),
+            (CompatibilityLevel::Risky, "Risky changes"),
+            (CompatibilityLevel::Compatible, "Compatible changes"),
+        ] {
+            let changes = self
+                .changes
+                .iter()
+                .filter(|change| change.level == level)
+                .collect::<Vec<_>>();
+            fields.fmt_field_optional(name, &changes, !changes.is_empty(), |changes| {
+                changes
+                    .iter()
+                    .map(|change| {
+                        format!(
+                            "{}: {}",
+                            format_export(&change.function),
+                            change.description
+                        )
+                    })
+                    .join("\n")
+            });
+        }
+
+        fields.build()
+    }
+}
+
+fn format_compatibility_level(level: &CompatibilityLevel) -> String {
+    match level {
+        CompatibilityLevel::Compatible => level.to_string().log_color_ok_highlight().to_string(),
+        CompatibilityLevel::Risky => level.to_string().log_color_warn().to_string(),
+        CompatibilityLevel::Incompatible => {
+            level.to_string().log_color_error_highlight().to_string()
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ComponentReplStartedView(pub ComponentView);
 
@@ -15,6 +15,7 @@
 use crate::fuzzy::Match;
 use crate::log::{log_warn_action, logln, LogColorize, LogIndent};
 use crate::model::deploy_diff::DiffSerialize;
+use crate::model::records::view_records;
 use crate::model::text::component::is_sensitive_env_var_name;
 use crate::model::{Format, WorkerNameMatch};
 use anyhow::Context;
@@ -24,11 +25,21 @@ use colored::Colorize;
 use golem_client::model::{InitialComponentFile, WorkerStatus};
 use itertools::Itertools;
 use regex::Regex;
+use serde::Serialize;
 use similar::TextDiff;
 use std::collections::BTreeMap;
 
 pub trait TextView {
     fn log(&self);
+
+    /// Records of the view for the line based (CSV and NDJSON) formats, lists produce one record
+    /// per element, every other view is a single record unless it overrides this
+    fn records(&self) -> Vec<serde_json::Value>
+    where
+        Self: Serialize + Sized,
+    {
+        view_records(self)
+    }
 }
 
 pub enum MessageWithFieldsIndentMode {
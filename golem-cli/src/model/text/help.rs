@@ -102,6 +102,36 @@ impl MessageWithFields for WorkerNameHelp {
     }
 }
 
+pub struct WorkerShellHelp;
+
+impl MessageWithFields for WorkerShellHelp {
+    fn message(&self) -> String {
+        "Worker shell commands:".log_color_help_group().to_string()
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields.field(
+            "<FUNCTION> <ARGUMENTS>",
+            &"Invoke a function with space separated WAVE arguments, function names are fuzzy matched and can be completed with TAB",
+        );
+        fields.field(":functions, :f", &"List the available function names");
+        fields.field(":help, :h", &"Show this help");
+        fields.field(":quit, :q, :exit", &"Exit the shell, Ctrl-D also works");
+
+        fields.build()
+    }
+
+    fn indent_mode() -> MessageWithFieldsIndentMode {
+        MessageWithFieldsIndentMode::IdentFields
+    }
+
+    fn format_field_name(name: String) -> String {
+        name.log_color_highlight().to_string()
+    }
+}
+
 pub struct ComponentNameHelp;
 
 impl MessageWithFields for ComponentNameHelp {
@@ -17,6 +17,7 @@ pub mod api_definition;
 pub mod api_deployment;
 pub mod api_domain;
 pub mod api_security;
+pub mod app_graph;
 pub mod certificate;
 pub mod component;
 pub mod fmt;
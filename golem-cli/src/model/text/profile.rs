@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::config::ProfileConfig;
+use crate::config::{ProfileConfig, ProfileName};
 use crate::log::{logln, LogColorize};
 use crate::model::text::fmt::*;
 use crate::model::ProfileView;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
+use golem_client::model::Account;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 impl TextView for Vec<ProfileView> {
     fn log(&self) {
@@ -54,7 +58,8 @@ impl MessageWithFields for ProfileView {
                 self.allow_insecure,
                 |b| b.to_string().red().to_string(),
             )
-            .field("Default output format", &self.config.default_format);
+            .field("Default output format", &self.config.default_format)
+            .fmt_field_option("Default project", &self.project, format_id);
 
         if let Some(url) = &self.url {
             if let Some(worker_url) = &self.worker_url {
@@ -80,3 +85,35 @@ impl TextView for ProfileConfig {
         ))
     }
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileWhoamiView {
+    pub profile_name: ProfileName,
+    pub account: Account,
+    pub token_id: Uuid,
+    pub token_expires_at: DateTime<Utc>,
+}
+
+impl MessageWithFields for ProfileWhoamiView {
+    fn message(&self) -> String {
+        format!(
+            "Authenticated as {} using profile {}",
+            format_message_highlight(&self.account.email),
+            format_message_highlight(&self.profile_name)
+        )
+    }
+
+    fn fields(&self) -> Vec<(String, String)> {
+        let mut fields = FieldsBuilder::new();
+
+        fields
+            .fmt_field("Account ID", &self.account.id, format_main_id)
+            .fmt_field("E-mail", &self.account.email, format_id)
+            .field("Name", &self.account.name)
+            .fmt_field("Token ID", &self.token_id, format_id)
+            .field("Token expires at", &self.token_expires_at);
+
+        fields.build()
+    }
+}
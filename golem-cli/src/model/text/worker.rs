@@ -15,7 +15,14 @@
 use crate::log::{logln, LogColorize};
 use crate::model::deploy::TryUpdateAllWorkersResult;
 use crate::model::invoke_result_view::InvokeResultView;
+use crate::model::records::{view_field_records, with_fields};
 use crate::model::text::fmt::*;
+use crate::model::worker_files::{
+    WorkerFileKind, WorkerFileView, WorkerFilesDownloadView, WorkerFilesView,
+};
+use crate::model::worker_invocations::{
+    WorkerInvocationStatus, WorkerInvocationView, WorkerInvocationsView,
+};
 use crate::model::{
     ComponentName, WorkerMetadata, WorkerMetadataView, WorkerName, WorkersMetadataResponseView,
 };
@@ -255,6 +262,168 @@ impl TextView for WorkersMetadataResponseView {
             ));
         }
     }
+
+    fn records(&self) -> Vec<serde_json::Value> {
+        // Optional fields are skipped when empty, but pages are streamed with a shared CSV header
+        with_fields(
+            view_field_records(self, "workers"),
+            &["createdBy", "projectId"],
+        )
+    }
+}
+
+#[derive(Table)]
+struct WorkerFileTableView {
+    #[table(title = "Path")]
+    pub path: String,
+    #[table(title = "Kind")]
+    pub kind: String,
+    #[table(title = "Size", justify = "Justify::Right")]
+    pub size: String,
+    #[table(title = "Permissions")]
+    pub permissions: String,
+    #[table(title = "Last modified")]
+    pub last_modified: String,
+}
+
+impl From<&WorkerFileView> for WorkerFileTableView {
+    fn from(value: &WorkerFileView) -> Self {
+        Self {
+            path: if value.is_dir() {
+                format!("{}/", value.path).blue().to_string()
+            } else {
+                value.path.clone()
+            },
+            kind: match value.kind {
+                WorkerFileKind::Directory => "directory",
+                WorkerFileKind::File => "file",
+            }
+            .to_string(),
+            size: value
+                .size
+                .map(|size| format_binary_size(&size))
+                .unwrap_or_default(),
+            permissions: value.permissions.clone().unwrap_or_default(),
+            last_modified: value
+                .last_modified
+                .map(|last_modified| last_modified.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl TextView for WorkerFilesView {
+    fn log(&self) {
+        if self.files.is_empty() {
+            logln(format!(
+                "No files found in {} for worker {}/{}",
+                self.path.log_color_highlight(),
+                self.component_name.0.log_color_highlight(),
+                self.worker_name.0.log_color_highlight(),
+            ));
+            return;
+        }
+
+        log_table::<_, WorkerFileTableView>(&self.files);
+    }
+
+    fn records(&self) -> Vec<serde_json::Value> {
+        view_field_records(self, "files")
+    }
+}
+
+impl TextView for WorkerFilesDownloadView {
+    fn log(&self) {
+        for file in &self.files {
+            logln(format!(
+                "{} -> {} ({})",
+                file.path.log_color_highlight(),
+                file.local_path.display().to_string().log_color_highlight(),
+                format_binary_size(&file.size),
+            ));
+        }
+    }
+}
+
+#[derive(Table)]
+struct WorkerInvocationTableView {
+    #[table(title = "Function")]
+    pub function_name: String,
+    #[table(title = "Idempotency key")]
+    pub idempotency_key: String,
+    #[table(title = "Status")]
+    pub status: String,
+    #[table(title = "Started")]
+    pub started_at: String,
+    #[table(title = "Ended")]
+    pub ended_at: String,
+    #[table(title = "Duration", justify = "Justify::Right")]
+    pub duration: String,
+    #[table(title = "Result")]
+    pub result: String,
+}
+
+impl From<&WorkerInvocationView> for WorkerInvocationTableView {
+    fn from(value: &WorkerInvocationView) -> Self {
+        Self {
+            function_name: format_export(&value.function_name),
+            idempotency_key: value.idempotency_key.clone(),
+            status: {
+                let status_name = value.status.to_string();
+                match value.status {
+                    WorkerInvocationStatus::Pending => status_name.yellow(),
+                    WorkerInvocationStatus::Running => status_name.cyan(),
+                    WorkerInvocationStatus::Completed => status_name.green(),
+                    WorkerInvocationStatus::Failed => status_name.bright_red(),
+                    WorkerInvocationStatus::Cancelled => status_name.white(),
+                }
+                .to_string()
+            },
+            started_at: value
+                .started_at
+                .or(value.enqueued_at)
+                .map(|started_at| started_at.to_string())
+                .unwrap_or_default(),
+            ended_at: value
+                .ended_at
+                .map(|ended_at| ended_at.to_string())
+                .unwrap_or_default(),
+            duration: value
+                .duration_ms
+                .map(|duration_ms| {
+                    if duration_ms < 1000 {
+                        format!("{duration_ms} ms")
+                    } else {
+                        format!("{:.3} s", duration_ms as f64 / 1000.0)
+                    }
+                })
+                .unwrap_or_default(),
+            result: match (&value.result, &value.error) {
+                (_, Some(error)) => error.log_color_error().to_string(),
+                (Some(result), None) => result.clone(),
+                (None, None) => String::new(),
+            },
+        }
+    }
+}
+
+impl TextView for WorkerInvocationsView {
+    fn log(&self) {
+        if self.invocations.is_empty() {
+            logln(format!(
+                "No invocations found for worker {}/{}",
+                self.component_name.0.log_color_highlight(),
+                self.worker_name.0.log_color_highlight(),
+            ));
+            return;
+        }
+
+        log_table::<_, WorkerInvocationTableView>(&self.invocations);
+    }
+
+    fn records(&self) -> Vec<serde_json::Value> {
+        view_field_records(self, "invocations")
+    }
 }
 
 impl TextView for TryUpdateAllWorkersResult {
@@ -305,6 +474,17 @@ impl TextView for Vec<(u64, PublicOplogEntry)> {
             entry.log()
         }
     }
+
+    fn records(&self) -> Vec<serde_json::Value> {
+        self.iter()
+            .map(|(oplog_index, entry)| {
+                serde_json::json!({
+                    "oplogIndex": oplog_index,
+                    "entry": entry,
+                })
+            })
+            .collect()
+    }
 }
 
 impl TextView for PublicOplogEntry {
@@ -0,0 +1,159 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{ComponentName, WorkerName};
+use chrono::{DateTime, Utc};
+use golem_client::model::{
+    ComponentFilePermissions, FlatComponentFileSystemNode, FlatComponentFileSystemNodeKind,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkerFileKind {
+    Directory,
+    File,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerFileView {
+    pub path: String,
+    pub kind: WorkerFileKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<String>,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+impl WorkerFileView {
+    pub fn from_node(dir: &str, node: &FlatComponentFileSystemNode) -> Self {
+        Self {
+            path: join_worker_path(dir, &node.name),
+            kind: match node.kind {
+                FlatComponentFileSystemNodeKind::Directory => WorkerFileKind::Directory,
+                FlatComponentFileSystemNodeKind::File => WorkerFileKind::File,
+            },
+            size: node.size,
+            permissions: node.permissions.as_ref().map(|permissions| {
+                match permissions {
+                    ComponentFilePermissions::ReadOnly => "read-only",
+                    ComponentFilePermissions::ReadWrite => "read-write",
+                }
+                .to_string()
+            }),
+            last_modified: DateTime::from_timestamp(node.last_modified as i64, 0),
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.kind == WorkerFileKind::Directory
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerFilesView {
+    pub component_name: ComponentName,
+    pub worker_name: WorkerName,
+    pub path: String,
+    pub files: Vec<WorkerFileView>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerDownloadedFileView {
+    pub path: String,
+    pub local_path: PathBuf,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerFilesDownloadView {
+    pub component_name: ComponentName,
+    pub worker_name: WorkerName,
+    pub files: Vec<WorkerDownloadedFileView>,
+}
+
+/// Normalizes a worker file system path to an absolute path without trailing separators
+pub fn normalize_worker_path(path: &str) -> String {
+    let segments = path
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != ".")
+        .collect::<Vec<_>>();
+    format!("/{}", segments.join("/"))
+}
+
+pub fn join_worker_path(dir: &str, name: &str) -> String {
+    normalize_worker_path(&format!("{dir}/{name}"))
+}
+
+/// Splits a normalized worker path into its parent directory and file name, None for the root
+pub fn split_worker_path(path: &str) -> Option<(String, String)> {
+    let path = normalize_worker_path(path);
+    let (parent, name) = path.rsplit_once('/')?;
+    if name.is_empty() {
+        return None;
+    }
+    Some((normalize_worker_path(parent), name.to_string()))
+}
+
+/// Local target of a downloaded worker file, relative to the local root of the downloaded path
+pub fn local_download_path(local_root: &Path, remote_root: &str, remote_path: &str) -> PathBuf {
+    let remote_root = normalize_worker_path(remote_root);
+    let remote_path = normalize_worker_path(remote_path);
+    let relative = remote_path
+        .strip_prefix(&remote_root)
+        .unwrap_or(&remote_path)
+        .trim_start_matches('/');
+
+    relative
+        .split('/')
+        .filter(|segment| !segment.is_empty() && *segment != "..")
+        .fold(local_root.to_path_buf(), |path, segment| path.join(segment))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_files::{
+        join_worker_path, local_download_path, normalize_worker_path, split_worker_path,
+    };
+    use assert2::check;
+    use std::path::{Path, PathBuf};
+    use test_r::test;
+
+    #[test]
+    fn worker_paths() {
+        check!(normalize_worker_path("") == "/");
+        check!(normalize_worker_path("/") == "/");
+        check!(normalize_worker_path("data//logs/./") == "/data/logs");
+        check!(join_worker_path("/", "a.txt") == "/a.txt");
+        check!(join_worker_path("/data/", "a.txt") == "/data/a.txt");
+
+        check!(split_worker_path("/") == None);
+        check!(split_worker_path("/a.txt") == Some(("/".to_string(), "a.txt".to_string())));
+        check!(split_worker_path("/data/logs/") == Some(("/data".to_string(), "logs".to_string())));
+    }
+
+    #[test]
+    fn local_download_paths() {
+        let root = Path::new("out");
+        check!(local_download_path(root, "/data", "/data/a/b.txt") == PathBuf::from("out/a/b.txt"));
+        check!(local_download_path(root, "/", "/a.txt") == PathBuf::from("out/a.txt"));
+        check!(local_download_path(root, "/data", "/data/../x") == PathBuf::from("out/x"));
+    }
+}
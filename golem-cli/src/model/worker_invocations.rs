@@ -0,0 +1,393 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{ComponentName, WorkerName};
+use chrono::{DateTime, Utc};
+use golem_client::model::PublicOplogEntry;
+use golem_common::model::public_oplog::PublicWorkerInvocation;
+use golem_common::model::Timestamp;
+use golem_wasm_rpc::{print_value_and_type, ValueAndType};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum WorkerInvocationStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl Display for WorkerInvocationStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            WorkerInvocationStatus::Pending => "pending",
+            WorkerInvocationStatus::Running => "running",
+            WorkerInvocationStatus::Completed => "completed",
+            WorkerInvocationStatus::Failed => "failed",
+            WorkerInvocationStatus::Cancelled => "cancelled",
+        };
+        write!(f, "{status}")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerInvocationView {
+    pub oplog_index: u64,
+    pub function_name: String,
+    pub idempotency_key: String,
+    pub status: WorkerInvocationStatus,
+    pub enqueued_at: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub duration_ms: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Typed result of completed invocations, rendered in `result`
+    #[serde(skip)]
+    pub response: Option<ValueAndType>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerInvocationsView {
+    pub component_name: ComponentName,
+    pub worker_name: WorkerName,
+    pub invocations: Vec<WorkerInvocationView>,
+}
+
+impl WorkerInvocationView {
+    fn new(
+        oplog_index: u64,
+        function_name: String,
+        idempotency_key: String,
+        status: WorkerInvocationStatus,
+    ) -> Self {
+        Self {
+            oplog_index,
+            function_name,
+            idempotency_key,
+            status,
+            enqueued_at: None,
+            started_at: None,
+            ended_at: None,
+            duration_ms: None,
+            result: None,
+            error: None,
+            response: None,
+        }
+    }
+
+    fn end(&mut self, status: WorkerInvocationStatus, timestamp: &Timestamp) {
+        let ended_at = to_date_time(timestamp);
+        self.status = status;
+        self.duration_ms = match (self.started_at, ended_at) {
+            (Some(started_at), Some(ended_at)) => Some((ended_at - started_at).num_milliseconds()),
+            _ => None,
+        };
+        self.ended_at = ended_at;
+    }
+
+    /// Derives the invocation history from the oplog of a worker, by pairing the enqueued,
+    /// started and completed entries of the same invocation
+    pub fn from_oplog(entries: &[(u64, PublicOplogEntry)]) -> Vec<Self> {
+        let mut invocations = Vec::<Self>::new();
+        let mut running = Option::<usize>::None;
+
+        for (oplog_index, entry) in entries {
+            match entry {
+                PublicOplogEntry::PendingWorkerInvocation(params) => {
+                    if let PublicWorkerInvocation::ExportedFunction(invocation) = &params.invocation
+                    {
+                        let mut pending = Self::new(
+                            *oplog_index,
+                            invocation.full_function_name.clone(),
+                            invocation.idempotency_key.to_string(),
+                            WorkerInvocationStatus::Pending,
+                        );
+                        pending.enqueued_at = to_date_time(&params.timestamp);
+                        invocations.push(pending);
+                    }
+                }
+                PublicOplogEntry::ExportedFunctionInvoked(params) => {
+                    let idempotency_key = params.idempotency_key.to_string();
+                    let idx = match invocations.iter().position(|invocation| {
+                        invocation.status == WorkerInvocationStatus::Pending
+                            && invocation.idempotency_key == idempotency_key
+                    }) {
+                        Some(idx) => idx,
+                        None => {
+                            invocations.push(Self::new(
+                                *oplog_index,
+                                params.function_name.clone(),
+                                idempotency_key,
+                                WorkerInvocationStatus::Running,
+                            ));
+                            invocations.len() - 1
+                        }
+                    };
+                    let invocation = &mut invocations[idx];
+                    invocation.status = WorkerInvocationStatus::Running;
+                    invocation.started_at = to_date_time(&params.timestamp);
+                    running = Some(idx);
+                }
+                PublicOplogEntry::ExportedFunctionCompleted(params) => {
+                    if let Some(idx) = running.take() {
+                        let invocation = &mut invocations[idx];
+                        invocation.end(WorkerInvocationStatus::Completed, &params.timestamp);
+                        invocation.error = None;
+                        invocation.result = Some(match &params.response {
+                            Some(response) => print_value_and_type(response)
+                                .unwrap_or_else(|err| format!("<{err}>")),
+                            None => "()".to_string(),
+                        });
+                        invocation.response = params.response.clone();
+                    }
+                }
+                PublicOplogEntry::Error(params) => {
+                    // The invocation may still be retried, so it stays the running one
+                    if let Some(idx) = running {
+                        let invocation = &mut invocations[idx];
+                        invocation.end(WorkerInvocationStatus::Failed, &params.timestamp);
+                        invocation.error = Some(params.error.clone());
+                    }
+                }
+                PublicOplogEntry::CancelInvocation(params) => {
+                    let idempotency_key = params.idempotency_key.to_string();
+                    if let Some(invocation) = invocations.iter_mut().find(|invocation| {
+                        invocation.status == WorkerInvocationStatus::Pending
+                            && invocation.idempotency_key == idempotency_key
+                    }) {
+                        invocation.end(WorkerInvocationStatus::Cancelled, &params.timestamp);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        invocations
+    }
+
+    /// Failed invocations are not finished, as they are retried, unless the worker itself failed
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            WorkerInvocationStatus::Completed | WorkerInvocationStatus::Cancelled
+        )
+    }
+}
+
+/// State of an invocation awaited by its idempotency key
+#[derive(Debug, Clone, PartialEq)]
+pub enum AwaitedInvocation {
+    NotFound,
+    InProgress(WorkerInvocationStatus),
+    Finished(WorkerInvocationView),
+}
+
+impl AwaitedInvocation {
+    pub fn new(invocation: Option<WorkerInvocationView>, worker_failed: bool) -> Self {
+        match invocation {
+            None => Self::NotFound,
+            Some(invocation)
+                if invocation.is_finished()
+                    || (worker_failed && invocation.status == WorkerInvocationStatus::Failed) =>
+            {
+                Self::Finished(invocation)
+            }
+            Some(invocation) => Self::InProgress(invocation.status),
+        }
+    }
+}
+
+impl WorkerInvocationsView {
+    pub fn pending(&self) -> impl Iterator<Item = &WorkerInvocationView> {
+        self.invocations
+            .iter()
+            .filter(|invocation| invocation.status == WorkerInvocationStatus::Pending)
+    }
+}
+
+fn to_date_time(timestamp: &Timestamp) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(timestamp.to_millis() as i64)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_invocations::{
+        AwaitedInvocation, WorkerInvocationStatus, WorkerInvocationView,
+    };
+    use assert2::{check, let_assert};
+    use golem_client::model::PublicOplogEntry;
+    use golem_common::model::invocation_context::TraceId;
+    use golem_common::model::public_oplog::{
+        CancelInvocationParameters, ErrorParameters, ExportedFunctionCompletedParameters,
+        ExportedFunctionInvokedParameters, ExportedFunctionParameters,
+        PendingWorkerInvocationParameters, PublicWorkerInvocation,
+    };
+    use golem_common::model::{IdempotencyKey, Timestamp};
+    use std::str::FromStr;
+    use test_r::test;
+
+    fn timestamp(seconds: u64) -> Timestamp {
+        Timestamp::from_str(&format!("2025-01-01T00:00:{seconds:0>2}Z")).unwrap()
+    }
+
+    fn invoked(seconds: u64, function_name: &str, key: &str) -> PublicOplogEntry {
+        PublicOplogEntry::ExportedFunctionInvoked(ExportedFunctionInvokedParameters {
+            timestamp: timestamp(seconds),
+            function_name: function_name.to_string(),
+            request: vec![],
+            idempotency_key: IdempotencyKey::new(key.to_string()),
+            trace_id: TraceId::generate(),
+            trace_states: vec![],
+            invocation_context: vec![],
+        })
+    }
+
+    fn completed(seconds: u64) -> PublicOplogEntry {
+        PublicOplogEntry::ExportedFunctionCompleted(ExportedFunctionCompletedParameters {
+            timestamp: timestamp(seconds),
+            response: None,
+            consumed_fuel: 0,
+        })
+    }
+
+    fn pending(seconds: u64, function_name: &str, key: &str) -> PublicOplogEntry {
+        PublicOplogEntry::PendingWorkerInvocation(PendingWorkerInvocationParameters {
+            timestamp: timestamp(seconds),
+            invocation: PublicWorkerInvocation::ExportedFunction(ExportedFunctionParameters {
+                idempotency_key: IdempotencyKey::new(key.to_string()),
+                full_function_name: function_name.to_string(),
+                function_input: None,
+                trace_id: TraceId::generate(),
+                trace_states: vec![],
+                invocation_context: vec![],
+            }),
+        })
+    }
+
+    #[test]
+    fn invocations_from_oplog() {
+        let entries = vec![
+            (2, invoked(1, "run", "key-1")),
+            (3, completed(3)),
+            (4, pending(4, "add", "key-2")),
+            (5, pending(4, "add", "key-3")),
+            (6, pending(5, "add", "key-4")),
+            (7, invoked(6, "add", "key-2")),
+            (
+                8,
+                PublicOplogEntry::Error(ErrorParameters {
+                    timestamp: timestamp(7),
+                    error: "trap".to_string(),
+                }),
+            ),
+            (
+                9,
+                PublicOplogEntry::CancelInvocation(CancelInvocationParameters {
+                    timestamp: timestamp(8),
+                    idempotency_key: IdempotencyKey::new("key-4".to_string()),
+                }),
+            ),
+        ];
+
+        let invocations = WorkerInvocationView::from_oplog(&entries);
+        let summary = invocations
+            .iter()
+            .map(|invocation| {
+                (
+                    invocation.idempotency_key.as_str(),
+                    invocation.status,
+                    invocation.duration_ms,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        check!(
+            summary
+                == vec![
+                    ("key-1", WorkerInvocationStatus::Completed, Some(2000)),
+                    ("key-2", WorkerInvocationStatus::Failed, Some(1000)),
+                    ("key-3", WorkerInvocationStatus::Pending, None),
+                    ("key-4", WorkerInvocationStatus::Cancelled, None),
+                ]
+        );
+        check!(invocations[0].result.as_deref() == Some("()"));
+        check!(invocations[1].error.as_deref() == Some("trap"));
+        check!(invocations[1].enqueued_at.is_some());
+        check!(invocations[3].is_finished());
+        check!(!invocations[2].is_finished());
+        check!(!invocations[1].is_finished());
+    }
+
+    fn awaited(
+        entries: &[(u64, PublicOplogEntry)],
+        key: &str,
+        worker_failed: bool,
+    ) -> AwaitedInvocation {
+        AwaitedInvocation::new(
+            WorkerInvocationView::from_oplog(entries)
+                .into_iter()
+                .find(|invocation| invocation.idempotency_key == key),
+            worker_failed,
+        )
+    }
+
+    #[test]
+    fn awaited_invocation_not_found() {
+        let entries = vec![(2, pending(1, "add", "key-1"))];
+
+        check!(awaited(&entries, "key-2", false) == AwaitedInvocation::NotFound);
+        check!(
+            awaited(&entries, "key-1", false)
+                == AwaitedInvocation::InProgress(WorkerInvocationStatus::Pending)
+        );
+    }
+
+    #[test]
+    fn awaited_invocation_waits_for_retries() {
+        let mut entries = vec![
+            (2, pending(1, "add", "key-1")),
+            (3, invoked(2, "add", "key-1")),
+            (
+                4,
+                PublicOplogEntry::Error(ErrorParameters {
+                    timestamp: timestamp(3),
+                    error: "trap".to_string(),
+                }),
+            ),
+        ];
+
+        check!(
+            awaited(&entries, "key-1", false)
+                == AwaitedInvocation::InProgress(WorkerInvocationStatus::Failed)
+        );
+        let_assert!(AwaitedInvocation::Finished(failed) = awaited(&entries, "key-1", true));
+        check!(failed.status == WorkerInvocationStatus::Failed);
+        check!(failed.error.as_deref() == Some("trap"));
+
+        // Entries fetched after resuming from the last seen oplog index
+        entries.push((5, completed(5)));
+
+        let_assert!(AwaitedInvocation::Finished(completed) = awaited(&entries, "key-1", false));
+        check!(completed.status == WorkerInvocationStatus::Completed);
+        check!(completed.error.is_none());
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use golem_common::model::{LogLevel, Timestamp};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum WorkerLogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+    Critical,
+}
+
+impl From<LogLevel> for WorkerLogLevel {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Trace => WorkerLogLevel::Trace,
+            LogLevel::Debug => WorkerLogLevel::Debug,
+            LogLevel::Info => WorkerLogLevel::Info,
+            LogLevel::Warn => WorkerLogLevel::Warn,
+            LogLevel::Error => WorkerLogLevel::Error,
+            LogLevel::Critical => WorkerLogLevel::Critical,
+        }
+    }
+}
+
+/// Selects the worker output entries to show, standard output is treated as an info,
+/// standard error as an error entry without context
+#[derive(Debug, Clone, Default)]
+pub struct WorkerLogFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub min_level: Option<WorkerLogLevel>,
+    pub context: Option<String>,
+}
+
+impl WorkerLogFilter {
+    pub fn matches(&self, timestamp: Timestamp, level: LogLevel, context: &str) -> bool {
+        let timestamp = DateTime::from_timestamp_millis(timestamp.to_millis() as i64);
+        let in_range = match timestamp {
+            Some(timestamp) => {
+                self.since.is_none_or(|since| timestamp >= since)
+                    && self.until.is_none_or(|until| timestamp < until)
+            }
+            None => true,
+        };
+
+        in_range
+            && self
+                .min_level
+                .is_none_or(|min_level| WorkerLogLevel::from(level) >= min_level)
+            && self
+                .context
+                .as_ref()
+                .is_none_or(|prefix| context.starts_with(prefix.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_logs::{WorkerLogFilter, WorkerLogLevel};
+    use assert2::check;
+    use chrono::{DateTime, Utc};
+    use golem_common::model::{LogLevel, Timestamp};
+    use std::str::FromStr;
+    use test_r::test;
+
+    #[test]
+    fn filter_entries() {
+        let at = |value: &str| Timestamp::from_str(value).unwrap();
+
+        check!(WorkerLogFilter::default().matches(at("2025-01-01T00:00:00Z"), LogLevel::Trace, ""));
+
+        let filter = WorkerLogFilter {
+            since: Some("2025-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()),
+            until: Some("2025-01-02T00:00:00Z".parse::<DateTime<Utc>>().unwrap()),
+            min_level: Some(WorkerLogLevel::Warn),
+            context: Some("app::orders".to_string()),
+        };
+        check!(filter.matches(
+            at("2025-01-01T10:00:00Z"),
+            LogLevel::Error,
+            "app::orders::db"
+        ));
+        check!(!filter.matches(at("2025-01-01T10:00:00Z"), LogLevel::Info, "app::orders"));
+        check!(!filter.matches(at("2025-01-01T10:00:00Z"), LogLevel::Warn, "app::users"));
+        check!(!filter.matches(at("2024-12-31T23:59:59Z"), LogLevel::Warn, "app::orders"));
+        check!(!filter.matches(at("2025-01-02T00:00:00Z"), LogLevel::Warn, "app::orders"));
+    }
+}
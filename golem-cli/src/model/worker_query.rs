@@ -0,0 +1,977 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Worker filter query language used by `worker list --filter`.
+//!
+//! Examples:
+//! - `name like order-* and status in (Running, Idle)`
+//! - `not (env.REGION = eu or version < 2)`
+//! - `created_at > now-1h || args = --verbose`
+//!
+//! Queries are converted into the server side `WorkerFilter` tree. Predicates which cannot be
+//! expressed there (glob patterns, `args`) are evaluated on the client, using a relaxed server
+//! side filter.
+
+use crate::model::WorkerMetadata;
+use chrono::{DateTime, NaiveDate, SecondsFormat, TimeDelta, Utc};
+use golem_client::model::WorkerStatus;
+use golem_common::model::{FilterComparator, StringFilterComparator, Timestamp, WorkerFilter};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerQuery {
+    Predicate(WorkerPredicate),
+    And(Vec<WorkerQuery>),
+    Or(Vec<WorkerQuery>),
+    Not(Box<WorkerQuery>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerPredicate {
+    Name {
+        op: StringOp,
+        value: String,
+    },
+    Env {
+        name: String,
+        op: StringOp,
+        value: String,
+    },
+    /// Matches if any of the worker arguments matches, or none of them for negated operators
+    Args {
+        op: StringOp,
+        value: String,
+    },
+    Status {
+        negated: bool,
+        values: Vec<WorkerStatus>,
+    },
+    Version {
+        op: CompareOp,
+        value: u64,
+    },
+    CreatedAt {
+        op: CompareOp,
+        value: TimeValue,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StringOp {
+    Equal,
+    NotEqual,
+    Like,
+    NotLike,
+    StartsWith,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Equal,
+    NotEqual,
+    Greater,
+    GreaterEqual,
+    Less,
+    LessEqual,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeValue {
+    Absolute(DateTime<Utc>),
+    /// Offset relative to the time of the query evaluation
+    Relative(TimeDelta),
+}
+
+impl TimeValue {
+    pub fn resolve(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            TimeValue::Absolute(value) => *value,
+            TimeValue::Relative(offset) => now + *offset,
+        }
+    }
+}
+
+/// Parses the formats accepted in queries, and plain durations as offsets to the past
+/// (e.g. `30m` is the same as `now-30m`)
+impl FromStr for TimeValue {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        parse_time(value)
+            .or_else(|| parse_duration(value).map(|duration| TimeValue::Relative(-duration)))
+            .ok_or_else(|| {
+                format!(
+                    "invalid time: {value}, expected a date, an RFC 3339 timestamp, now-<duration> or <duration>"
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerQueryParseError {
+    pub query: String,
+    /// Character offset of the offending token
+    pub position: usize,
+    pub token: Option<String>,
+    pub message: String,
+}
+
+impl Display for WorkerQueryParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.token {
+            Some(token) => writeln!(
+                f,
+                "{} at position {}, found '{}'",
+                self.message, self.position, token
+            )?,
+            None => writeln!(f, "{} at the end of the query", self.message)?,
+        }
+        writeln!(f, "  {}", self.query)?;
+        write!(
+            f,
+            "  {}{}",
+            " ".repeat(self.position),
+            "^".repeat(
+                self.token
+                    .as_ref()
+                    .map(|token| token.chars().count())
+                    .unwrap_or(1)
+                    .max(1)
+            )
+        )
+    }
+}
+
+impl std::error::Error for WorkerQueryParseError {}
+
+impl WorkerQuery {
+    pub fn parse(query: &str) -> Result<Self, WorkerQueryParseError> {
+        let mut parser = Parser {
+            query,
+            tokens: tokenize(query)?,
+            position: 0,
+        };
+        let result = parser.parse_or()?;
+        match parser.peek() {
+            Some(token) => Err(parser.error_at(token, "Unexpected token, expected 'and' or 'or'")),
+            None => Ok(result),
+        }
+    }
+
+    /// Parses multiple filters, joining them with AND
+    pub fn parse_all(filters: &[String]) -> Result<Option<Self>, WorkerQueryParseError> {
+        let mut queries = filters
+            .iter()
+            .map(|filter| Self::parse(filter))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(match queries.len() {
+            0 => None,
+            1 => queries.pop(),
+            _ => Some(WorkerQuery::And(queries)),
+        })
+    }
+
+    /// True if the query can be fully evaluated by the server
+    pub fn is_exact(&self) -> bool {
+        match self {
+            WorkerQuery::Predicate(predicate) => predicate.is_server_side(),
+            WorkerQuery::And(queries) | WorkerQuery::Or(queries) => {
+                queries.iter().all(|query| query.is_exact())
+            }
+            WorkerQuery::Not(query) => query.is_exact(),
+        }
+    }
+
+    /// Server side filter, which is exact if the query is exact, otherwise it is relaxed to
+    /// a superset of the matching workers, and the query has to be matched on the client too
+    pub fn to_worker_filter(&self, now: DateTime<Utc>) -> Option<WorkerFilter> {
+        match self {
+            WorkerQuery::Predicate(predicate) => predicate.to_worker_filter(now),
+            WorkerQuery::And(queries) => {
+                let mut filters = queries
+                    .iter()
+                    .filter_map(|query| query.to_worker_filter(now))
+                    .collect::<Vec<_>>();
+                match filters.len() {
+                    0 => None,
+                    1 => filters.pop(),
+                    _ => Some(WorkerFilter::new_and(filters)),
+                }
+            }
+            WorkerQuery::Or(queries) => queries
+                .iter()
+                .map(|query| query.to_worker_filter(now))
+                .collect::<Option<Vec<_>>>()
+                .map(WorkerFilter::new_or),
+            WorkerQuery::Not(query) => {
+                if query.is_exact() {
+                    query.to_worker_filter(now).map(WorkerFilter::new_not)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    pub fn matches(&self, worker: &WorkerMetadata, now: DateTime<Utc>) -> bool {
+        match self {
+            WorkerQuery::Predicate(predicate) => predicate.matches(worker, now),
+            WorkerQuery::And(queries) => queries.iter().all(|query| query.matches(worker, now)),
+            WorkerQuery::Or(queries) => queries.iter().any(|query| query.matches(worker, now)),
+            WorkerQuery::Not(query) => !query.matches(worker, now),
+        }
+    }
+}
+
+impl WorkerPredicate {
+    fn is_server_side(&self) -> bool {
+        match self {
+            WorkerPredicate::Name { op, value } | WorkerPredicate::Env { op, value, .. } => {
+                !matches!(op, StringOp::Like | StringOp::NotLike) || !is_glob(value)
+            }
+            WorkerPredicate::Args { .. } => false,
+            WorkerPredicate::Status { .. }
+            | WorkerPredicate::Version { .. }
+            | WorkerPredicate::CreatedAt { .. } => true,
+        }
+    }
+
+    fn to_worker_filter(&self, now: DateTime<Utc>) -> Option<WorkerFilter> {
+        if !self.is_server_side() {
+            return None;
+        }
+
+        match self {
+            WorkerPredicate::Name { op, value } => {
+                Some(WorkerFilter::new_name(op.comparator(), value.clone()))
+            }
+            WorkerPredicate::Env { name, op, value } => Some(WorkerFilter::new_env(
+                name.clone(),
+                op.comparator(),
+                value.clone(),
+            )),
+            WorkerPredicate::Args { .. } => None,
+            WorkerPredicate::Status { negated, values } => {
+                let mut filters = values
+                    .iter()
+                    .map(|status| WorkerFilter::new_status(FilterComparator::Equal, status.clone()))
+                    .collect::<Vec<_>>();
+                let filter = if filters.len() == 1 {
+                    filters.pop()?
+                } else {
+                    WorkerFilter::new_or(filters)
+                };
+                Some(if *negated {
+                    WorkerFilter::new_not(filter)
+                } else {
+                    filter
+                })
+            }
+            WorkerPredicate::Version { op, value } => {
+                Some(WorkerFilter::new_version(op.comparator(), *value))
+            }
+            WorkerPredicate::CreatedAt { op, value } => Timestamp::from_str(
+                &value
+                    .resolve(now)
+                    .to_rfc3339_opts(SecondsFormat::Millis, true),
+            )
+            .ok()
+            .map(|timestamp| WorkerFilter::new_created_at(op.comparator(), timestamp)),
+        }
+    }
+
+    fn matches(&self, worker: &WorkerMetadata, now: DateTime<Utc>) -> bool {
+        match self {
+            WorkerPredicate::Name { op, value } => op.matches(&worker.worker_id.worker_name, value),
+            WorkerPredicate::Env { name, op, value } => match worker.env.get(name) {
+                Some(actual) => op.matches(actual, value),
+                None => op.is_negated(),
+            },
+            WorkerPredicate::Args { op, value } => {
+                if op.is_negated() {
+                    worker.args.iter().all(|arg| op.matches(arg, value))
+                } else {
+                    worker.args.iter().any(|arg| op.matches(arg, value))
+                }
+            }
+            WorkerPredicate::Status { negated, values } => {
+                let status = worker.status.to_string();
+                values.iter().any(|value| value.to_string() == status) != *negated
+            }
+            WorkerPredicate::Version { op, value } => {
+                op.matches(worker.component_version.cmp(value))
+            }
+            WorkerPredicate::CreatedAt { op, value } => {
+                op.matches(worker.created_at.cmp(&value.resolve(now)))
+            }
+        }
+    }
+}
+
+impl StringOp {
+    fn comparator(&self) -> StringFilterComparator {
+        match self {
+            StringOp::Equal => StringFilterComparator::Equal,
+            StringOp::NotEqual => StringFilterComparator::NotEqual,
+            StringOp::Like => StringFilterComparator::Like,
+            StringOp::NotLike => StringFilterComparator::NotLike,
+            StringOp::StartsWith => StringFilterComparator::StartsWith,
+        }
+    }
+
+    fn is_negated(&self) -> bool {
+        matches!(self, StringOp::NotEqual | StringOp::NotLike)
+    }
+
+    pub fn matches(&self, actual: &str, pattern: &str) -> bool {
+        match self {
+            StringOp::Equal => actual == pattern,
+            StringOp::NotEqual => actual != pattern,
+            StringOp::Like => like(actual, pattern),
+            StringOp::NotLike => !like(actual, pattern),
+            StringOp::StartsWith => actual.starts_with(pattern),
+        }
+    }
+}
+
+impl CompareOp {
+    fn comparator(&self) -> FilterComparator {
+        match self {
+            CompareOp::Equal => FilterComparator::Equal,
+            CompareOp::NotEqual => FilterComparator::NotEqual,
+            CompareOp::Greater => FilterComparator::Greater,
+            CompareOp::GreaterEqual => FilterComparator::GreaterEqual,
+            CompareOp::Less => FilterComparator::Less,
+            CompareOp::LessEqual => FilterComparator::LessEqual,
+        }
+    }
+
+    fn matches(&self, ordering: Ordering) -> bool {
+        match self {
+            CompareOp::Equal => ordering.is_eq(),
+            CompareOp::NotEqual => ordering.is_ne(),
+            CompareOp::Greater => ordering.is_gt(),
+            CompareOp::GreaterEqual => ordering.is_ge(),
+            CompareOp::Less => ordering.is_lt(),
+            CompareOp::LessEqual => ordering.is_le(),
+        }
+    }
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Glob patterns have to match the whole value, other patterns match as substrings (as on the server)
+fn like(actual: &str, pattern: &str) -> bool {
+    if !is_glob(pattern) {
+        return actual.contains(pattern);
+    }
+
+    let regex = pattern
+        .chars()
+        .map(|char| match char {
+            '*' => ".*".to_string(),
+            '?' => ".".to_string(),
+            char => regex::escape(&char.to_string()),
+        })
+        .collect::<String>();
+    Regex::new(&format!("^{regex}$"))
+        .map(|regex| regex.is_match(actual))
+        .unwrap_or(false)
+}
+
+const WORKER_STATUSES: [WorkerStatus; 7] = [
+    WorkerStatus::Running,
+    WorkerStatus::Idle,
+    WorkerStatus::Suspended,
+    WorkerStatus::Interrupted,
+    WorkerStatus::Retrying,
+    WorkerStatus::Failed,
+    WorkerStatus::Exited,
+];
+
+fn parse_status(value: &str) -> Option<WorkerStatus> {
+    WORKER_STATUSES
+        .iter()
+        .find(|status| status.to_string().eq_ignore_ascii_case(value))
+        .cloned()
+}
+
+/// Parses `now`, `now-1h30m`, `now+2d`, RFC 3339 timestamps and dates
+fn parse_time(value: &str) -> Option<TimeValue> {
+    if let Some(offset) = value.strip_prefix("now") {
+        if offset.is_empty() {
+            return Some(TimeValue::Relative(TimeDelta::zero()));
+        }
+        let (sign, duration) = match offset.split_at(1) {
+            ("-", duration) => (-1, duration),
+            ("+", duration) => (1, duration),
+            _ => return None,
+        };
+        return parse_duration(duration).map(|duration| TimeValue::Relative(duration * sign));
+    }
+
+    if let Ok(value) = DateTime::parse_from_rfc3339(value) {
+        return Some(TimeValue::Absolute(value.with_timezone(&Utc)));
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|date| TimeValue::Absolute(date.and_utc()))
+}
+
+/// Parses durations like `90s`, `15m`, `1h30m`, `2d` or `1w`
+fn parse_duration(value: &str) -> Option<TimeDelta> {
+    let mut total = TimeDelta::zero();
+    let mut number = String::new();
+    for char in value.chars() {
+        if char.is_ascii_digit() {
+            number.push(char);
+            continue;
+        }
+        let amount = number.parse::<i64>().ok()?;
+        number.clear();
+        total += match char {
+            's' => TimeDelta::try_seconds(amount)?,
+            'm' => TimeDelta::try_minutes(amount)?,
+            'h' => TimeDelta::try_hours(amount)?,
+            'd' => TimeDelta::try_days(amount)?,
+            'w' => TimeDelta::try_weeks(amount)?,
+            _ => return None,
+        };
+    }
+    (number.is_empty() && !value.is_empty()).then_some(total)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word,
+    Quoted,
+    Op,
+    LParen,
+    RParen,
+    Comma,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Token {
+    kind: TokenKind,
+    /// Source text of the token
+    text: String,
+    /// Unquoted value for quoted strings, same as text otherwise
+    value: String,
+    position: usize,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        self.kind == TokenKind::Word && self.text.eq_ignore_ascii_case(keyword)
+    }
+
+    fn is_op(&self, op: &str) -> bool {
+        self.kind == TokenKind::Op && self.text == op
+    }
+}
+
+const OPERATORS: [&str; 10] = ["==", "!=", ">=", "<=", "&&", "||", "=", ">", "<", "!"];
+
+fn is_word_char(char: char) -> bool {
+    !char.is_whitespace() && !"()=!<>,\"'&|".contains(char)
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, WorkerQueryParseError> {
+    let chars = query.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let char = chars[idx];
+        let start = idx;
+
+        if char.is_whitespace() {
+            idx += 1;
+            continue;
+        }
+
+        let simple = match char {
+            '(' => Some(TokenKind::LParen),
+            ')' => Some(TokenKind::RParen),
+            ',' => Some(TokenKind::Comma),
+            _ => None,
+        };
+        if let Some(kind) = simple {
+            tokens.push(Token {
+                kind,
+                text: char.to_string(),
+                value: char.to_string(),
+                position: start,
+            });
+            idx += 1;
+            continue;
+        }
+
+        if char == '"' || char == '\'' {
+            let mut value = String::new();
+            idx += 1;
+            loop {
+                match chars.get(idx) {
+                    Some('\\') if idx + 1 < chars.len() => {
+                        value.push(chars[idx + 1]);
+                        idx += 2;
+                    }
+                    Some(current) if *current == char => {
+                        idx += 1;
+                        break;
+                    }
+                    Some(current) => {
+                        value.push(*current);
+                        idx += 1;
+                    }
+                    None => {
+                        return Err(WorkerQueryParseError {
+                            query: query.to_string(),
+                            position: start,
+                            token: Some(chars[start..].iter().collect()),
+                            message: "Unterminated string".to_string(),
+                        })
+                    }
+                }
+            }
+            tokens.push(Token {
+                kind: TokenKind::Quoted,
+                text: chars[start..idx].iter().collect(),
+                value,
+                position: start,
+            });
+            continue;
+        }
+
+        if let Some(op) = OPERATORS.iter().find(|op| {
+            op.chars()
+                .enumerate()
+                .all(|(offset, op_char)| chars.get(idx + offset) == Some(&op_char))
+        }) {
+            tokens.push(Token {
+                kind: TokenKind::Op,
+                text: op.to_string(),
+                value: op.to_string(),
+                position: start,
+            });
+            idx += op.chars().count();
+            continue;
+        }
+
+        if !is_word_char(char) {
+            return Err(WorkerQueryParseError {
+                query: query.to_string(),
+                position: start,
+                token: Some(char.to_string()),
+                message: "Unexpected character".to_string(),
+            });
+        }
+
+        while idx < chars.len() && is_word_char(chars[idx]) {
+            idx += 1;
+        }
+        let text = chars[start..idx].iter().collect::<String>();
+        tokens.push(Token {
+            kind: TokenKind::Word,
+            value: text.clone(),
+            text,
+            position: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+struct Parser<'a> {
+    query: &'a str,
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+enum ParsedOp {
+    String(StringOp),
+    Compare(CompareOp),
+    In { negated: bool },
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        if token.is_some() {
+            self.position += 1;
+        }
+        token
+    }
+
+    fn error_at(&self, token: &Token, message: &str) -> WorkerQueryParseError {
+        WorkerQueryParseError {
+            query: self.query.to_string(),
+            position: token.position,
+            token: Some(token.text.clone()),
+            message: message.to_string(),
+        }
+    }
+
+    fn error_at_end(&self, message: &str) -> WorkerQueryParseError {
+        WorkerQueryParseError {
+            query: self.query.to_string(),
+            position: self.query.chars().count(),
+            token: None,
+            message: message.to_string(),
+        }
+    }
+
+    fn expect(&mut self, message: &str) -> Result<Token, WorkerQueryParseError> {
+        self.next().ok_or_else(|| self.error_at_end(message))
+    }
+
+    fn parse_or(&mut self) -> Result<WorkerQuery, WorkerQueryParseError> {
+        let mut queries = vec![self.parse_and()?];
+        while self
+            .peek()
+            .is_some_and(|token| token.is_keyword("or") || token.is_op("||"))
+        {
+            self.next();
+            queries.push(self.parse_and()?);
+        }
+        Ok(if queries.len() == 1 {
+            queries.remove(0)
+        } else {
+            WorkerQuery::Or(queries)
+        })
+    }
+
+    fn parse_and(&mut self) -> Result<WorkerQuery, WorkerQueryParseError> {
+        let mut queries = vec![self.parse_unary()?];
+        while self
+            .peek()
+            .is_some_and(|token| token.is_keyword("and") || token.is_op("&&"))
+        {
+            self.next();
+            queries.push(self.parse_unary()?);
+        }
+        Ok(if queries.len() == 1 {
+            queries.remove(0)
+        } else {
+            WorkerQuery::And(queries)
+        })
+    }
+
+    fn parse_unary(&mut self) -> Result<WorkerQuery, WorkerQueryParseError> {
+        let token = self.expect("Expected a filter expression")?;
+        if token.is_keyword("not") || token.is_op("!") {
+            return Ok(WorkerQuery::Not(Box::new(self.parse_unary()?)));
+        }
+        if token.kind == TokenKind::LParen {
+            let query = self.parse_or()?;
+            let close = self.expect("Expected ')'")?;
+            if close.kind != TokenKind::RParen {
+                return Err(self.error_at(&close, "Expected ')'"));
+            }
+            return Ok(query);
+        }
+        if token.kind != TokenKind::Word {
+            return Err(self.error_at(&token, "Expected a property name"));
+        }
+        self.parse_predicate(token).map(WorkerQuery::Predicate)
+    }
+
+    fn parse_op(&mut self) -> Result<(Token, ParsedOp), WorkerQueryParseError> {
+        let token = self.expect("Expected a comparison operator")?;
+        let op = match token.text.to_lowercase().as_str() {
+            "=" | "==" => ParsedOp::Compare(CompareOp::Equal),
+            "!=" => ParsedOp::Compare(CompareOp::NotEqual),
+            ">" => ParsedOp::Compare(CompareOp::Greater),
+            ">=" => ParsedOp::Compare(CompareOp::GreaterEqual),
+            "<" => ParsedOp::Compare(CompareOp::Less),
+            "<=" => ParsedOp::Compare(CompareOp::LessEqual),
+            "like" if token.kind == TokenKind::Word => ParsedOp::String(StringOp::Like),
+            "notlike" if token.kind == TokenKind::Word => ParsedOp::String(StringOp::NotLike),
+            "startswith" if token.kind == TokenKind::Word => {
+                ParsedOp::String(StringOp::StartsWith)
+            }
+            "in" if token.kind == TokenKind::Word => ParsedOp::In { negated: false },
+            "not" if token.kind == TokenKind::Word => {
+                let next = self.expect("Expected 'like' or 'in' after 'not'")?;
+                if next.is_keyword("like") {
+                    ParsedOp::String(StringOp::NotLike)
+                } else if next.is_keyword("in") {
+                    ParsedOp::In { negated: true }
+                } else {
+                    return Err(self.error_at(&next, "Expected 'like' or 'in' after 'not'"));
+                }
+            }
+            _ => {
+                return Err(self.error_at(
+                    &token,
+                    "Expected a comparison operator (=, !=, >, >=, <, <=, like, not like, startswith, in, not in)",
+                ))
+            }
+        };
+        Ok((token, op))
+    }
+
+    fn parse_value(&mut self) -> Result<Token, WorkerQueryParseError> {
+        let token = self.expect("Expected a value")?;
+        match token.kind {
+            TokenKind::Word | TokenKind::Quoted => Ok(token),
+            _ => Err(self.error_at(&token, "Expected a value")),
+        }
+    }
+
+    fn parse_values(&mut self) -> Result<Vec<Token>, WorkerQueryParseError> {
+        let open = self.expect("Expected '('")?;
+        if open.kind != TokenKind::LParen {
+            return Err(self.error_at(&open, "Expected '('"));
+        }
+        let mut values = vec![self.parse_value()?];
+        loop {
+            let token = self.expect("Expected ',' or ')'")?;
+            match token.kind {
+                TokenKind::Comma => values.push(self.parse_value()?),
+                TokenKind::RParen => return Ok(values),
+                _ => return Err(self.error_at(&token, "Expected ',' or ')'")),
+            }
+        }
+    }
+
+    fn parse_predicate(
+        &mut self,
+        property: Token,
+    ) -> Result<WorkerPredicate, WorkerQueryParseError> {
+        let property_name = property.text.to_lowercase();
+        let (op_token, op) = self.parse_op()?;
+
+        let string_op = |parser: &Self, op: ParsedOp| match op {
+            ParsedOp::String(op) => Ok(op),
+            ParsedOp::Compare(CompareOp::Equal) => Ok(StringOp::Equal),
+            ParsedOp::Compare(CompareOp::NotEqual) => Ok(StringOp::NotEqual),
+            _ => Err(parser.error_at(
+                &op_token,
+                &format!("Operator is not supported for '{}'", property.text),
+            )),
+        };
+        let compare_op = |parser: &Self, op: ParsedOp| match op {
+            ParsedOp::Compare(op) => Ok(op),
+            _ => Err(parser.error_at(
+                &op_token,
+                &format!("Operator is not supported for '{}'", property.text),
+            )),
+        };
+
+        match property_name.as_str() {
+            "name" => Ok(WorkerPredicate::Name {
+                op: string_op(self, op)?,
+                value: self.parse_value()?.value,
+            }),
+            "args" => Ok(WorkerPredicate::Args {
+                op: string_op(self, op)?,
+                value: self.parse_value()?.value,
+            }),
+            "status" => {
+                let (negated, values) = match op {
+                    ParsedOp::In { negated } => (negated, self.parse_values()?),
+                    ParsedOp::Compare(CompareOp::Equal) => (false, vec![self.parse_value()?]),
+                    ParsedOp::Compare(CompareOp::NotEqual) => (true, vec![self.parse_value()?]),
+                    _ => {
+                        return Err(self.error_at(
+                            &op_token,
+                            "Operator is not supported for 'status', use =, !=, in or not in",
+                        ))
+                    }
+                };
+                let values = values
+                    .iter()
+                    .map(|token| {
+                        parse_status(&token.value).ok_or_else(|| {
+                            self.error_at(
+                                token,
+                                &format!(
+                                    "Unknown worker status, expected one of {}",
+                                    WORKER_STATUSES.iter().map(|status| status.to_string()).collect::<Vec<_>>().join(", ")
+                                ),
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(WorkerPredicate::Status { negated, values })
+            }
+            "version" => {
+                let op = compare_op(self, op)?;
+                let token = self.parse_value()?;
+                let value = token
+                    .value
+                    .parse::<u64>()
+                    .map_err(|_| self.error_at(&token, "Expected a component version number"))?;
+                Ok(WorkerPredicate::Version { op, value })
+            }
+            "created_at" | "createdat" | "created-at" => {
+                let op = compare_op(self, op)?;
+                let token = self.parse_value()?;
+                let value = parse_time(&token.value).ok_or_else(|| {
+                    self.error_at(
+                        &token,
+                        "Expected a time, e.g. now, now-1h, 2024-01-31 or 2024-01-31T12:00:00Z",
+                    )
+                })?;
+                Ok(WorkerPredicate::CreatedAt { op, value })
+            }
+            _ => match property.text.split_once('.') {
+                Some((prefix, name)) if prefix.eq_ignore_ascii_case("env") && !name.is_empty() => {
+                    Ok(WorkerPredicate::Env {
+                        name: name.to_string(),
+                        op: string_op(self, op)?,
+                        value: self.parse_value()?.value,
+                    })
+                }
+                _ => Err(self.error_at(
+                    &property,
+                    "Unknown property, expected one of name, status, version, created_at, args or env.<NAME>",
+                )),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_query::{
+        parse_duration, CompareOp, StringOp, TimeValue, WorkerPredicate, WorkerQuery,
+    };
+    use assert2::check;
+    use chrono::{DateTime, TimeDelta, Utc};
+    use golem_client::model::WorkerStatus;
+    use test_r::test;
+
+    fn name(op: StringOp, value: &str) -> WorkerQuery {
+        WorkerQuery::Predicate(WorkerPredicate::Name {
+            op,
+            value: value.to_string(),
+        })
+    }
+
+    #[test]
+    fn parse_simple_filters() {
+        check!(WorkerQuery::parse("name = worker-1").unwrap() == name(StringOp::Equal, "worker-1"));
+        check!(
+            WorkerQuery::parse("version >= 0").unwrap()
+                == WorkerQuery::Predicate(WorkerPredicate::Version {
+                    op: CompareOp::GreaterEqual,
+                    value: 0
+                })
+        );
+        check!(
+            WorkerQuery::parse("env.var1 = value").unwrap()
+                == WorkerQuery::Predicate(WorkerPredicate::Env {
+                    name: "var1".to_string(),
+                    op: StringOp::Equal,
+                    value: "value".to_string()
+                })
+        );
+    }
+
+    #[test]
+    fn parse_boolean_operators() {
+        let query =
+            WorkerQuery::parse("name like 'a b*' or not (name = x && status in (running, Idle))")
+                .unwrap();
+        check!(
+            query
+                == WorkerQuery::Or(vec![
+                    name(StringOp::Like, "a b*"),
+                    WorkerQuery::Not(Box::new(WorkerQuery::And(vec![
+                        name(StringOp::Equal, "x"),
+                        WorkerQuery::Predicate(WorkerPredicate::Status {
+                            negated: false,
+                            values: vec![WorkerStatus::Running, WorkerStatus::Idle]
+                        }),
+                    ]))),
+                ])
+        );
+        check!(!query.is_exact());
+        check!(query.to_worker_filter(Utc::now()).is_none());
+
+        let query = WorkerQuery::parse("name like order-* and version > 1").unwrap();
+        check!(!query.is_exact());
+        check!(query.to_worker_filter(Utc::now()).is_some());
+    }
+
+    #[test]
+    fn parse_times() {
+        check!(parse_duration("1h30m") == Some(TimeDelta::minutes(90)));
+        check!(parse_duration("1x") == None);
+        check!(parse_duration("10") == None);
+
+        check!(
+            WorkerQuery::parse("created_at > now-1h").unwrap()
+                == WorkerQuery::Predicate(WorkerPredicate::CreatedAt {
+                    op: CompareOp::Greater,
+                    value: TimeValue::Relative(TimeDelta::hours(-1))
+                })
+        );
+        check!(
+            WorkerQuery::parse("created_at <= 2024-01-31").unwrap()
+                == WorkerQuery::Predicate(WorkerPredicate::CreatedAt {
+                    op: CompareOp::LessEqual,
+                    value: TimeValue::Absolute(
+                        "2024-01-31T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                    )
+                })
+        );
+
+        check!("30m".parse::<TimeValue>() == Ok(TimeValue::Relative(TimeDelta::minutes(-30))));
+        check!("now-2h".parse::<TimeValue>() == Ok(TimeValue::Relative(TimeDelta::hours(-2))));
+        check!("yesterday".parse::<TimeValue>().is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = WorkerQuery::parse("name = a or foo = b").unwrap_err();
+        check!(error.position == 12);
+        check!(error.token.as_deref() == Some("foo"));
+
+        let error = WorkerQuery::parse("status = Sleeping").unwrap_err();
+        check!(error.position == 9);
+
+        let error = WorkerQuery::parse("(name = a").unwrap_err();
+        check!(error.token == None);
+        check!(error.position == 9);
+
+        let error = WorkerQuery::parse("version like 1").unwrap_err();
+        check!(error.token.as_deref() == Some("like"));
+        check!(error
+            .to_string()
+            .ends_with("\n  version like 1\n          ^^^^"));
+    }
+
+    #[test]
+    fn like_matching() {
+        check!(StringOp::Like.matches("order-12", "order-*"));
+        check!(!StringOp::Like.matches("my-order-12", "order-*"));
+        check!(StringOp::Like.matches("my-order-12", "order"));
+        check!(StringOp::NotLike.matches("a.b", "a?c"));
+    }
+}
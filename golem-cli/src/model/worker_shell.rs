@@ -0,0 +1,229 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{ComponentName, WorkerName};
+
+/// A single line entered in the worker shell
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkerShellCommand {
+    Empty,
+    Help,
+    Functions,
+    Exit,
+    Invoke {
+        function_name: String,
+        arguments: Vec<String>,
+    },
+}
+
+impl WorkerShellCommand {
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let mut words = split_shell_words(line)?.into_iter();
+        let Some(first) = words.next() else {
+            return Ok(Self::Empty);
+        };
+
+        match first.as_str() {
+            ":help" | ":h" => Ok(Self::Help),
+            ":functions" | ":f" => Ok(Self::Functions),
+            ":quit" | ":q" | ":exit" => Ok(Self::Exit),
+            command if command.starts_with(':') => Err(format!(
+                "Unknown command: {command}, use :help to list the available commands"
+            )),
+            _ => Ok(Self::Invoke {
+                function_name: first,
+                arguments: words.collect(),
+            }),
+        }
+    }
+}
+
+/// Splits a shell line on whitespace, keeping quoted strings, chars and nested WAVE values
+/// (records, lists, tuples, variants) as a single word, so they can be passed as they were typed
+pub fn split_shell_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::<String>::new();
+    let mut word = String::new();
+    let mut closing_brackets = Vec::<char>::new();
+    let mut chars = line.chars();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' | '\'' => {
+                word.push(char);
+                let mut closed = false;
+                while let Some(quoted) = chars.next() {
+                    word.push(quoted);
+                    if quoted == '\\' {
+                        if let Some(escaped) = chars.next() {
+                            word.push(escaped);
+                        }
+                    } else if quoted == char {
+                        closed = true;
+                        break;
+                    }
+                }
+                if !closed {
+                    return Err(format!("Unterminated quote: {word}"));
+                }
+            }
+            '{' | '[' | '(' => {
+                word.push(char);
+                closing_brackets.push(match char {
+                    '{' => '}',
+                    '[' => ']',
+                    _ => ')',
+                });
+            }
+            '}' | ']' | ')' => {
+                if closing_brackets.pop() != Some(char) {
+                    return Err(format!("Unexpected closing bracket: {char}"));
+                }
+                word.push(char);
+            }
+            char if char.is_whitespace() && closing_brackets.is_empty() => {
+                if !word.is_empty() {
+                    words.push(std::mem::take(&mut word));
+                }
+            }
+            char => word.push(char),
+        }
+    }
+
+    if let Some(closing_bracket) = closing_brackets.last() {
+        return Err(format!("Missing closing bracket: {closing_bracket}"));
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    Ok(words)
+}
+
+/// Completes the function name at the start of the line, matching either the fully qualified
+/// or the bare function name. Returns the start position of the completed word and the candidates,
+/// arguments are not completed.
+pub fn complete_function_name(
+    line: &str,
+    pos: usize,
+    function_names: &[String],
+) -> (usize, Vec<String>) {
+    let start = line.len() - line.trim_start().len();
+    let prefix = &line[start..pos.max(start)];
+    if prefix.contains(char::is_whitespace) {
+        return (pos, vec![]);
+    }
+
+    let candidates = function_names
+        .iter()
+        .filter(|function_name| {
+            function_name.starts_with(prefix)
+                || bare_function_name(function_name).starts_with(prefix)
+        })
+        .cloned()
+        .collect();
+    (start, candidates)
+}
+
+fn bare_function_name(function_name: &str) -> &str {
+    function_name
+        .rsplit_once(".{")
+        .map(|(_, function_name)| function_name.trim_end_matches('}'))
+        .unwrap_or(function_name)
+}
+
+/// File name of the per-worker command history
+pub fn history_file_name(component_name: &ComponentName, worker_name: &WorkerName) -> String {
+    let sanitize = |name: &str| {
+        name.chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() || char == '-' || char == '_' {
+                    char
+                } else {
+                    '_'
+                }
+            })
+            .collect::<String>()
+    };
+    format!(
+        "{}.{}.history",
+        sanitize(&component_name.0),
+        sanitize(&worker_name.0)
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_shell::{
+        complete_function_name, history_file_name, split_shell_words, WorkerShellCommand,
+    };
+    use crate::model::{ComponentName, WorkerName};
+    use assert2::check;
+    use test_r::test;
+
+    #[test]
+    fn parse_commands() {
+        check!(WorkerShellCommand::parse("  ") == Ok(WorkerShellCommand::Empty));
+        check!(WorkerShellCommand::parse(":q") == Ok(WorkerShellCommand::Exit));
+        check!(WorkerShellCommand::parse(":functions") == Ok(WorkerShellCommand::Functions));
+        check!(WorkerShellCommand::parse(":unknown").is_err());
+        check!(
+            WorkerShellCommand::parse(r#"add-item {name: "a b", tags: ["x", "y"]} 'c' 12"#)
+                == Ok(WorkerShellCommand::Invoke {
+                    function_name: "add-item".to_string(),
+                    arguments: vec![
+                        r#"{name: "a b", tags: ["x", "y"]}"#.to_string(),
+                        "'c'".to_string(),
+                        "12".to_string(),
+                    ],
+                })
+        );
+    }
+
+    #[test]
+    fn split_words() {
+        check!(
+            split_shell_words(r#""say \"hi\"" some(1)"#).unwrap()
+                == vec![r#""say \"hi\"""#, "some(1)"]
+        );
+        check!(split_shell_words(r#""unterminated"#).is_err());
+        check!(split_shell_words("[1, 2").is_err());
+        check!(split_shell_words("[1, 2)").is_err());
+    }
+
+    #[test]
+    fn complete_functions() {
+        let function_names = vec![
+            "app:main/api.{add-item}".to_string(),
+            "app:main/api.{get-items}".to_string(),
+            "run".to_string(),
+        ];
+
+        check!(
+            complete_function_name("app:main/api.{a", 15, &function_names)
+                == (0, vec!["app:main/api.{add-item}".to_string()])
+        );
+        check!(
+            complete_function_name("get", 3, &function_names)
+                == (0, vec!["app:main/api.{get-items}".to_string()])
+        );
+        check!(complete_function_name(" r", 2, &function_names) == (1, vec!["run".to_string()]));
+        check!(complete_function_name("run 1", 5, &function_names) == (5, vec![]));
+        check!(
+            history_file_name(
+                &ComponentName("app:main".to_string()),
+                &WorkerName("worker/1".to_string())
+            ) == "app_main.worker_1.history"
+        );
+    }
+}
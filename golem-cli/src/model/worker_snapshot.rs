@@ -0,0 +1,122 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::{ComponentName, WorkerName};
+use golem_client::model::PublicOplogEntry;
+use golem_common::model::public_oplog::ExportedFunctionInvokedParameters;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Exported state of a worker, which can be used to recreate it on another component or profile
+/// by replaying the recorded invocations
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerSnapshot {
+    pub component_name: ComponentName,
+    pub component_version: u64,
+    pub worker_name: WorkerName,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub oplog_index: u64,
+    pub oplog: Vec<WorkerSnapshotOplogEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerSnapshotOplogEntry {
+    pub oplog_index: u64,
+    pub entry: PublicOplogEntry,
+}
+
+impl WorkerSnapshot {
+    /// Invocations recorded in the snapshot, in the order of their execution
+    pub fn invocations(&self) -> impl Iterator<Item = &ExportedFunctionInvokedParameters> {
+        self.oplog
+            .iter()
+            .filter(|entry| entry.oplog_index <= self.oplog_index)
+            .filter_map(|entry| match &entry.entry {
+                PublicOplogEntry::ExportedFunctionInvoked(params) => Some(params),
+                _ => None,
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_snapshot::{WorkerSnapshot, WorkerSnapshotOplogEntry};
+    use crate::model::{ComponentName, WorkerName};
+    use assert2::check;
+    use golem_client::model::PublicOplogEntry;
+    use golem_common::model::invocation_context::TraceId;
+    use golem_common::model::public_oplog::ExportedFunctionInvokedParameters;
+    use golem_common::model::{IdempotencyKey, Timestamp};
+    use std::collections::HashMap;
+    use test_r::test;
+
+    fn snapshot(oplog_index: u64, oplog: Vec<WorkerSnapshotOplogEntry>) -> WorkerSnapshot {
+        WorkerSnapshot {
+            component_name: ComponentName("app:main".to_string()),
+            component_version: 3,
+            worker_name: WorkerName("worker-1".to_string()),
+            args: vec!["arg".to_string()],
+            env: HashMap::from([("KEY".to_string(), "value".to_string())]),
+            oplog_index,
+            oplog,
+        }
+    }
+
+    fn invoked(oplog_index: u64, function_name: &str) -> WorkerSnapshotOplogEntry {
+        WorkerSnapshotOplogEntry {
+            oplog_index,
+            entry: PublicOplogEntry::ExportedFunctionInvoked(ExportedFunctionInvokedParameters {
+                timestamp: Timestamp::now_utc(),
+                function_name: function_name.to_string(),
+                request: vec![],
+                idempotency_key: IdempotencyKey::new(format!("key-{oplog_index}")),
+                trace_id: TraceId::generate(),
+                trace_states: vec![],
+                invocation_context: vec![],
+            }),
+        }
+    }
+
+    #[test]
+    fn worker_snapshot_roundtrip() {
+        let snapshot = snapshot(12, vec![]);
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let parsed: WorkerSnapshot = serde_json::from_str(&json).unwrap();
+
+        check!(parsed == snapshot);
+        check!(parsed.invocations().count() == 0);
+    }
+
+    #[test]
+    fn worker_snapshot_invocations_up_to_oplog_index() {
+        let snapshot = snapshot(
+            4,
+            vec![
+                invoked(2, "app:main/api.{first}"),
+                invoked(4, "app:main/api.{second}"),
+                invoked(6, "app:main/api.{third}"),
+            ],
+        );
+
+        let function_names = snapshot
+            .invocations()
+            .map(|invocation| invocation.function_name.as_str())
+            .collect::<Vec<_>>();
+        check!(function_names == vec!["app:main/api.{first}", "app:main/api.{second}"]);
+    }
+}
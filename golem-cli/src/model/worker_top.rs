@@ -0,0 +1,383 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::model::text::fmt::{format_binary_size, format_status};
+use crate::model::{ComponentName, WorkerMetadata};
+use colored::Colorize;
+use golem_client::model::WorkerStatus;
+use std::cmp::Ordering;
+use uuid::Uuid;
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WorkerTopSortBy {
+    /// Component and worker name
+    #[default]
+    Name,
+    /// Worker status
+    Status,
+    /// Component version
+    Version,
+    /// Pending invocation count
+    Pending,
+    /// Retry count
+    Retries,
+    /// Total linear memory size
+    Memory,
+}
+
+impl WorkerTopSortBy {
+    pub fn next(self) -> Self {
+        match self {
+            WorkerTopSortBy::Name => WorkerTopSortBy::Status,
+            WorkerTopSortBy::Status => WorkerTopSortBy::Version,
+            WorkerTopSortBy::Version => WorkerTopSortBy::Pending,
+            WorkerTopSortBy::Pending => WorkerTopSortBy::Retries,
+            WorkerTopSortBy::Retries => WorkerTopSortBy::Memory,
+            WorkerTopSortBy::Memory => WorkerTopSortBy::Name,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            WorkerTopSortBy::Name => "name",
+            WorkerTopSortBy::Status => "status",
+            WorkerTopSortBy::Version => "version",
+            WorkerTopSortBy::Pending => "pending",
+            WorkerTopSortBy::Retries => "retries",
+            WorkerTopSortBy::Memory => "memory",
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct WorkerTopRow {
+    pub component_name: ComponentName,
+    pub component_id: Uuid,
+    pub worker_name: String,
+    pub status: WorkerStatus,
+    pub component_version: u64,
+    pub pending_invocation_count: u64,
+    pub retry_count: u64,
+    pub memory: u64,
+    pub last_error: Option<String>,
+}
+
+impl From<&WorkerMetadata> for WorkerTopRow {
+    fn from(metadata: &WorkerMetadata) -> Self {
+        Self {
+            component_name: metadata.component_name.clone(),
+            component_id: metadata.worker_id.component_id.0,
+            worker_name: metadata.worker_id.worker_name.clone(),
+            status: metadata.status.clone(),
+            component_version: metadata.component_version,
+            pending_invocation_count: metadata.pending_invocation_count,
+            retry_count: metadata.retry_count,
+            memory: metadata.total_linear_memory_size,
+            last_error: metadata.last_error.clone(),
+        }
+    }
+}
+
+/// State of the worker dashboard, rendered as plain terminal lines
+pub struct WorkerTop {
+    pub title: String,
+    pub sort_by: WorkerTopSortBy,
+    pub descending: bool,
+    pub message: Option<String>,
+    rows: Vec<WorkerTopRow>,
+    selected: usize,
+}
+
+impl WorkerTop {
+    pub fn new(title: String, sort_by: WorkerTopSortBy) -> Self {
+        Self {
+            title,
+            sort_by,
+            descending: false,
+            message: None,
+            rows: vec![],
+            selected: 0,
+        }
+    }
+
+    /// Replaces the rows, keeping the selection on the same worker if it is still listed
+    pub fn set_rows(&mut self, rows: Vec<WorkerTopRow>) {
+        let selected = self.selected_key();
+        self.rows = rows;
+        self.sort(selected);
+    }
+
+    pub fn rows(&self) -> &[WorkerTopRow] {
+        &self.rows
+    }
+
+    pub fn selected(&self) -> Option<&WorkerTopRow> {
+        self.rows.get(self.selected)
+    }
+
+    pub fn select_next(&mut self) {
+        if self.selected + 1 < self.rows.len() {
+            self.selected += 1;
+        }
+    }
+
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn next_sort(&mut self) {
+        self.sort_by = self.sort_by.next();
+        let selected = self.selected_key();
+        self.sort(selected);
+    }
+
+    pub fn toggle_order(&mut self) {
+        self.descending = !self.descending;
+        let selected = self.selected_key();
+        self.sort(selected);
+    }
+
+    fn selected_key(&self) -> Option<(Uuid, String)> {
+        self.selected()
+            .map(|row| (row.component_id, row.worker_name.clone()))
+    }
+
+    fn sort(&mut self, selected: Option<(Uuid, String)>) {
+        let by_name = |a: &WorkerTopRow, b: &WorkerTopRow| {
+            a.component_name
+                .0
+                .cmp(&b.component_name.0)
+                .then_with(|| a.worker_name.cmp(&b.worker_name))
+        };
+        let sort_by = self.sort_by;
+        let descending = self.descending;
+
+        self.rows.sort_by(|a, b| {
+            let ordering = match sort_by {
+                WorkerTopSortBy::Name => Ordering::Equal,
+                WorkerTopSortBy::Status => a.status.to_string().cmp(&b.status.to_string()),
+                WorkerTopSortBy::Version => a.component_version.cmp(&b.component_version),
+                WorkerTopSortBy::Pending => {
+                    a.pending_invocation_count.cmp(&b.pending_invocation_count)
+                }
+                WorkerTopSortBy::Retries => a.retry_count.cmp(&b.retry_count),
+                WorkerTopSortBy::Memory => a.memory.cmp(&b.memory),
+            }
+            .then_with(|| by_name(a, b));
+
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+
+        self.selected = selected
+            .and_then(|(component_id, worker_name)| {
+                self.rows.iter().position(|row| {
+                    row.component_id == component_id && row.worker_name == worker_name
+                })
+            })
+            .unwrap_or(self.selected)
+            .min(self.rows.len().saturating_sub(1));
+    }
+
+    pub fn render(&self, width: usize, height: usize) -> Vec<String> {
+        const HEADERS: [&str; 8] = [
+            "Component",
+            "Worker",
+            "Status",
+            "Version",
+            "Pending",
+            "Retries",
+            "Memory",
+            "Last error",
+        ];
+
+        let cells = self
+            .rows
+            .iter()
+            .map(|row| {
+                [
+                    row.component_name.0.clone(),
+                    row.worker_name.clone(),
+                    row.status.to_string(),
+                    row.component_version.to_string(),
+                    row.pending_invocation_count.to_string(),
+                    row.retry_count.to_string(),
+                    format_binary_size(&row.memory),
+                    row.last_error
+                        .as_deref()
+                        .and_then(|error| error.lines().next())
+                        .unwrap_or_default()
+                        .to_string(),
+                ]
+            })
+            .collect::<Vec<_>>();
+
+        let mut widths = HEADERS.map(|header| header.chars().count());
+        for row in &cells {
+            for (idx, cell) in row.iter().enumerate() {
+                widths[idx] = widths[idx].max(cell.chars().count());
+            }
+        }
+
+        let mut lines = vec![
+            truncate(
+                &format!(
+                    "{} | workers: {} | sort: {} {}",
+                    self.title,
+                    self.rows.len(),
+                    self.sort_by.as_str(),
+                    if self.descending { "desc" } else { "asc" },
+                ),
+                width,
+            )
+            .bold()
+            .to_string(),
+            truncate(
+                "up/down: select | s: sort | o: order | i: interrupt | r: resume | enter: stream | q: quit",
+                width,
+            ),
+            truncate(self.message.as_deref().unwrap_or_default(), width)
+                .yellow()
+                .to_string(),
+            truncate(&pad_row(&HEADERS.map(|header| header.to_string()), &widths), width)
+                .bold()
+                .to_string(),
+        ];
+
+        let visible_rows = height.saturating_sub(lines.len()).max(1);
+        let first_visible = (self.selected + 1).saturating_sub(visible_rows);
+        for (idx, row) in cells
+            .iter()
+            .enumerate()
+            .skip(first_visible)
+            .take(visible_rows)
+        {
+            let line = truncate(&pad_row(row, &widths), width);
+            if idx == self.selected {
+                lines.push(line.reversed().to_string());
+            } else {
+                lines.push(colorize_status(line, &self.rows[idx].status, &widths));
+            }
+        }
+
+        lines
+    }
+}
+
+fn pad_row(cells: &[String; 8], widths: &[usize; 8]) -> String {
+    cells
+        .iter()
+        .zip(widths)
+        .map(|(cell, width)| format!("{cell:<width$}"))
+        .collect::<Vec<_>>()
+        .join("  ")
+        .trim_end()
+        .to_string()
+}
+
+/// Colors the status column of an already padded and truncated row, if it is fully visible
+fn colorize_status(line: String, status: &WorkerStatus, widths: &[usize; 8]) -> String {
+    let status_name = status.to_string();
+    let start = widths[0] + widths[1] + 4;
+    let end = start + status_name.chars().count();
+    if line.chars().count() < end {
+        return line;
+    }
+
+    let prefix = line.chars().take(start).collect::<String>();
+    let suffix = line.chars().skip(end).collect::<String>();
+    format!("{prefix}{}{suffix}", format_status(status))
+}
+
+fn truncate(line: &str, width: usize) -> String {
+    line.chars().take(width).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::worker_top::{WorkerTop, WorkerTopRow, WorkerTopSortBy};
+    use crate::model::ComponentName;
+    use assert2::check;
+    use golem_client::model::WorkerStatus;
+    use test_r::test;
+    use uuid::Uuid;
+
+    fn row(worker_name: &str, status: WorkerStatus, retry_count: u64) -> WorkerTopRow {
+        WorkerTopRow {
+            component_name: ComponentName("app:main".to_string()),
+            component_id: Uuid::nil(),
+            worker_name: worker_name.to_string(),
+            status,
+            component_version: 1,
+            pending_invocation_count: 0,
+            retry_count,
+            memory: 1024,
+            last_error: None,
+        }
+    }
+
+    #[test]
+    fn sort_and_select() {
+        let mut top = WorkerTop::new("app:main".to_string(), WorkerTopSortBy::Name);
+        top.set_rows(vec![
+            row("c", WorkerStatus::Idle, 0),
+            row("a", WorkerStatus::Running, 2),
+            row("b", WorkerStatus::Failed, 1),
+        ]);
+
+        let names = |top: &WorkerTop| {
+            top.rows()
+                .iter()
+                .map(|row| row.worker_name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        check!(names(&top) == vec!["a", "b", "c"]);
+        top.select_next();
+        check!(top.selected().unwrap().worker_name == "b");
+
+        top.sort_by = WorkerTopSortBy::Pending;
+        top.next_sort();
+        check!(top.sort_by == WorkerTopSortBy::Retries);
+        check!(names(&top) == vec!["c", "b", "a"]);
+        check!(top.selected().unwrap().worker_name == "b");
+
+        top.toggle_order();
+        check!(names(&top) == vec!["a", "b", "c"]);
+
+        top.set_rows(vec![row("a", WorkerStatus::Running, 2)]);
+        check!(top.selected().unwrap().worker_name == "a");
+    }
+
+    #[test]
+    fn render() {
+        let mut top = WorkerTop::new("app:main".to_string(), WorkerTopSortBy::Name);
+        top.set_rows(
+            (0..10)
+                .map(|idx| row(&format!("worker-{idx}"), WorkerStatus::Idle, 0))
+                .collect(),
+        );
+        for _ in 0..9 {
+            top.select_next();
+        }
+
+        let lines = top.render(40, 8);
+        check!(lines.len() == 8);
+        check!(lines.iter().any(|line| line.contains("worker-9")));
+        check!(!lines.iter().any(|line| line.contains("worker-0")));
+    }
+}
@@ -0,0 +1,691 @@
+// Copyright 2024-2025 Golem Cloud
+//
+// Licensed under the Golem Source License v1.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://license.golem.cloud/LICENSE
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::context::check_http_response_success;
+use crate::log::LogColorize;
+use crate::model::component::ComponentWasmMetadataView;
+use anyhow::{anyhow, bail, Context};
+use base64::prelude::*;
+use reqwest::header::{ACCEPT, AUTHORIZATION, CONTENT_TYPE, LOCATION, WWW_AUTHENTICATE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::Mutex;
+use tracing::debug;
+use url::Url;
+
+pub const OCI_USERNAME_ENV_VAR: &str = "GOLEM_OCI_USERNAME";
+pub const OCI_PASSWORD_ENV_VAR: &str = "GOLEM_OCI_PASSWORD";
+
+const OCI_SCHEME_PREFIX: &str = "oci://";
+const OCI_MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+const DOCKER_MANIFEST_MEDIA_TYPE: &str = "application/vnd.docker.distribution.manifest.v2+json";
+const WASM_CONFIG_MEDIA_TYPE: &str = "application/vnd.wasm.config.v0+json";
+const WASM_LAYER_MEDIA_TYPE: &str = "application/wasm";
+const DOCKER_CONTENT_DIGEST: &str = "Docker-Content-Digest";
+
+/// Reference to an OCI artifact in `oci://<registry>/<repository>[:<tag>][@<digest>]` form
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OciReference {
+    pub registry: String,
+    pub repository: String,
+    pub tag: Option<String>,
+    pub digest: Option<String>,
+}
+
+impl OciReference {
+    pub fn is_oci_url(url: &str) -> bool {
+        url.starts_with(OCI_SCHEME_PREFIX)
+    }
+
+    /// Returns the same repository pinned to the digest, keeping the tag for display
+    pub fn with_digest(&self, digest: impl Into<String>) -> Self {
+        Self {
+            digest: Some(digest.into()),
+            ..self.clone()
+        }
+    }
+
+    /// Tag or digest used for manifest requests, digests take precedence
+    pub fn manifest_reference(&self) -> &str {
+        self.digest
+            .as_deref()
+            .or(self.tag.as_deref())
+            .unwrap_or("latest")
+    }
+
+    fn manifest_url(&self) -> String {
+        format!(
+            "{}/manifests/{}",
+            self.base_url(),
+            self.manifest_reference()
+        )
+    }
+
+    // NOTE: local registries (e.g. a registry container used for testing) are accessed using plain HTTP
+    fn base_url(&self) -> String {
+        let host = match self.registry.rsplit_once(':') {
+            Some((host, port)) if !port.contains(']') => host,
+            _ => self.registry.as_str(),
+        };
+        let scheme = match host {
+            "localhost" | "127.0.0.1" | "[::1]" => "http",
+            _ => "https",
+        };
+        format!("{scheme}://{}/v2/{}", self.registry, self.repository)
+    }
+}
+
+impl FromStr for OciReference {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let reference = s.strip_prefix(OCI_SCHEME_PREFIX).unwrap_or(s);
+
+        let Some((registry, rest)) = reference.split_once('/') else {
+            return Err(format!(
+                "Missing repository in OCI reference {s}, expected oci://<registry>/<repository>[:<tag>][@<digest>]"
+            ));
+        };
+
+        let (rest, digest) = match rest.split_once('@') {
+            Some((rest, digest)) => {
+                if !digest.starts_with("sha256:") {
+                    return Err(format!(
+                        "Unsupported digest in OCI reference {s}, expected sha256:<hex>"
+                    ));
+                }
+                (rest, Some(digest.to_string()))
+            }
+            None => (rest, None),
+        };
+
+        let last_segment_start = rest.rfind('/').map(|idx| idx + 1).unwrap_or(0);
+        let (repository, tag) = match rest[last_segment_start..].rsplit_once(':') {
+            Some((_, tag)) => (&rest[..rest.len() - tag.len() - 1], Some(tag.to_string())),
+            None => (rest, None),
+        };
+
+        if registry.is_empty() || repository.is_empty() {
+            return Err(format!(
+                "Invalid OCI reference {s}, expected oci://<registry>/<repository>[:<tag>][@<digest>]"
+            ));
+        }
+        if tag.as_deref() == Some("") {
+            return Err(format!("Empty tag in OCI reference {s}"));
+        }
+
+        Ok(Self {
+            registry: registry.to_string(),
+            repository: repository.to_string(),
+            tag,
+            digest,
+        })
+    }
+}
+
+impl Display for OciReference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{OCI_SCHEME_PREFIX}{}/{}",
+            self.registry, self.repository
+        )?;
+        if let Some(tag) = &self.tag {
+            write!(f, ":{tag}")?;
+        }
+        if let Some(digest) = &self.digest {
+            write!(f, "@{digest}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OciDescriptor {
+    media_type: String,
+    digest: String,
+    size: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OciManifest {
+    schema_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    media_type: Option<String>,
+    config: OciDescriptor,
+    layers: Vec<OciDescriptor>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty", default)]
+    annotations: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WasmConfig {
+    created: String,
+    architecture: String,
+    os: String,
+    layer_digests: Vec<String>,
+}
+
+/// Content addressed layer of an OCI artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OciLayer {
+    pub digest: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct TokenResponse {
+    token: Option<String>,
+    access_token: Option<String>,
+}
+
+/// Minimal OCI distribution client for pulling and pushing single layer WASM artifacts,
+/// following the CNCF wasm OCI artifact layout.
+///
+/// Registries requiring authentication are accessed using the credentials from the
+/// `GOLEM_OCI_USERNAME` and `GOLEM_OCI_PASSWORD` environment variables.
+pub struct OciClient {
+    client: reqwest::Client,
+    authorization: Mutex<Option<String>>,
+}
+
+impl OciClient {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self {
+            client,
+            authorization: Mutex::new(None),
+        }
+    }
+
+    /// Returns the content of the WASM layer, or the only layer of the referenced artifact
+    pub async fn pull(&self, reference: &OciReference) -> anyhow::Result<Vec<u8>> {
+        let layer = self.wasm_layer(reference).await?;
+        let response = self.blob(reference, &layer.digest).await?;
+        let bytes = response
+            .bytes()
+            .await
+            .with_context(|| anyhow!("Failed to download OCI blob: {reference}"))?;
+        check_digest(reference, &layer.digest, &bytes)?;
+
+        Ok(bytes.into())
+    }
+
+    /// Returns the digest of the manifest currently referenced by the tag (or digest) without
+    /// downloading the artifact, used to detect moved tags
+    pub async fn resolve_digest(&self, reference: &OciReference) -> anyhow::Result<String> {
+        if let Some(digest) = &reference.digest {
+            return Ok(digest.clone());
+        }
+
+        let manifest_url = reference.manifest_url();
+        let response = self
+            .send(reference, false, |client| {
+                client.head(&manifest_url).header(ACCEPT, manifest_accept())
+            })
+            .await?;
+        let response = check_http_response_success(response).await?;
+        if let Some(digest) = response
+            .headers()
+            .get(DOCKER_CONTENT_DIGEST)
+            .and_then(|digest| digest.to_str().ok())
+        {
+            return Ok(digest.to_string());
+        }
+
+        // NOTE: registries are not required to return the digest header, in that case the
+        //       manifest is downloaded and hashed
+        Ok(sha256_digest(&self.manifest_bytes(reference).await?))
+    }
+
+    /// Returns the descriptor of the WASM layer, or the only layer of the referenced artifact,
+    /// downloading only the manifest
+    pub async fn wasm_layer(&self, reference: &OciReference) -> anyhow::Result<OciLayer> {
+        let manifest_bytes = self.manifest_bytes(reference).await?;
+        let manifest: OciManifest = serde_json::from_slice(&manifest_bytes)
+            .with_context(|| anyhow!("Failed to parse OCI manifest: {reference}"))?;
+
+        let layer = match manifest
+            .layers
+            .iter()
+            .find(|layer| layer.media_type == WASM_LAYER_MEDIA_TYPE)
+        {
+            Some(layer) => layer,
+            None => match manifest.layers.as_slice() {
+                [layer] => layer,
+                _ => bail!(
+                    "Expected a single layer or a {} layer in OCI artifact {}, found {} layers",
+                    WASM_LAYER_MEDIA_TYPE,
+                    reference.to_string().log_color_error_highlight(),
+                    manifest.layers.len()
+                ),
+            },
+        };
+
+        Ok(OciLayer {
+            digest: layer.digest.clone(),
+            size: layer.size,
+        })
+    }
+
+    /// Returns the successful response for the blob, for streaming large layers
+    pub async fn blob(
+        &self,
+        reference: &OciReference,
+        digest: &str,
+    ) -> anyhow::Result<reqwest::Response> {
+        let blob_url = format!("{}/blobs/{digest}", reference.base_url());
+        let response = self
+            .send(reference, false, |client| client.get(&blob_url))
+            .await?;
+        check_http_response_success(response).await
+    }
+
+    async fn manifest_bytes(&self, reference: &OciReference) -> anyhow::Result<bytes::Bytes> {
+        let manifest_url = reference.manifest_url();
+        let response = self
+            .send(reference, false, |client| {
+                client.get(&manifest_url).header(ACCEPT, manifest_accept())
+            })
+            .await?;
+        let response = check_http_response_success(response).await?;
+        let manifest_bytes = response
+            .bytes()
+            .await
+            .with_context(|| anyhow!("Failed to download OCI manifest: {reference}"))?;
+        if let Some(digest) = &reference.digest {
+            check_digest(reference, digest, &manifest_bytes)?;
+        }
+        Ok(manifest_bytes)
+    }
+
+    /// Pushes the WASM component as a single layer artifact, returns the digest of the pushed manifest
+    pub async fn push_wasm(
+        &self,
+        reference: &OciReference,
+        wasm: Vec<u8>,
+        annotations: BTreeMap<String, String>,
+    ) -> anyhow::Result<String> {
+        if reference.digest.is_some() {
+            bail!(
+                "Cannot publish to OCI reference with digest: {}, use a tag instead",
+                reference.to_string().log_color_error_highlight()
+            );
+        }
+
+        let layer = OciDescriptor {
+            media_type: WASM_LAYER_MEDIA_TYPE.to_string(),
+            digest: sha256_digest(&wasm),
+            size: wasm.len() as u64,
+        };
+        let config = serde_json::to_vec(&WasmConfig {
+            created: annotations
+                .get("org.opencontainers.image.created")
+                .cloned()
+                .unwrap_or_else(|| chrono::Utc::now().to_rfc3339()),
+            architecture: "wasm".to_string(),
+            os: "wasip2".to_string(),
+            layer_digests: vec![layer.digest.clone()],
+        })?;
+        let config_descriptor = OciDescriptor {
+            media_type: WASM_CONFIG_MEDIA_TYPE.to_string(),
+            digest: sha256_digest(&config),
+            size: config.len() as u64,
+        };
+
+        self.push_blob(reference, &config_descriptor.digest, config)
+            .await?;
+        self.push_blob(reference, &layer.digest, wasm).await?;
+
+        let manifest = serde_json::to_vec(&OciManifest {
+            schema_version: 2,
+            media_type: Some(OCI_MANIFEST_MEDIA_TYPE.to_string()),
+            config: config_descriptor,
+            layers: vec![layer],
+            annotations,
+        })?;
+        let manifest_digest = sha256_digest(&manifest);
+
+        let manifest_url = reference.manifest_url();
+        let response = self
+            .send(reference, true, |client| {
+                client
+                    .put(&manifest_url)
+                    .header(CONTENT_TYPE, OCI_MANIFEST_MEDIA_TYPE)
+                    .body(manifest.clone())
+            })
+            .await?;
+        check_http_response_success(response).await?;
+
+        Ok(manifest_digest)
+    }
+
+    async fn push_blob(
+        &self,
+        reference: &OciReference,
+        digest: &str,
+        content: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let base_url = reference.base_url();
+
+        let blob_url = format!("{base_url}/blobs/{digest}");
+        let response = self
+            .send(reference, true, |client| client.head(&blob_url))
+            .await?;
+        if response.status().is_success() {
+            debug!(digest, "OCI blob already exists");
+            return Ok(());
+        }
+
+        let upload_url = format!("{base_url}/blobs/uploads/");
+        let response = self
+            .send(reference, true, |client| client.post(&upload_url))
+            .await?;
+        let response = check_http_response_success(response).await?;
+        let location = response
+            .headers()
+            .get(LOCATION)
+            .and_then(|location| location.to_str().ok())
+            .ok_or_else(|| anyhow!("Missing upload location for OCI blob: {reference}"))?;
+
+        let mut location = Url::parse(&upload_url)?
+            .join(location)
+            .with_context(|| anyhow!("Invalid upload location for OCI blob: {location}"))?;
+        location.query_pairs_mut().append_pair("digest", digest);
+
+        let response = self
+            .send(reference, true, |client| {
+                client
+                    .put(location.clone())
+                    .header(CONTENT_TYPE, "application/octet-stream")
+                    .body(content.clone())
+            })
+            .await?;
+        check_http_response_success(response).await?;
+
+        Ok(())
+    }
+
+    /// Sends the request, and retries it once with authorization if the registry requires it
+    async fn send(
+        &self,
+        reference: &OciReference,
+        push: bool,
+        request: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    ) -> anyhow::Result<reqwest::Response> {
+        let authorization = self.authorization.lock().unwrap().clone();
+        let response = with_authorization(request(&self.client), authorization.as_deref())
+            .send()
+            .await
+            .with_context(|| anyhow!("Failed to send request to OCI registry: {reference}"))?;
+
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        let authorization = self.authorize(reference, push, &response).await?;
+        let response = with_authorization(request(&self.client), Some(&authorization))
+            .send()
+            .await
+            .with_context(|| anyhow!("Failed to send request to OCI registry: {reference}"))?;
+        *self.authorization.lock().unwrap() = Some(authorization);
+
+        Ok(response)
+    }
+
+    async fn authorize(
+        &self,
+        reference: &OciReference,
+        push: bool,
+        response: &reqwest::Response,
+    ) -> anyhow::Result<String> {
+        let challenge = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|challenge| challenge.to_str().ok())
+            .unwrap_or_default();
+        let (scheme, params) = parse_challenge(challenge);
+        let credentials = credentials_from_env();
+
+        match scheme.to_ascii_lowercase().as_str() {
+            "basic" => {
+                let Some((username, password)) = credentials else {
+                    bail!(
+                        "OCI registry {} requires authentication, set the {} and {} environment variables",
+                        reference.registry.log_color_error_highlight(),
+                        OCI_USERNAME_ENV_VAR,
+                        OCI_PASSWORD_ENV_VAR
+                    );
+                };
+                Ok(format!(
+                    "Basic {}",
+                    BASE64_STANDARD.encode(format!("{username}:{password}"))
+                ))
+            }
+            "bearer" => {
+                let realm = params.get("realm").ok_or_else(|| {
+                    anyhow!("Missing realm in OCI registry authentication challenge: {challenge}")
+                })?;
+                let scope = format!(
+                    "repository:{}:{}",
+                    reference.repository,
+                    if push { "pull,push" } else { "pull" }
+                );
+
+                let mut query = vec![("scope", scope.as_str())];
+                if let Some(service) = params.get("service") {
+                    query.push(("service", service.as_str()));
+                }
+                let mut request = self.client.get(realm).query(&query);
+                if let Some((username, password)) = credentials {
+                    request = request.basic_auth(username, Some(password));
+                }
+
+                debug!(realm, scope, "Requesting OCI registry token");
+                let response = request.send().await.with_context(|| {
+                    anyhow!("Failed to request OCI registry token from {realm}")
+                })?;
+                let response = check_http_response_success(response).await?;
+                let token: TokenResponse = response
+                    .json()
+                    .await
+                    .with_context(|| anyhow!("Failed to parse OCI registry token response"))?;
+
+                Ok(format!(
+                    "Bearer {}",
+                    token
+                        .token
+                        .or(token.access_token)
+                        .ok_or_else(|| anyhow!("Missing token in OCI registry token response"))?
+                ))
+            }
+            _ => bail!(
+                "Unsupported OCI registry authentication challenge for {}: {}",
+                reference.registry.log_color_error_highlight(),
+                challenge
+            ),
+        }
+    }
+}
+
+/// Standard OCI annotations based on the metadata embedded in the component
+pub fn oci_annotations(metadata: &ComponentWasmMetadataView) -> BTreeMap<String, String> {
+    [
+        ("title", &metadata.name),
+        ("version", &metadata.version),
+        ("authors", &metadata.authors),
+        ("description", &metadata.description),
+        ("licenses", &metadata.licenses),
+        ("source", &metadata.source),
+        ("url", &metadata.homepage),
+        ("revision", &metadata.revision),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| {
+        value
+            .as_ref()
+            .map(|value| (format!("org.opencontainers.image.{key}"), value.clone()))
+    })
+    .chain([(
+        "org.opencontainers.image.created".to_string(),
+        chrono::Utc::now().to_rfc3339(),
+    )])
+    .collect()
+}
+
+fn manifest_accept() -> String {
+    format!("{OCI_MANIFEST_MEDIA_TYPE}, {DOCKER_MANIFEST_MEDIA_TYPE}")
+}
+
+fn with_authorization(
+    request: reqwest::RequestBuilder,
+    authorization: Option<&str>,
+) -> reqwest::RequestBuilder {
+    match authorization {
+        Some(authorization) => request.header(AUTHORIZATION, authorization),
+        None => request,
+    }
+}
+
+fn credentials_from_env() -> Option<(String, String)> {
+    Some((
+        std::env::var(OCI_USERNAME_ENV_VAR).ok()?,
+        std::env::var(OCI_PASSWORD_ENV_VAR).ok()?,
+    ))
+}
+
+pub fn sha256_digest(content: &[u8]) -> String {
+    format!("sha256:{:x}", Sha256::digest(content))
+}
+
+fn check_digest(reference: &OciReference, digest: &str, content: &[u8]) -> anyhow::Result<()> {
+    let actual_digest = sha256_digest(content);
+    if actual_digest != digest {
+        bail!(
+            "Digest mismatch for OCI artifact {}, expected {}, got {}",
+            reference.to_string().log_color_error_highlight(),
+            digest,
+            actual_digest
+        );
+    }
+    Ok(())
+}
+
+/// Parses `<scheme> key="value",key=value` style WWW-Authenticate challenges
+fn parse_challenge(challenge: &str) -> (String, BTreeMap<String, String>) {
+    let (scheme, rest) = challenge
+        .trim()
+        .split_once(' ')
+        .unwrap_or((challenge.trim(), ""));
+
+    let mut params = BTreeMap::new();
+    let mut chars = rest.chars().peekable();
+    loop {
+        while chars.next_if(|c| *c == ',' || c.is_whitespace()).is_some() {}
+
+        let key = std::iter::from_fn(|| chars.next_if(|c| *c != '=')).collect::<String>();
+        if key.is_empty() || chars.next().is_none() {
+            break;
+        }
+
+        let value = if chars.next_if_eq(&'"').is_some() {
+            let value = std::iter::from_fn(|| chars.next_if(|c| *c != '"')).collect::<String>();
+            chars.next();
+            value
+        } else {
+            std::iter::from_fn(|| chars.next_if(|c| *c != ',')).collect::<String>()
+        };
+
+        params.insert(key.trim().to_ascii_lowercase(), value);
+    }
+
+    (scheme.to_string(), params)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::oci::{parse_challenge, sha256_digest, OciReference};
+    use assert2::{assert, let_assert};
+    use std::str::FromStr;
+    use test_r::test;
+
+    #[test]
+    fn parse_oci_reference() {
+        let_assert!(
+            Ok(reference) = OciReference::from_str("oci://localhost:5000/libs/counter:1.0.0")
+        );
+        assert!(reference.registry == "localhost:5000");
+        assert!(reference.repository == "libs/counter");
+        assert!(reference.tag.as_deref() == Some("1.0.0"));
+        assert!(reference.digest.is_none());
+        assert!(reference.base_url() == "http://localhost:5000/v2/libs/counter");
+        assert!(reference.to_string() == "oci://localhost:5000/libs/counter:1.0.0");
+
+        let_assert!(
+            Ok(reference) = OciReference::from_str("oci://ghcr.io/golemcloud/counter@sha256:abcd")
+        );
+        assert!(reference.repository == "golemcloud/counter");
+        assert!(reference.tag.is_none());
+        assert!(reference.manifest_reference() == "sha256:abcd");
+        assert!(reference.base_url() == "https://ghcr.io/v2/golemcloud/counter");
+
+        let_assert!(Ok(reference) = OciReference::from_str("ghcr.io/golemcloud/counter"));
+        assert!(reference.manifest_reference() == "latest");
+
+        assert!(OciReference::from_str("oci://ghcr.io").is_err());
+        assert!(OciReference::from_str("oci://ghcr.io/counter@md5:abcd").is_err());
+    }
+
+    #[test]
+    fn parse_bearer_challenge() {
+        let (scheme, params) = parse_challenge(
+            r#"Bearer realm="https://ghcr.io/token",service="ghcr.io",scope="repository:golemcloud/counter:pull,push""#,
+        );
+
+        assert!(scheme == "Bearer");
+        assert!(params.get("realm").map(|s| s.as_str()) == Some("https://ghcr.io/token"));
+        assert!(params.get("service").map(|s| s.as_str()) == Some("ghcr.io"));
+        assert!(
+            params.get("scope").map(|s| s.as_str())
+                == Some("repository:golemcloud/counter:pull,push")
+        );
+    }
+
+    #[test]
+    fn oci_reference_with_digest() {
+        let_assert!(
+            Ok(reference) = OciReference::from_str("oci://localhost:5000/libs/counter:1.0.0")
+        );
+        assert!(
+            reference.manifest_url() == "http://localhost:5000/v2/libs/counter/manifests/1.0.0"
+        );
+
+        let digest = sha256_digest(b"manifest");
+        let pinned = reference.with_digest(&digest);
+        assert!(pinned.tag.as_deref() == Some("1.0.0"));
+        assert!(pinned.manifest_reference() == digest);
+        assert!(
+            pinned.manifest_url()
+                == format!("http://localhost:5000/v2/libs/counter/manifests/{digest}")
+        );
+        assert!(pinned.to_string() == format!("oci://localhost:5000/libs/counter:1.0.0@{digest}"));
+        assert!(OciReference::from_str(&pinned.to_string()) == Ok(pinned));
+    }
+}
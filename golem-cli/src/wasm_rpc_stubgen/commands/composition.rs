@@ -1,16 +1,33 @@
 use crate::fs;
 use crate::fs::PathExtra;
 use crate::log::{log_warn_action, LogColorize};
-use anyhow::Context;
+use crate::model::app::PlugSelection;
+use anyhow::{anyhow, bail, Context};
+use indexmap::IndexMap;
+use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet};
 use std::path::{Path, PathBuf};
-use wac_graph::types::{Package, SubtypeChecker};
-use wac_graph::{CompositionGraph, EncodeOptions, PackageId, PlugError};
+use wac_graph::types::{BorrowedPackageKey, Package, SubtypeChecker};
+use wac_graph::{CompositionGraph, EncodeOptions, PackageId};
 
 pub async fn compose(
     source_wasm: &Path,
     stub_wasms: &[PathBuf],
     dest_wasm: &Path,
+) -> anyhow::Result<()> {
+    let plugs = stub_wasms
+        .iter()
+        .map(|stub_wasm| (stub_wasm.clone(), PlugSelection::default()))
+        .collect::<Vec<_>>();
+    compose_with_plug_selections(source_wasm, &plugs, dest_wasm).await
+}
+
+/// Same as [compose], but the imports plugged by each plug can be restricted to an allow-list of
+/// interfaces, and imports can be mapped to differently named exports
+pub async fn compose_with_plug_selections(
+    source_wasm: &Path,
+    plugs: &[(PathBuf, PlugSelection)],
+    dest_wasm: &Path,
 ) -> anyhow::Result<()> {
     // Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/src/commands/plug.rs
     // with allowing missing plugs (through the also customized plug function below)
@@ -26,15 +43,19 @@ pub async fn compose(
     let socket = graph.register_package(socket)?;
 
     let mut plug_packages = Vec::new();
-    for stub_wasm in stub_wasms {
+    for (plug_wasm, selection) in plugs {
         let plug_package = Package::from_file(
-            &stub_wasm.to_string_lossy(),
+            &plug_wasm.to_string_lossy(),
             None,
-            stub_wasm,
+            plug_wasm,
             graph.types_mut(),
         )?;
         let package_id = graph.register_package(plug_package)?;
-        plug_packages.push((stub_wasm.to_string_lossy().to_string(), package_id));
+        plug_packages.push((
+            plug_wasm.to_string_lossy().to_string(),
+            package_id,
+            selection,
+        ));
     }
 
     plug(&mut graph, plug_packages, socket)?;
@@ -47,85 +68,484 @@ pub async fn compose(
     Ok(())
 }
 
+/// Composes the component described by the WAC source, referenced packages are looked up by
+/// package name in `packages`
+pub fn compose_wac(
+    source_name: &str,
+    source: &str,
+    packages: &BTreeMap<String, PathBuf>,
+    dest_wasm: &Path,
+) -> anyhow::Result<()> {
+    // Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/src/commands/compose.rs
+    // using local packages only
+
+    let dest_wasm = PathExtra::new(dest_wasm);
+
+    let document = wac_parser::Document::parse(source)
+        .map_err(|err| anyhow!("Failed to parse WAC composition {source_name}: {err}"))?;
+
+    let mut package_bytes = IndexMap::new();
+    for (name, path) in packages {
+        package_bytes.insert(
+            BorrowedPackageKey::from_name_and_version(name, None),
+            fs::read(path).with_context(|| anyhow!("Failed to read WAC package {name}"))?,
+        );
+    }
+
+    let resolution = document
+        .resolve(package_bytes)
+        .map_err(|err| anyhow!("Failed to resolve WAC composition {source_name}: {err}"))?;
+
+    let bytes = resolution
+        .encode(EncodeOptions::default())
+        .map_err(|err| anyhow!("Failed to encode WAC composition {source_name}: {err}"))?;
+
+    fs::create_dir_all(dest_wasm.parent()?)?;
+    fs::write(dest_wasm, bytes)?;
+
+    Ok(())
+}
+
+/// Returns the names of the packages instantiated or imported by the WAC source
+pub fn wac_package_names(source_name: &str, source: &str) -> anyhow::Result<BTreeSet<String>> {
+    use wac_parser::ast::{Expr, ImportType, InstantiationArgument, PrimaryExpr, Statement};
+
+    fn collect_expr(expr: &Expr, package_names: &mut BTreeSet<String>) {
+        match &expr.primary {
+            PrimaryExpr::New(new_expr) => {
+                package_names.insert(new_expr.package.name.to_string());
+                for argument in &new_expr.arguments {
+                    if let InstantiationArgument::Named(argument) = argument {
+                        collect_expr(&argument.expr, package_names);
+                    }
+                }
+            }
+            PrimaryExpr::Nested(nested) => collect_expr(&nested.0, package_names),
+            PrimaryExpr::Ident(_) => {}
+        }
+    }
+
+    let document = wac_parser::Document::parse(source)
+        .map_err(|err| anyhow!("Failed to parse WAC composition {source_name}: {err}"))?;
+
+    // NOTE: packages referenced only by type statements are not collected, as component outputs
+    //       are used by instantiating or importing them
+    let mut package_names = BTreeSet::new();
+    for statement in &document.statements {
+        match statement {
+            Statement::Import(import) => {
+                if let ImportType::Package(path) = &import.ty {
+                    package_names.insert(path.name.to_string());
+                }
+            }
+            Statement::Let(let_statement) => collect_expr(&let_statement.expr, &mut package_names),
+            Statement::Export(export) => collect_expr(&export.expr, &mut package_names),
+            Statement::Type(_) => {}
+        }
+    }
+
+    Ok(package_names)
+}
+
 // Based on https://github.com/bytecodealliance/wac/blob/release-0.6.0/crates/wac-graph/src/plug.rs#L23
-// but instead of returning NoPlugError, it logs skipped instantiations
+// but instead of returning NoPlugError, it logs skipped instantiations, and applies the plug selections.
 fn plug(
     graph: &mut CompositionGraph,
-    plugs: Vec<(String, PackageId)>,
+    plugs: Vec<(String, PackageId, &PlugSelection)>,
     socket: PackageId,
-) -> Result<(), PlugError> {
+) -> anyhow::Result<()> {
+    let arguments = plug_arguments(graph, &plugs, socket)?;
+
     let socket_instantiation = graph.instantiate(socket);
+    let mut plug_instantiations = BTreeMap::<usize, _>::new();
+    for (import_name, argument) in &arguments {
+        let plug_instantiation = *plug_instantiations
+            .entry(argument.plug_idx)
+            .or_insert_with(|| graph.instantiate(plugs[argument.plug_idx].1));
+        let export = graph.alias_instance_export(plug_instantiation, &argument.export_name)?;
+        graph.set_instantiation_argument(socket_instantiation, import_name, export)?;
+    }
 
-    let mut requested_plugs = BTreeSet::<String>::new();
-    let mut plug_exports_to_plug = BTreeMap::<String, String>::new();
+    for (plug_idx, (plug_name, _, _)) in plugs.iter().enumerate() {
+        if !plug_instantiations.contains_key(&plug_idx) {
+            log_warn_action(
+                "Skipping",
+                format!("{}, not used", plug_name.log_color_highlight()),
+            );
+        }
+    }
 
-    for (plug_name, plug) in plugs {
-        requested_plugs.insert(plug_name.clone());
+    // Export all exports from the socket component.
+    for name in graph.types()[graph[socket].ty()]
+        .exports
+        .keys()
+        .cloned()
+        .collect::<Vec<_>>()
+    {
+        let export = graph.alias_instance_export(socket_instantiation, &name)?;
+        graph.export(export, &name)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct PlugArgument {
+    plug_idx: usize,
+    export_name: String,
+    explicit: bool,
+}
+
+/// Selects the plug export used for each plugged socket import.
+///
+/// Imports selected explicitly (by interface allow-list or mapping) take precedence over imports
+/// matched by name, and are validated with the SubtypeChecker. An import can only be selected
+/// explicitly by one plug. When multiple plugs export an import matched by name, the last one
+/// wins, same as with plain `wac plug`.
+fn plug_arguments(
+    graph: &CompositionGraph,
+    plugs: &[(String, PackageId, &PlugSelection)],
+    socket: PackageId,
+) -> anyhow::Result<BTreeMap<String, PlugArgument>> {
+    let socket_imports = graph.types()[graph[socket].ty()].imports.clone();
+    let mut arguments = BTreeMap::<String, PlugArgument>::new();
 
-        let mut plug_exports = Vec::new();
+    for (plug_idx, (plug_name, plug, selection)) in plugs.iter().enumerate() {
+        let plug_exports = graph.types()[graph[*plug].ty()].exports.clone();
         let mut cache = Default::default();
         let mut checker = SubtypeChecker::new(&mut cache);
-        for (name, plug_ty) in &graph.types()[graph[plug].ty()].exports {
-            if let Some(socket_ty) = graph.types()[graph[socket].ty()].imports.get(name) {
-                if checker
-                    .is_subtype(*plug_ty, graph.types(), *socket_ty, graph.types())
-                    .is_ok()
+
+        let mut candidates = Vec::<(String, String, bool)>::new();
+
+        for (import_name, export_name) in &selection.mappings {
+            if !socket_imports.contains_key(import_name) {
+                bail!(
+                    "Mapped import {} is not imported by the component, mapped to export {} of {}",
+                    import_name.log_color_error_highlight(),
+                    export_name.log_color_highlight(),
+                    plug_name.log_color_highlight()
+                );
+            }
+            if !plug_exports.contains_key(export_name) {
+                bail!(
+                    "Mapped export {} is not exported by {}, available exports: {}",
+                    export_name.log_color_error_highlight(),
+                    plug_name.log_color_highlight(),
+                    plug_exports.keys().join(", ")
+                );
+            }
+            candidates.push((import_name.clone(), export_name.clone(), true));
+        }
+
+        for interface in &selection.interfaces {
+            let matching_exports = plug_exports
+                .keys()
+                .filter(|export_name| {
+                    PlugSelection::interface_matches(interface, export_name)
+                        && socket_imports.contains_key(*export_name)
+                        && !selection.mappings.contains_key(*export_name)
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+            if matching_exports.is_empty() {
+                bail!(
+                    "Selected interface {} is not both imported by the component and exported by {}",
+                    interface.log_color_error_highlight(),
+                    plug_name.log_color_highlight()
+                );
+            }
+            for export_name in matching_exports {
+                candidates.push((export_name.clone(), export_name, true));
+            }
+        }
+
+        if selection.interfaces.is_empty() {
+            for export_name in plug_exports.keys() {
+                if socket_imports.contains_key(export_name)
+                    && !selection.mappings.contains_key(export_name)
                 {
-                    plug_exports.push(name.clone());
+                    candidates.push((export_name.clone(), export_name.clone(), false));
                 }
             }
         }
 
-        // Instantiate the plug component
-        let mut plug_instantiation = None;
-        for plug_export_name in plug_exports {
-            plug_exports_to_plug.insert(plug_export_name.clone(), plug_name.clone());
-
-            let plug_instantiation =
-                *plug_instantiation.get_or_insert_with(|| graph.instantiate(plug));
-            let export = graph
-                .alias_instance_export(plug_instantiation, &plug_export_name)
-                .map_err(|err| PlugError::GraphError { source: err.into() })?;
-            graph
-                .set_instantiation_argument(socket_instantiation, &plug_export_name, export)
-                .map_err(|err| PlugError::GraphError { source: err.into() })?;
+        for (import_name, export_name, explicit) in candidates {
+            let type_check = checker.is_subtype(
+                plug_exports[&export_name],
+                graph.types(),
+                socket_imports[&import_name],
+                graph.types(),
+            );
+            if let Err(err) = type_check {
+                if explicit {
+                    bail!(
+                        "Export {} of {} cannot be used for import {}: {:#}",
+                        export_name.log_color_error_highlight(),
+                        plug_name.log_color_highlight(),
+                        import_name.log_color_error_highlight(),
+                        err
+                    );
+                }
+                continue;
+            }
+
+            match arguments.get(&import_name) {
+                Some(existing) if existing.explicit && explicit => {
+                    bail!(
+                        "Import {} is selected from multiple dependencies: {} and {}",
+                        import_name.log_color_error_highlight(),
+                        plugs[existing.plug_idx].0.log_color_highlight(),
+                        plug_name.log_color_highlight()
+                    );
+                }
+                Some(existing) if existing.explicit => {}
+                _ => {
+                    arguments.insert(
+                        import_name,
+                        PlugArgument {
+                            plug_idx,
+                            export_name,
+                            explicit,
+                        },
+                    );
+                }
+            }
         }
     }
 
-    let unused_plugs = {
-        for (plug_export_name, _) in graph.get_instantiation_arguments(socket_instantiation) {
-            plug_exports_to_plug
-                .remove(plug_export_name)
-                .iter()
-                .for_each(|plug_name| {
-                    requested_plugs.remove(plug_name);
-                });
-        }
-        requested_plugs
+    Ok(arguments)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::model::app::PlugSelection;
+    use crate::wasm_rpc_stubgen::commands::composition::{
+        plug_arguments, wac_package_names, PlugArgument,
     };
+    use assert2::{check, let_assert};
+    use indoc::indoc;
+    use std::collections::{BTreeMap, BTreeSet};
+    use test_r::test;
+    use wac_graph::types::Package;
+    use wac_graph::{CompositionGraph, PackageId};
+    use wit_component::{ComponentEncoder, StringEncoding};
+    use wit_parser::{ManglingAndAbi, Resolve};
+
+    static PLUG_WIT: &str = indoc! {"
+        package app:dep;
+
+        interface api {
+            hello: func() -> string;
+        }
+
+        interface other {
+            hello: func() -> string;
+        }
+
+        interface mismatch {
+            hello: func() -> u32;
+        }
+
+        world socket {
+            import api;
+            import other;
+        }
+
+        world plug {
+            export api;
+            export other;
+            export mismatch;
+        }
+    "};
+
+    fn register_component(graph: &mut CompositionGraph, name: &str, world: &str) -> PackageId {
+        let mut resolve = Resolve::default();
+        let package_id = resolve.push_str("plug.wit", PLUG_WIT).unwrap();
+        let world_id = resolve.select_world(package_id, Some(world)).unwrap();
+
+        let mut module =
+            wit_component::dummy_module(&resolve, world_id, ManglingAndAbi::Standard32);
+        wit_component::embed_component_metadata(
+            &mut module,
+            &resolve,
+            world_id,
+            StringEncoding::UTF8,
+        )
+        .unwrap();
+        let component = ComponentEncoder::default()
+            .module(&module)
+            .unwrap()
+            .validate(true)
+            .encode()
+            .unwrap();
+
+        let package = Package::from_bytes(name, None, component, graph.types_mut()).unwrap();
+        graph.register_package(package).unwrap()
+    }
+
+    fn selected_plug_arguments(
+        selections: &[PlugSelection],
+    ) -> anyhow::Result<BTreeMap<String, PlugArgument>> {
+        let mut graph = CompositionGraph::new();
+        let socket = register_component(&mut graph, "socket", "socket");
+        let plugs = selections
+            .iter()
+            .enumerate()
+            .map(|(idx, selection)| {
+                let name = format!("plug-{idx}");
+                let package_id = register_component(&mut graph, &name, "plug");
+                (name, package_id, selection)
+            })
+            .collect::<Vec<_>>();
+        plug_arguments(&graph, &plugs, socket)
+    }
+
+    fn argument(plug_idx: usize, export_name: &str, explicit: bool) -> PlugArgument {
+        PlugArgument {
+            plug_idx,
+            export_name: export_name.to_string(),
+            explicit,
+        }
+    }
+
+    #[test]
+    fn wac_package_names_are_resolved_from_the_document() {
+        let source = indoc! {"
+            package app:composed;
+
+            // app:commented is only mentioned in a comment
+            import config: app:config/store;
+
+            let lib = new app:lib { ... };
+            let main = new app:main {
+                lib: (new app:nested { ... }).lib,
+                ...
+            };
 
-    for plug_name in unused_plugs {
-        log_warn_action(
-            "Skipping",
-            format!("{}, not used", plug_name.log_color_highlight()),
+            export main...;
+        "};
+
+        let_assert!(Ok(package_names) = wac_package_names("test", source));
+        check!(
+            package_names
+                == BTreeSet::from([
+                    "app:config".to_string(),
+                    "app:lib".to_string(),
+                    "app:main".to_string(),
+                    "app:nested".to_string(),
+                ])
         );
     }
 
-    // Export all exports from the socket component.
-    for name in graph.types()[graph[socket].ty()]
-        .exports
-        .keys()
-        .cloned()
-        .collect::<Vec<_>>()
-    {
-        let export = graph
-            .alias_instance_export(socket_instantiation, &name)
-            .map_err(|err| PlugError::GraphError { source: err.into() })?;
+    #[test]
+    fn wac_package_names_invalid_source() {
+        let_assert!(Err(_) = wac_package_names("test", "package app:composed; let x = ;"));
+    }
 
-        graph
-            .export(export, &name)
-            .map_err(|err| PlugError::GraphError { source: err.into() })?;
+    #[test]
+    fn plug_matched_by_name_last_plug_wins() {
+        let_assert!(
+            Ok(arguments) =
+                selected_plug_arguments(&[PlugSelection::default(), PlugSelection::default()])
+        );
+        check!(
+            arguments
+                == BTreeMap::from([
+                    ("app:dep/api".to_string(), argument(1, "app:dep/api", false)),
+                    (
+                        "app:dep/other".to_string(),
+                        argument(1, "app:dep/other", false)
+                    ),
+                ])
+        );
     }
 
-    Ok(())
+    #[test]
+    fn plug_selected_interfaces_take_precedence() {
+        let_assert!(
+            Ok(arguments) = selected_plug_arguments(&[
+                PlugSelection {
+                    interfaces: BTreeSet::from(["app:dep/api".to_string()]),
+                    mappings: BTreeMap::new(),
+                },
+                PlugSelection::default(),
+            ])
+        );
+        check!(
+            arguments
+                == BTreeMap::from([
+                    ("app:dep/api".to_string(), argument(0, "app:dep/api", true)),
+                    (
+                        "app:dep/other".to_string(),
+                        argument(1, "app:dep/other", false)
+                    ),
+                ])
+        );
+    }
+
+    #[test]
+    fn plug_mapped_import() {
+        let_assert!(
+            Ok(arguments) = selected_plug_arguments(&[PlugSelection {
+                interfaces: BTreeSet::new(),
+                mappings: BTreeMap::from([(
+                    "app:dep/api".to_string(),
+                    "app:dep/other".to_string()
+                )]),
+            }])
+        );
+        check!(
+            arguments
+                == BTreeMap::from([
+                    (
+                        "app:dep/api".to_string(),
+                        argument(0, "app:dep/other", true)
+                    ),
+                    (
+                        "app:dep/other".to_string(),
+                        argument(0, "app:dep/other", false)
+                    ),
+                ])
+        );
+    }
+
+    #[test]
+    fn plug_mapped_import_with_incompatible_export() {
+        let_assert!(
+            Err(err) = selected_plug_arguments(&[PlugSelection {
+                interfaces: BTreeSet::new(),
+                mappings: BTreeMap::from([(
+                    "app:dep/api".to_string(),
+                    "app:dep/mismatch".to_string()
+                )]),
+            }])
+        );
+        check!(err.to_string().contains("cannot be used for import"));
+    }
+
+    #[test]
+    fn plug_mapped_import_not_exported() {
+        let_assert!(
+            Err(err) = selected_plug_arguments(&[PlugSelection {
+                interfaces: BTreeSet::new(),
+                mappings: BTreeMap::from([(
+                    "app:dep/api".to_string(),
+                    "app:dep/missing".to_string()
+                )]),
+            }])
+        );
+        check!(err.to_string().contains("is not exported by"));
+    }
+
+    #[test]
+    fn plug_interface_selected_by_multiple_plugs() {
+        let selection = PlugSelection {
+            interfaces: BTreeSet::from(["app:dep/api".to_string()]),
+            mappings: BTreeMap::new(),
+        };
+        let_assert!(Err(err) = selected_plug_arguments(&[selection.clone(), selection]));
+        check!(err
+            .to_string()
+            .contains("is selected from multiple dependencies"));
+    }
 }
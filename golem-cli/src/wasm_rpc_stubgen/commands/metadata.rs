@@ -12,37 +12,93 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::diagnose::DetectedBuildTools;
 use crate::fs::{create_dir_all, PathExtra};
-use crate::wasm_metadata::{AddMetadata, AddMetadataField};
-use anyhow::Context;
+use crate::model::app_raw::ComponentMetadata;
+use crate::wasm_metadata::{
+    AddMetadata, AddMetadataField, Authors, Description, Homepage, Licenses, Revision, Source,
+    Version,
+};
+use anyhow::{anyhow, Context};
 use std::fs;
 use std::path::Path;
 use wit_parser::PackageName;
 
-/// Writes a name and a version metadata section based on the provided `root_package-name`
-/// to the WASM read from `source`, saving the result to `target`
+/// Writes the metadata sections to the WASM read from `source`, saving the result to `target`.
+///
+/// The name and the default version are based on the provided `root_package-name`, the other
+/// OCI annotation sections are based on the manifest `metadata`. The `revision` and the detected
+/// build tools are also recorded, the latter in the producers section. Sections without a
+/// configured value are kept as produced by the language toolchain.
 #[allow(clippy::field_reassign_with_default)]
 pub fn add_metadata(
     source: &impl AsRef<Path>,
     root_package_name: PackageName,
+    metadata: &ComponentMetadata,
+    revision: Option<&str>,
+    build_tools: &DetectedBuildTools,
     target: &impl AsRef<Path>,
 ) -> anyhow::Result<()> {
     let wasm = fs::read(source)
         .with_context(|| format!("Reading linked WASM from {:?}", source.as_ref()))?;
 
-    let mut metadata = AddMetadata::default();
-    metadata.name = AddMetadataField::Set(format!(
+    let mut add_metadata = AddMetadata::default();
+    add_metadata.name = AddMetadataField::Set(format!(
         "{}:{}",
         root_package_name.namespace, root_package_name.name
     ));
-    metadata.version = match &root_package_name.version {
-        None => AddMetadataField::Clear,
-        Some(v) => AddMetadataField::Set(crate::wasm_metadata::Version::new(v.to_string())),
+    add_metadata.version = match (&metadata.version, &root_package_name.version) {
+        (Some(version), _) => AddMetadataField::Set(Version::new(version.clone())),
+        (None, Some(version)) => AddMetadataField::Set(Version::new(version.to_string())),
+        (None, None) => AddMetadataField::Clear,
     };
+    add_metadata.authors = match &metadata.authors {
+        Some(authors) => AddMetadataField::Set(Authors::new(authors.clone())),
+        None => AddMetadataField::Keep,
+    };
+    add_metadata.description = match &metadata.description {
+        Some(description) => AddMetadataField::Set(Description::new(description.clone())),
+        None => AddMetadataField::Keep,
+    };
+    add_metadata.licenses = match &metadata.licenses {
+        Some(licenses) => AddMetadataField::Set(Licenses::new(licenses).with_context(|| {
+            anyhow!("Invalid SPDX license expression in metadata.licenses: {licenses}")
+        })?),
+        None => AddMetadataField::Keep,
+    };
+    add_metadata.source = match &metadata.source {
+        Some(source) => AddMetadataField::Set(
+            Source::new(source)
+                .with_context(|| anyhow!("Invalid URL in metadata.source: {source}"))?,
+        ),
+        None => AddMetadataField::Keep,
+    };
+    add_metadata.homepage = match &metadata.homepage {
+        Some(homepage) => AddMetadataField::Set(
+            Homepage::new(homepage)
+                .with_context(|| anyhow!("Invalid URL in metadata.homepage: {homepage}"))?,
+        ),
+        None => AddMetadataField::Keep,
+    };
+    add_metadata.revision = match revision {
+        Some(revision) => AddMetadataField::Set(Revision::new(revision.to_string())),
+        None => AddMetadataField::Keep,
+    };
+
+    add_metadata.language = build_tools
+        .language
+        .iter()
+        .map(|language| (language.clone(), String::new()))
+        .collect();
+    add_metadata.sdk = build_tools.sdk.clone();
+    add_metadata.processed_by = build_tools.processed_by.clone();
+    add_metadata
+        .processed_by
+        .push(("golem-cli".to_string(), crate::version().to_string()));
 
-    let updated_wasm = metadata
+    let updated_wasm = add_metadata
         .to_wasm(&wasm)
-        .context("Adding name and version metadata to the linked WASM")?;
+        .context("Adding metadata to the linked WASM")?;
 
     create_dir_all(PathExtra::new(target).parent()?)
         .with_context(|| format!("Failed to create target dir for {:?}", target.as_ref()))?;
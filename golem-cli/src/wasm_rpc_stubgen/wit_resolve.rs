@@ -10,6 +10,7 @@ use indexmap::IndexMap;
 use indoc::formatdoc;
 use itertools::Itertools;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt::{Display, Formatter};
 use std::path::{Path, PathBuf};
 use wit_parser::decoding::DecodedWasm;
 use wit_parser::{
@@ -147,6 +148,29 @@ impl ResolvedWitDir {
         Ok(result)
     }
 
+    pub fn imports(&self) -> anyhow::Result<Vec<String>> {
+        let mut result = Vec::new();
+        let main = self.main_package()?;
+        for (world_name, world_id) in &main.worlds {
+            let world = self
+                .resolve
+                .worlds
+                .get(*world_id)
+                .ok_or_else(|| anyhow!("Could not find world {world_name} in resolve"))?;
+            for (import_name, import) in world.imports.iter() {
+                match import {
+                    WorldItem::Function(function) => result.push(function.name.clone()),
+                    WorldItem::Interface { .. } => {
+                        result.push(self.resolve.name_world_key(import_name))
+                    }
+                    WorldItem::Type(_) => {}
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
     pub fn is_agent(&self) -> anyhow::Result<bool> {
         golem_common::model::agent::extraction::is_agent(&self.resolve, &self.package_id, None)
     }
@@ -168,6 +192,24 @@ pub enum ExportedFunction {
     },
 }
 
+impl Display for ExportedFunction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExportedFunction::Interface {
+                interface_name,
+                function_name,
+            } => write!(f, "{interface_name}.{{{function_name}}}"),
+            ExportedFunction::InlineInterface {
+                export_name,
+                function_name,
+            } => write!(f, "{export_name}.{{{function_name}}}"),
+            ExportedFunction::InlineFunction { function_name, .. } => {
+                write!(f, "{function_name}")
+            }
+        }
+    }
+}
+
 fn resolve_wit_dir(path: &Path) -> anyhow::Result<ResolvedWitDir> {
     // TODO: Can be removed once we fixed all docs and templates
     std::env::set_var("WIT_REQUIRE_F32_F64", "0");
@@ -929,6 +971,36 @@ impl ResolvedWitApplication {
         let component = self.component(component_name)?;
         Ok(component.main_package_name.clone())
     }
+
+    /// Resolved generated wit dir of the component, available once the component wit was generated
+    pub fn component_generated_wit(
+        &self,
+        component_name: &AppComponentName,
+    ) -> anyhow::Result<Option<&ResolvedWitDir>> {
+        let component = self.component(component_name)?;
+        Ok(component.resolved_wit_dir.as_ref())
+    }
+
+    /// Looks up the generated wasm-rpc client package of the component in the generated wit dirs
+    /// of the dependent components
+    pub fn component_client_package(
+        &self,
+        component_name: &AppComponentName,
+    ) -> anyhow::Result<Option<(&Resolve, PackageId)>> {
+        let client_package_name = naming::wit::client_parser_package_name(
+            &self.component(component_name)?.main_package_name,
+        );
+        Ok(self
+            .components
+            .values()
+            .filter_map(|component| component.resolved_wit_dir.as_ref())
+            .find_map(|wit| {
+                wit.resolve
+                    .package_names
+                    .get(&client_package_name)
+                    .map(|package_id| (&wit.resolve, *package_id))
+            }))
+    }
 }
 
 pub fn parse_wit_deps_dir(path: &Path) -> Result<Vec<UnresolvedPackageGroup>, Error> {